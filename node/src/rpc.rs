@@ -7,13 +7,183 @@
 
 use std::sync::Arc;
 
-use jsonrpsee::RpcModule;
+use jsonrpsee::{
+    core::RpcResult, proc_macros::rpc, types::error::ErrorObjectOwned, RpcModule,
+};
+use pallet_nft_attributes_runtime_api::NftAttributesApi as NftAttributesRuntimeApi;
 use sc_transaction_pool_api::TransactionPool;
+use serde::Deserialize;
 use sp_api::ProvideRuntimeApi;
 use sp_block_builder::BlockBuilder;
 use sp_blockchain::{Error as BlockChainError, HeaderBackend, HeaderMetadata};
+use sp_core::Bytes;
+use sp_runtime::traits::Block as BlockT;
 use telurean_chain_runtime::{opaque::Block, AccountId, Balance, Nonce};
 
+/// Hash of a block, used to choose the state an RPC call is answered from.
+type BlockHash = <Block as BlockT>::Hash;
+
+/// Condition on the value of an attribute, as received over JSON-RPC.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum AttributeFilter {
+    /// The value is exactly the given bytes.
+    Equals(Bytes),
+    /// The value is a number within the inclusive bounds. A missing bound leaves that side open.
+    Range {
+        /// Lower bound of the value.
+        min: Option<i64>,
+        /// Upper bound of the value.
+        max: Option<i64>,
+    },
+}
+
+impl From<AttributeFilter> for pallet_nft_attributes_runtime_api::AttributeFilter {
+    fn from(filter: AttributeFilter) -> Self {
+        match filter {
+            AttributeFilter::Equals(value) => Self::Equals(value.to_vec()),
+            AttributeFilter::Range { min, max } => Self::Range { min, max },
+        }
+    }
+}
+
+/// RPC methods to query the attributes of NFTs.
+#[rpc(server)]
+pub trait NftAttributesApi {
+    /// All the attributes of an item, as key-value pairs, including those it inherits.
+    #[method(name = "nftAttributes_attributes")]
+    fn attributes(
+        &self,
+        collection: u32,
+        item: u128,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<(Bytes, Bytes)>>;
+
+    /// The value of a single attribute of an item, looked up in the item itself, then in its
+    /// template and finally in the collection defaults.
+    #[method(name = "nftAttributes_attribute")]
+    fn attribute(
+        &self,
+        collection: u32,
+        item: u128,
+        key: Bytes,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<Bytes>>;
+
+    /// Items of a collection whose attribute `key` matches `filter`, one page at a time. Each page
+    /// comes with the cursor to pass as `start_after` to get the next one, which is `None` once
+    /// the whole collection has been searched.
+    #[method(name = "nftAttributes_itemsMatching")]
+    fn items_matching(
+        &self,
+        collection: u32,
+        key: Bytes,
+        filter: AttributeFilter,
+        start_after: Option<u128>,
+        limit: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<(Vec<u128>, Option<u128>)>;
+
+    /// Attributes of an NFT once the modifiers of the assets it owns have been applied.
+    #[method(name = "nftAttributes_effectiveAttributes")]
+    fn effective_attributes(
+        &self,
+        collection: u32,
+        item: u128,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<(Bytes, Bytes)>>;
+}
+
+/// Implementation of the NFT attributes RPC methods, answered by the runtime API.
+pub struct NftAttributes<C> {
+    client: Arc<C>,
+}
+
+impl<C> NftAttributes<C> {
+    /// Create a new instance with the given client.
+    pub fn new(client: Arc<C>) -> Self {
+        Self { client }
+    }
+}
+
+/// Map an error of the runtime API into an RPC error.
+fn runtime_error(error: impl std::fmt::Display) -> ErrorObjectOwned {
+    ErrorObjectOwned::owned(1, "Unable to query NFT attributes.", Some(error.to_string()))
+}
+
+/// Convert key-value pairs into their RPC representation.
+fn to_bytes_pairs(pairs: Vec<(Vec<u8>, Vec<u8>)>) -> Vec<(Bytes, Bytes)> {
+    pairs
+        .into_iter()
+        .map(|(key, value)| (key.into(), value.into()))
+        .collect()
+}
+
+impl<C> NftAttributesApiServer for NftAttributes<C>
+where
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: NftAttributesRuntimeApi<Block, u32, u128>,
+{
+    fn attributes(
+        &self,
+        collection: u32,
+        item: u128,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<(Bytes, Bytes)>> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.client
+            .runtime_api()
+            .attributes(at, collection, item)
+            .map(to_bytes_pairs)
+            .map_err(runtime_error)
+    }
+
+    fn attribute(
+        &self,
+        collection: u32,
+        item: u128,
+        key: Bytes,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<Bytes>> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.client
+            .runtime_api()
+            .attribute(at, collection, item, key.to_vec())
+            .map(|value| value.map(Into::into))
+            .map_err(runtime_error)
+    }
+
+    fn items_matching(
+        &self,
+        collection: u32,
+        key: Bytes,
+        filter: AttributeFilter,
+        start_after: Option<u128>,
+        limit: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<(Vec<u128>, Option<u128>)> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.client
+            .runtime_api()
+            .items_matching(at, collection, key.to_vec(), filter.into(), start_after, limit)
+            .map_err(runtime_error)
+    }
+
+    fn effective_attributes(
+        &self,
+        collection: u32,
+        item: u128,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<(Bytes, Bytes)>> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.client
+            .runtime_api()
+            .effective_attributes(at, collection, item)
+            .map(to_bytes_pairs)
+            .map_err(runtime_error)
+    }
+}
+
 /// Full client dependencies.
 pub struct FullDeps<C, P> {
     /// The client instance to use.
@@ -32,6 +202,7 @@ where
     C: Send + Sync + 'static,
     C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
     C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
+    C::Api: NftAttributesRuntimeApi<Block, u32, u128>,
     C::Api: BlockBuilder<Block>,
     P: TransactionPool + 'static,
 {
@@ -42,7 +213,8 @@ where
     let FullDeps { client, pool } = deps;
 
     module.merge(System::new(client.clone(), pool).into_rpc())?;
-    module.merge(TransactionPayment::new(client).into_rpc())?;
+    module.merge(TransactionPayment::new(client.clone()).into_rpc())?;
+    module.merge(NftAttributes::new(client).into_rpc())?;
 
     // Extend this RPC with a custom API by using the following syntax.
     // `YourRpcStruct` should have a reference to a client, which is needed
@@ -0,0 +1,161 @@
+//! Benchmarking setup for pallet-dice
+
+use super::*;
+
+#[allow(unused)]
+use crate::Pallet as Dice;
+use alloc::{format, vec, vec::Vec};
+use frame_benchmarking::v2::*;
+use frame_support::{
+    traits::{
+        fungible::{Inspect, Mutate},
+        Get,
+    },
+    BoundedVec,
+};
+use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
+use pallet_nft_attributes::traits::MutateAttributes;
+use sp_runtime::traits::{Hash, Saturating};
+
+/// Give `who` enough balance to create a roll and commit to a seed.
+fn fund<T: Config>(who: &T::AccountId) {
+    let deposit = T::SeedDeposit::get()
+        .saturating_add(T::RollDeposit::get())
+        .saturating_mul(2u32.into());
+    T::Currency::set_balance(who, T::Currency::minimum_balance().saturating_add(deposit));
+}
+
+/// The most dice of the most faces, thrown twice.
+fn notation<T: Config>() -> Notation<T> {
+    let notation = format!("{}d{}+9 adv", T::MaxDice::get(), T::MaxSides::get());
+    BoundedVec::truncate_from(notation.into_bytes())
+}
+
+/// An attribute of an item of `creator` with a key of the maximum length, if the attributes of
+/// the runtime have any item.
+fn target<T: Config>(creator: &T::AccountId) -> Option<TargetOf<T>> {
+    let (collection, item) = T::Attributes::benchmark_item(creator)?;
+    let key = BoundedVec::truncate_from(vec![b'r'; T::KeyLimit::get() as usize]);
+    Some((collection, item, key))
+}
+
+/// Create a roll by `creator` among `participants`, funded, with the total recorded in an
+/// attribute of an item of `creator`.
+fn roll<T: Config>(creator: &T::AccountId, participants: &[T::AccountId]) -> RollId {
+    participants.iter().for_each(fund::<T>);
+    fund::<T>(creator);
+    let roll = NextRollId::<T>::get();
+    Dice::<T>::create_roll(
+        RawOrigin::Signed(creator.clone()).into(),
+        notation::<T>(),
+        BoundedVec::truncate_from(participants.to_vec()),
+        target::<T>(creator),
+    )
+    .expect("the roll is valid");
+    roll
+}
+
+/// `count` participants, the caller last.
+fn participants<T: Config>(count: u32) -> Vec<T::AccountId> {
+    let mut participants: Vec<T::AccountId> =
+        (1..count).map(|index| account("participant", index, 0)).collect();
+    participants.push(whitelisted_caller());
+    participants
+}
+
+/// The seed of the participant at `index`.
+fn seed(index: usize) -> [u8; 32] {
+    [index as u8; 32]
+}
+
+/// Move on to the first block in which the seeds of `roll` may be revealed.
+fn open_reveals<T: Config>(roll: RollId) {
+    let entropy_block = Rolls::<T>::get(roll).expect("the roll is not resolved yet").entropy_block;
+    frame_system::Pallet::<T>::set_block_number(entropy_block.saturating_add(1u32.into()));
+}
+
+/// Commit `who` to `seed` in `roll`.
+fn commit<T: Config>(roll: RollId, who: &T::AccountId, seed: [u8; 32]) {
+    let hash = T::Hashing::hash_of(&(who, seed));
+    Dice::<T>::commit_seed(RawOrigin::Signed(who.clone()).into(), roll, hash)
+        .expect("the participant has not committed yet");
+}
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn create_roll(p: Linear<1, { T::MaxParticipants::get() }>) {
+        let caller: T::AccountId = whitelisted_caller();
+        fund::<T>(&caller);
+        let target = target::<T>(&caller);
+        let participants = BoundedVec::truncate_from(participants::<T>(p));
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), notation::<T>(), participants, target);
+
+        assert_eq!(Rolls::<T>::get(0).map(|roll| roll.participants.len()), Some(p as usize));
+    }
+
+    /// The caller commits last, which fixes the entropy block of the roll.
+    #[benchmark]
+    fn commit_seed() {
+        let caller: T::AccountId = whitelisted_caller();
+        let participants = participants::<T>(T::MaxParticipants::get());
+        let roll = roll::<T>(&account("creator", 0, 0), &participants);
+        for (index, who) in participants.iter().enumerate().filter(|(_, who)| **who != caller) {
+            commit::<T>(roll, who, seed(index));
+        }
+        let hash = T::Hashing::hash_of(&(&caller, seed(participants.len() - 1)));
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), roll, hash);
+
+        let next = frame_system::Pallet::<T>::block_number().saturating_add(1u32.into());
+        assert!(Rolls::<T>::get(roll).is_some_and(|roll| roll.entropy_block == next));
+    }
+
+    /// The caller is the only participant to commit, so its reveal reads the hash of the
+    /// entropy block as well as rolling the dice and recording the total.
+    #[benchmark]
+    fn reveal_seed() {
+        let caller: T::AccountId = whitelisted_caller();
+        let participants = participants::<T>(T::MaxParticipants::get());
+        let roll = roll::<T>(&account("creator", 0, 0), &participants);
+        commit::<T>(roll, &caller, seed(participants.len() - 1));
+        open_reveals::<T>(roll);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), roll, seed(participants.len() - 1));
+
+        assert!(!Rolls::<T>::contains_key(roll));
+    }
+
+    /// `p` participants forfeit their deposits, and the others revealed their seeds.
+    #[benchmark]
+    fn resolve_roll(p: Linear<1, { T::MaxParticipants::get() }>) {
+        let caller: T::AccountId = whitelisted_caller();
+        let participants = participants::<T>(T::MaxParticipants::get());
+        let roll = roll::<T>(&account("creator", 0, 0), &participants);
+        for (index, who) in participants.iter().enumerate() {
+            commit::<T>(roll, who, seed(index));
+        }
+        open_reveals::<T>(roll);
+        let revealed = participants.len() - p as usize;
+        for (index, who) in participants.iter().enumerate().take(revealed) {
+            Dice::<T>::reveal_seed(RawOrigin::Signed(who.clone()).into(), roll, seed(index))
+                .expect("the seed was committed");
+        }
+        let deadline: BlockNumberFor<T> =
+            Rolls::<T>::get(roll).expect("the roll is not resolved yet").reveal_deadline;
+        frame_system::Pallet::<T>::set_block_number(deadline.saturating_add(1u32.into()));
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), roll);
+
+        assert!(!Rolls::<T>::contains_key(roll));
+    }
+
+    impl_benchmark_test_suite!(Dice, crate::mock::new_test_ext(), crate::mock::Test);
+}
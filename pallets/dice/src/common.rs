@@ -0,0 +1,152 @@
+use alloc::vec::Vec;
+use frame_support::pallet_prelude::{
+    Decode, DecodeWithMemTracking, Encode, MaxEncodedLen, RuntimeDebug, TypeInfo,
+};
+
+/// How many times the dice are thrown, and which throw is kept.
+#[derive(
+    Encode, Decode, DecodeWithMemTracking, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo,
+    MaxEncodedLen,
+)]
+pub enum RollMode {
+    /// The dice are thrown once.
+    Normal,
+    /// The dice are thrown twice and the higher sum is kept.
+    Advantage,
+    /// The dice are thrown twice and the lower sum is kept.
+    Disadvantage,
+}
+
+/// Dice to roll, as written in the standard notation: `3d6+2` throws three six-sided dice and adds
+/// two to their sum, and a trailing `adv` or `dis` throws them twice and keeps the higher or the
+/// lower sum, as in `1d20 adv`.
+#[derive(
+    Encode, Decode, DecodeWithMemTracking, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo,
+    MaxEncodedLen,
+)]
+pub struct Dice {
+    /// Number of dice in each throw.
+    pub count: u32,
+    /// Number of faces of each die, numbered from 1.
+    pub sides: u32,
+    /// Amount added to the sum of the dice.
+    pub modifier: i32,
+    pub mode: RollMode,
+}
+
+impl Dice {
+    /// Parse dice written in the standard notation. The number of dice may be left out for a
+    /// single die, as in `d20`, and spaces are allowed around the modifier.
+    pub fn parse(notation: &[u8]) -> Option<Self> {
+        let notation = notation.trim_ascii();
+        let (dice, mode) = if let Some(dice) = notation.strip_suffix(b"adv") {
+            (dice, RollMode::Advantage)
+        } else if let Some(dice) = notation.strip_suffix(b"dis") {
+            (dice, RollMode::Disadvantage)
+        } else {
+            (notation, RollMode::Normal)
+        };
+        // The mode is a separate word: `1d20adv` is not valid notation.
+        if mode != RollMode::Normal && !dice.last()?.is_ascii_whitespace() {
+            return None;
+        }
+
+        let dice = dice.trim_ascii_end();
+        let separator = dice.iter().position(|c| *c == b'd' || *c == b'D')?;
+        let (count, rest) = (&dice[..separator], &dice[separator + 1..]);
+        let count = if count.is_empty() { 1 } else { parse_digits(count)? };
+        let (sides, modifier) = match rest.iter().position(|c| *c == b'+' || *c == b'-') {
+            Some(sign) => {
+                let magnitude = parse_digits(rest[sign + 1..].trim_ascii_start())?;
+                let magnitude = i32::try_from(magnitude).ok()?;
+                let modifier = if rest[sign] == b'-' { -magnitude } else { magnitude };
+                (rest[..sign].trim_ascii_end(), modifier)
+            }
+            None => (rest, 0),
+        };
+        let sides = parse_digits(sides)?;
+        if count == 0 || sides < 2 {
+            return None;
+        }
+        Some(Dice { count, sides, modifier, mode })
+    }
+
+    /// Number of dice thrown, counting both throws under advantage or disadvantage.
+    pub fn thrown(&self) -> u32 {
+        match self.mode {
+            RollMode::Normal => self.count,
+            RollMode::Advantage | RollMode::Disadvantage => self.count.saturating_mul(2),
+        }
+    }
+
+    /// Roll the dice with `die`, which returns the face of the next die thrown given its number
+    /// of sides, from 1 to `sides`. Return the faces of every die thrown, in order, and the total
+    /// of the kept throw plus the modifier.
+    pub fn roll(&self, mut die: impl FnMut(u32) -> u32) -> (Vec<u32>, i64) {
+        let faces: Vec<u32> = (0..self.thrown()).map(|_| die(self.sides)).collect();
+        let mut sums = faces.chunks(self.count as usize).map(|throw| {
+            throw.iter().map(|face| *face as i64).sum::<i64>()
+        });
+        let first = sums.next().unwrap_or_default();
+        let kept = match (self.mode, sums.next()) {
+            (RollMode::Advantage, Some(second)) => first.max(second),
+            (RollMode::Disadvantage, Some(second)) => first.min(second),
+            _ => first,
+        };
+        (faces, kept + self.modifier as i64)
+    }
+}
+
+/// Parse a non-empty sequence of decimal digits.
+fn parse_digits(digits: &[u8]) -> Option<u32> {
+    if digits.is_empty() {
+        return None;
+    }
+    let mut number: u32 = 0;
+    for digit in digits {
+        if !digit.is_ascii_digit() {
+            return None;
+        }
+        number = number.checked_mul(10)?.checked_add((digit - b'0') as u32)?;
+    }
+    Some(number)
+}
+
+/// The seed of a participant in a roll.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum Seed<Hash> {
+    /// The participant has not committed to a seed yet.
+    Pending,
+    /// The participant committed to the seed behind this hash, and left a deposit until it is
+    /// revealed.
+    Committed(Hash),
+    /// The participant revealed the seed.
+    Revealed([u8; 32]),
+}
+
+/// A roll of dice, from the commitments of its participants to the result.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct Roll<AccountId, Balance, BlockNumber, Hash, Participants, Target> {
+    /// The account that created the roll, on whose behalf the result is recorded.
+    pub creator: AccountId,
+    pub dice: Dice,
+    /// The participants, with their seeds.
+    pub participants: Participants,
+    /// Deposit that each participant leaves when committing, forfeited if the seed is not
+    /// revealed in time.
+    pub deposit: Balance,
+    /// Deposit held from the creator until the roll is resolved.
+    pub creator_deposit: Balance,
+    /// Last block in which the participants may commit.
+    pub commit_deadline: BlockNumber,
+    /// Last block in which the participants may reveal their seeds.
+    pub reveal_deadline: BlockNumber,
+    /// Block whose hash is mixed with the seeds: the one after the block in which the last
+    /// participant committed, or the one after the commit deadline if some participant never
+    /// commits. The seeds are revealed once it is produced.
+    pub entropy_block: BlockNumber,
+    /// Hash of the entropy block, read by the first reveal.
+    pub entropy: Option<Hash>,
+    /// Attribute of an NFT where the total is recorded, if any.
+    pub target: Option<Target>,
+}
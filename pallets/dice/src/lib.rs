@@ -0,0 +1,543 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+// Re-export pallet items so that they can be accessed from the crate namespace.
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod weights;
+pub use weights::*;
+
+// Notation of the dice, and the state of the rolls.
+mod common;
+pub use common::{Dice, Roll, RollMode, Seed};
+
+/// Rolls of dice that nobody can fudge, the game master included. Each participant of a roll
+/// commits to a secret seed and reveals it once everybody has committed. The dice are derived
+/// from all the seeds together with the hash of a block produced after the last commitment, so no
+/// participant controls the result on their own. Withholding a seed to discard an unwanted result
+/// costs the deposit left with the commitment.
+// The dispatch code generated for calls returning post-dispatch info converts it into itself.
+#[allow(clippy::useless_conversion)]
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+    use alloc::{format, vec::Vec};
+    use frame_support::pallet_prelude::*;
+    use frame_support::traits::{
+        fungible::{self, MutateHold},
+        tokens::{Fortitude, Precision},
+    };
+    use frame_system::pallet_prelude::*;
+    use pallet_nft_attributes::traits::MutateAttributes;
+    use sp_runtime::{
+        traits::{Hash, One, Saturating, TrailingZeroInput},
+        ArithmeticError,
+    };
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    /// The pallet's configuration trait.
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        /// The overarching runtime event type.
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// A type representing the weights required by the dispatchables of this pallet.
+        type WeightInfo: WeightInfo;
+
+        /// The overarching hold reason.
+        type RuntimeHoldReason: From<HoldReason>;
+
+        /// The currency in which the participants leave their deposits.
+        type Currency: fungible::Mutate<Self::AccountId>
+            + MutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason>;
+
+        /// Deposit that each participant leaves when committing to a seed. It is returned when the
+        /// seed is revealed, and burned if it is not revealed in time.
+        #[pallet::constant]
+        type SeedDeposit: Get<BalanceOf<Self>>;
+
+        /// Deposit that the creator of a roll leaves until it is resolved, whether every seed is
+        /// revealed or anyone resolves it once the reveal deadline has passed.
+        #[pallet::constant]
+        type RollDeposit: Get<BalanceOf<Self>>;
+
+        /// Number of blocks after the creation of a roll during which its participants may commit.
+        #[pallet::constant]
+        type CommitPeriod: Get<BlockNumberFor<Self>>;
+
+        /// Number of blocks during which the participants may reveal their seeds, counted from the
+        /// block after the commit deadline. Together with CommitPeriod, it must be shorter than
+        /// the block hashes kept by the system, which the reveals read.
+        #[pallet::constant]
+        type RevealPeriod: Get<BlockNumberFor<Self>>;
+
+        /// Maximum number of participants of a roll.
+        #[pallet::constant]
+        type MaxParticipants: Get<u32>;
+
+        /// Maximum number of dice in each throw of a roll.
+        #[pallet::constant]
+        type MaxDice: Get<u32>;
+
+        /// Maximum number of faces of the dice.
+        #[pallet::constant]
+        type MaxSides: Get<u32>;
+
+        /// Limit on the length of the notation of the dice.
+        #[pallet::constant]
+        type NotationLimit: Get<u32>;
+
+        /// Identifier of a collection of the NFTs whose attributes record rolls.
+        type CollectionId: Member + Parameter + MaxEncodedLen;
+
+        /// Identifier of an item of the NFTs whose attributes record rolls.
+        type ItemId: Member + Parameter + MaxEncodedLen;
+
+        /// Limit on the length of the key of the attribute that records a roll.
+        #[pallet::constant]
+        type KeyLimit: Get<u32>;
+
+        /// The attributes in which the totals of the rolls may be recorded.
+        type Attributes: MutateAttributes<Self::AccountId, Self::CollectionId, Self::ItemId>;
+    }
+
+    /// The balance of the currency of the deposits.
+    pub type BalanceOf<T> =
+        <<T as Config>::Currency as fungible::Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+
+    /// Identifier of a roll.
+    pub type RollId = u32;
+
+    /// The notation of the dice of a roll, such as `3d6+2` or `1d20 adv`.
+    pub type Notation<T> = BoundedVec<u8, <T as Config>::NotationLimit>;
+
+    /// The attribute of an NFT where the total of a roll is recorded.
+    pub type TargetOf<T> = (
+        <T as Config>::CollectionId,
+        <T as Config>::ItemId,
+        BoundedVec<u8, <T as Config>::KeyLimit>,
+    );
+
+    /// The participants of a roll, with their seeds.
+    pub type ParticipantsOf<T> = BoundedVec<
+        (<T as frame_system::Config>::AccountId, Seed<<T as frame_system::Config>::Hash>),
+        <T as Config>::MaxParticipants,
+    >;
+
+    /// A roll in this runtime.
+    pub type RollOf<T> = Roll<
+        <T as frame_system::Config>::AccountId,
+        BalanceOf<T>,
+        BlockNumberFor<T>,
+        <T as frame_system::Config>::Hash,
+        ParticipantsOf<T>,
+        TargetOf<T>,
+    >;
+
+    /// A reason for the pallet placing a hold on funds.
+    #[pallet::composite_enum]
+    pub enum HoldReason {
+        /// Deposit of a participant who committed to a seed, until the seed is revealed.
+        SeedCommitment,
+        /// Deposit of the creator of a roll, until the roll is resolved.
+        RollCreation,
+    }
+
+    /// Identifier of the next roll.
+    #[pallet::storage]
+    pub type NextRollId<T: Config> = StorageValue<_, RollId, ValueQuery>;
+
+    /// The rolls that have not been resolved yet.
+    #[pallet::storage]
+    pub type Rolls<T: Config> = StorageMap<_, Twox64Concat, RollId, RollOf<T>, OptionQuery>;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        RollCreated {
+            roll: RollId,
+            dice: Dice,
+            creator: T::AccountId,
+            commit_deadline: BlockNumberFor<T>,
+            reveal_deadline: BlockNumberFor<T>,
+        },
+        SeedCommitted {
+            roll: RollId,
+            who: T::AccountId,
+        },
+        SeedRevealed {
+            roll: RollId,
+            who: T::AccountId,
+        },
+        /// A participant did not reveal the seed in time, and lost the deposit.
+        SeedForfeited {
+            roll: RollId,
+            who: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        /// The dice were rolled: `faces` are those of every die thrown, in order, and `total` the
+        /// sum of the kept throw plus the modifier.
+        Rolled {
+            roll: RollId,
+            dice: Dice,
+            faces: Vec<u32>,
+            total: i64,
+        },
+        /// The roll was resolved without any revealed seed, so the dice were not rolled.
+        RollCancelled {
+            roll: RollId,
+        },
+        /// The total of a roll could not be recorded in its target attribute.
+        RollNotRecorded {
+            roll: RollId,
+            error: DispatchError,
+        },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        InvalidNotation,
+        TooManyDice,
+        TooManySides,
+        NoParticipants,
+        DuplicateParticipant,
+        RollNotFound,
+        NotParticipant,
+        CommitmentsClosed,
+        AlreadyCommitted,
+        RevealsNotOpen,
+        RevealsClosed,
+        NotCommitted,
+        WrongReveal,
+        RevealsStillOpen,
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn integrity_test() {
+            let span = T::CommitPeriod::get()
+                .saturating_add(T::RevealPeriod::get())
+                .saturating_add(2u32.into());
+            assert!(
+                span <= T::BlockHashCount::get(),
+                "the hash of the entropy block is kept until the reveal deadline"
+            );
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn try_state(_: BlockNumberFor<T>) -> Result<(), sp_runtime::TryRuntimeError> {
+            Self::do_try_state()
+        }
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Create a roll of the dice written in `notation`, such as `3d6+2` or `1d20 adv`, among
+        /// `participants`. The creator need not take part. With a `target`, the total is recorded
+        /// in that attribute of an NFT, which the creator must be allowed to set. The creator leaves
+        /// `RollDeposit` until the roll is resolved.
+        #[pallet::call_index(0)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::create_roll(participants.len() as u32))]
+        pub fn create_roll(
+            origin: OriginFor<T>,
+            notation: Notation<T>,
+            participants: BoundedVec<T::AccountId, T::MaxParticipants>,
+            target: Option<TargetOf<T>>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let dice = Dice::parse(&notation).ok_or(Error::<T>::InvalidNotation)?;
+            ensure!(dice.count <= T::MaxDice::get(), Error::<T>::TooManyDice);
+            ensure!(dice.sides <= T::MaxSides::get(), Error::<T>::TooManySides);
+            ensure!(!participants.is_empty(), Error::<T>::NoParticipants);
+            for (index, participant) in participants.iter().enumerate() {
+                ensure!(
+                    !participants[..index].contains(participant),
+                    Error::<T>::DuplicateParticipant
+                );
+            }
+            if let Some((collection, item, _)) = &target {
+                T::Attributes::ensure_can_set(&who, collection, item)?;
+            }
+
+            let roll = NextRollId::<T>::get();
+            NextRollId::<T>::put(roll.checked_add(1).ok_or(ArithmeticError::Overflow)?);
+            let creator_deposit = T::RollDeposit::get();
+            T::Currency::hold(&HoldReason::RollCreation.into(), &who, creator_deposit)?;
+            let commit_deadline =
+                frame_system::Pallet::<T>::block_number().saturating_add(T::CommitPeriod::get());
+            let entropy_block = commit_deadline.saturating_add(One::one());
+            let reveal_deadline = entropy_block.saturating_add(T::RevealPeriod::get());
+            let participants = participants
+                .into_iter()
+                .map(|participant| (participant, Seed::Pending))
+                .collect::<Vec<_>>();
+            Rolls::<T>::insert(
+                roll,
+                Roll {
+                    creator: who.clone(),
+                    dice,
+                    participants: BoundedVec::truncate_from(participants),
+                    deposit: T::SeedDeposit::get(),
+                    creator_deposit,
+                    commit_deadline,
+                    reveal_deadline,
+                    entropy_block,
+                    entropy: None,
+                    target,
+                },
+            );
+            Self::deposit_event(Event::RollCreated {
+                roll,
+                dice,
+                creator: who,
+                commit_deadline,
+                reveal_deadline,
+            });
+            Ok(())
+        }
+
+        /// Commit to a seed for a roll: `hash` is the hash of the SCALE-encoded `(account, seed)`,
+        /// computed with the hashing of the chain, where `seed` is 32 secret bytes. Only the
+        /// participants may commit, once each and until the commit deadline, leaving the deposit
+        /// until the seed is revealed.
+        #[pallet::call_index(1)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::commit_seed())]
+        pub fn commit_seed(origin: OriginFor<T>, roll: RollId, hash: T::Hash) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let mut details = Rolls::<T>::get(roll).ok_or(Error::<T>::RollNotFound)?;
+            ensure!(
+                frame_system::Pallet::<T>::block_number() <= details.commit_deadline,
+                Error::<T>::CommitmentsClosed
+            );
+            let deposit = details.deposit;
+            let seed = Self::seed_of(&mut details, &who)?;
+            ensure!(*seed == Seed::Pending, Error::<T>::AlreadyCommitted);
+
+            T::Currency::hold(&HoldReason::SeedCommitment.into(), &who, deposit)?;
+            *seed = Seed::Committed(hash);
+            if details.participants.iter().all(|(_, seed)| *seed != Seed::Pending) {
+                details.entropy_block =
+                    frame_system::Pallet::<T>::block_number().saturating_add(One::one());
+            }
+            Rolls::<T>::insert(roll, details);
+            Self::deposit_event(Event::SeedCommitted { roll, who });
+            Ok(())
+        }
+
+        /// Reveal the seed committed to for a roll, which returns the deposit. Seeds are revealed
+        /// once the entropy block of the roll is produced, until the reveal deadline. The first
+        /// reveal stores the hash of that block, and the dice are rolled as soon as every committed
+        /// seed is revealed.
+        #[pallet::call_index(2)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::reveal_seed())]
+        pub fn reveal_seed(origin: OriginFor<T>, roll: RollId, seed: [u8; 32]) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let mut details = Rolls::<T>::get(roll).ok_or(Error::<T>::RollNotFound)?;
+            let now = frame_system::Pallet::<T>::block_number();
+            ensure!(now > details.entropy_block, Error::<T>::RevealsNotOpen);
+            ensure!(now <= details.reveal_deadline, Error::<T>::RevealsClosed);
+            let deposit = details.deposit;
+            let state = Self::seed_of(&mut details, &who)?;
+            let Seed::Committed(hash) = &*state else {
+                return Err(Error::<T>::NotCommitted.into());
+            };
+            ensure!(T::Hashing::hash_of(&(&who, &seed)) == *hash, Error::<T>::WrongReveal);
+
+            T::Currency::release(
+                &HoldReason::SeedCommitment.into(),
+                &who,
+                deposit,
+                Precision::BestEffort,
+            )?;
+            *state = Seed::Revealed(seed);
+            if details.entropy.is_none() {
+                details.entropy = Some(frame_system::BlockHash::<T>::get(details.entropy_block));
+            }
+            Self::deposit_event(Event::SeedRevealed { roll, who });
+
+            if details.participants.iter().any(|(_, seed)| matches!(seed, Seed::Committed(_))) {
+                Rolls::<T>::insert(roll, details);
+                Ok(())
+            } else {
+                Self::resolve(roll, details)
+            }
+        }
+
+        /// Resolve a roll whose reveal deadline has passed: the participants who did not reveal
+        /// their seeds lose their deposits, the dice are rolled with the seeds revealed, if any,
+        /// and the creator gets the deposit of the roll back. Anyone may call it.
+        ///
+        /// The call is charged for every participant forfeiting the deposit upfront and refunded
+        /// for those who revealed.
+        #[pallet::call_index(3)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::resolve_roll(T::MaxParticipants::get()))]
+        pub fn resolve_roll(origin: OriginFor<T>, roll: RollId) -> DispatchResultWithPostInfo {
+            ensure_signed(origin)?;
+            let details = Rolls::<T>::get(roll).ok_or(Error::<T>::RollNotFound)?;
+            ensure!(
+                frame_system::Pallet::<T>::block_number() > details.reveal_deadline,
+                Error::<T>::RevealsStillOpen
+            );
+
+            let mut forfeited: u32 = 0;
+            for (who, seed) in details.participants.iter() {
+                if let Seed::Committed(_) = seed {
+                    let amount = T::Currency::burn_held(
+                        &HoldReason::SeedCommitment.into(),
+                        who,
+                        details.deposit,
+                        Precision::BestEffort,
+                        Fortitude::Force,
+                    )?;
+                    forfeited += 1;
+                    Self::deposit_event(Event::SeedForfeited { roll, who: who.clone(), amount });
+                }
+            }
+            Self::resolve(roll, details)?;
+            Ok(Some(<T as pallet::Config>::WeightInfo::resolve_roll(forfeited)).into())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// The seed of `who` in a roll, if a participant.
+        fn seed_of<'a>(
+            details: &'a mut RollOf<T>,
+            who: &T::AccountId,
+        ) -> Result<&'a mut Seed<T::Hash>, Error<T>> {
+            details
+                .participants
+                .iter_mut()
+                .find(|(participant, _)| participant == who)
+                .map(|(_, seed)| seed)
+                .ok_or(Error::<T>::NotParticipant)
+        }
+
+        /// Roll the dice of a roll with its revealed seeds, record the total in its target, if
+        /// any, and remove it, returning the deposit of the creator. Without any revealed seed,
+        /// and so without entropy, the roll is cancelled instead.
+        fn resolve(roll: RollId, details: RollOf<T>) -> DispatchResult {
+            Rolls::<T>::remove(roll);
+            T::Currency::release(
+                &HoldReason::RollCreation.into(),
+                &details.creator,
+                details.creator_deposit,
+                Precision::BestEffort,
+            )?;
+            let Some(entropy) = details.entropy else {
+                Self::deposit_event(Event::RollCancelled { roll });
+                return Ok(());
+            };
+            let seeds: Vec<[u8; 32]> = details
+                .participants
+                .iter()
+                .filter_map(|(_, seed)| match seed {
+                    Seed::Revealed(seed) => Some(*seed),
+                    _ => None,
+                })
+                .collect();
+            let seed = T::Hashing::hash_of(&(roll, &seeds, entropy));
+            let mut index: u32 = 0;
+            let (faces, total) = details.dice.roll(|sides| {
+                let hash = T::Hashing::hash_of(&(seed, index));
+                index += 1;
+                let random = u64::decode(&mut TrailingZeroInput::new(hash.as_ref()))
+                    .expect("infinite length input; no invalid inputs for type; qed");
+                // The bias of the modulo is negligible for dice far smaller than 2^64.
+                (random % sides as u64) as u32 + 1
+            });
+            Self::deposit_event(Event::Rolled { roll, dice: details.dice, faces, total });
+
+            if let Some((collection, item, key)) = details.target {
+                let value = format!("{total}");
+                let recorded = T::Attributes::set_attribute(
+                    &details.creator,
+                    &collection,
+                    &item,
+                    &key,
+                    value.as_bytes(),
+                );
+                if let Err(error) = recorded {
+                    Self::deposit_event(Event::RollNotRecorded { roll, error });
+                }
+            }
+            Ok(())
+        }
+
+        /// Check the invariants of the rolls: their participants are distinct, the entropy block
+        /// follows the commit deadline until every participant has committed, and its hash is
+        /// stored once a seed is revealed. Each account holds the deposits of the seeds it has
+        /// committed to and not revealed, and those of the rolls it has created.
+        #[cfg(any(feature = "try-runtime", test))]
+        pub fn do_try_state() -> Result<(), sp_runtime::TryRuntimeError> {
+            use alloc::collections::btree_map::BTreeMap;
+            use frame_support::traits::fungible::InspectHold;
+
+            let mut held: BTreeMap<T::AccountId, BalanceOf<T>> = BTreeMap::new();
+            let mut created: BTreeMap<T::AccountId, BalanceOf<T>> = BTreeMap::new();
+            for (roll, details) in Rolls::<T>::iter() {
+                let deposit = created.entry(details.creator.clone()).or_default();
+                *deposit = deposit.saturating_add(details.creator_deposit);
+                ensure!(roll < NextRollId::<T>::get(), "A roll has an identifier not assigned yet");
+                ensure!(!details.participants.is_empty(), "A roll has no participants");
+                ensure!(
+                    details.commit_deadline <= details.reveal_deadline,
+                    "A roll closes its reveals before its commitments"
+                );
+                ensure!(
+                    details.entropy_block <= details.commit_deadline.saturating_add(One::one()),
+                    "A roll takes its entropy from a block after the commit deadline"
+                );
+                let mut committed_by_all = true;
+                let mut revealed = false;
+                for (index, (who, seed)) in details.participants.iter().enumerate() {
+                    ensure!(
+                        !details.participants[..index].iter().any(|(other, _)| other == who),
+                        "A participant takes part twice in a roll"
+                    );
+                    match seed {
+                        Seed::Pending => committed_by_all = false,
+                        Seed::Committed(_) => {
+                            let deposit = held.entry(who.clone()).or_default();
+                            *deposit = deposit.saturating_add(details.deposit);
+                        }
+                        Seed::Revealed(_) => revealed = true,
+                    }
+                }
+                ensure!(
+                    committed_by_all
+                        || details.entropy_block == details.commit_deadline.saturating_add(One::one()),
+                    "The entropy block of a roll is fixed before every participant commits"
+                );
+                ensure!(
+                    details.entropy.is_some() == revealed,
+                    "The entropy of a roll is stored without a revealed seed"
+                );
+            }
+            for (who, deposit) in held {
+                ensure!(
+                    T::Currency::balance_on_hold(&HoldReason::SeedCommitment.into(), &who)
+                        == deposit,
+                    "An account does not hold the deposits of its commitments"
+                );
+            }
+            for (who, deposit) in created {
+                ensure!(
+                    T::Currency::balance_on_hold(&HoldReason::RollCreation.into(), &who) == deposit,
+                    "An account does not hold the deposits of the rolls it created"
+                );
+            }
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,6 @@
+pub mod dice__notation;
+pub mod dice__create_roll;
+pub mod dice__commit_seed;
+pub mod dice__reveal_seed;
+pub mod dice__resolve_roll;
+pub mod dice__try_state;
@@ -0,0 +1,121 @@
+#![allow(non_snake_case)]
+
+use super::super::*;
+use crate::mock::{
+    commitment, new_test_ext, notation, participants, Balances, Dice, RuntimeHoldReason,
+    RuntimeOrigin, System, Test,
+};
+use frame_support::{assert_noop, assert_ok, traits::fungible::InspectHold};
+use sp_runtime::TokenError;
+
+const DEPOSIT: u128 = 1_000_000_000_000;
+
+fn held(who: u64) -> u128 {
+    Balances::balance_on_hold(&RuntimeHoldReason::Dice(HoldReason::SeedCommitment), &who)
+}
+
+#[test]
+fn commits_with_a_deposit() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Dice::create_roll(
+            RuntimeOrigin::signed(1),
+            notation("1d20"),
+            participants(&[2, 3]),
+            None
+        ));
+
+        assert_ok!(Dice::commit_seed(RuntimeOrigin::signed(2), 0, commitment(2, [2; 32])));
+
+        System::assert_last_event(Event::<Test>::SeedCommitted { roll: 0, who: 2 }.into());
+        assert_eq!(held(2), DEPOSIT);
+        let roll = Rolls::<Test>::get(0).unwrap();
+        assert_eq!(roll.participants[0], (2, Seed::Committed(commitment(2, [2; 32]))));
+        assert_eq!(roll.participants[1], (3, Seed::Pending));
+        assert_eq!(roll.entropy_block, 7);
+        assert_eq!(roll.entropy, None);
+    });
+}
+
+#[test]
+fn last_commitment_fixes_the_entropy_block() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Dice::create_roll(
+            RuntimeOrigin::signed(1),
+            notation("1d20"),
+            participants(&[2, 3]),
+            None
+        ));
+        assert_ok!(Dice::commit_seed(RuntimeOrigin::signed(2), 0, commitment(2, [2; 32])));
+        System::set_block_number(3);
+
+        assert_ok!(Dice::commit_seed(RuntimeOrigin::signed(3), 0, commitment(3, [3; 32])));
+
+        // The hash of the next block, unknown to every participant, is read once it is produced.
+        let roll = Rolls::<Test>::get(0).unwrap();
+        assert_eq!(roll.entropy_block, 4);
+        assert_eq!(roll.entropy, None);
+    });
+}
+
+#[test]
+fn only_participants_commit_once() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Dice::create_roll(
+            RuntimeOrigin::signed(1),
+            notation("1d20"),
+            participants(&[2, 3]),
+            None
+        ));
+
+        assert_noop!(
+            Dice::commit_seed(RuntimeOrigin::signed(1), 0, commitment(1, [1; 32])),
+            Error::<Test>::NotParticipant
+        );
+        assert_noop!(
+            Dice::commit_seed(RuntimeOrigin::signed(2), 1, commitment(2, [2; 32])),
+            Error::<Test>::RollNotFound
+        );
+        assert_ok!(Dice::commit_seed(RuntimeOrigin::signed(2), 0, commitment(2, [2; 32])));
+        assert_noop!(
+            Dice::commit_seed(RuntimeOrigin::signed(2), 0, commitment(2, [9; 32])),
+            Error::<Test>::AlreadyCommitted
+        );
+    });
+}
+
+#[test]
+fn commitments_close_at_the_deadline() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Dice::create_roll(
+            RuntimeOrigin::signed(1),
+            notation("1d20"),
+            participants(&[2, 3]),
+            None
+        ));
+
+        System::set_block_number(6);
+        assert_ok!(Dice::commit_seed(RuntimeOrigin::signed(2), 0, commitment(2, [2; 32])));
+        System::set_block_number(7);
+        assert_noop!(
+            Dice::commit_seed(RuntimeOrigin::signed(3), 0, commitment(3, [3; 32])),
+            Error::<Test>::CommitmentsClosed
+        );
+    });
+}
+
+#[test]
+fn commitment_needs_the_deposit() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Dice::create_roll(
+            RuntimeOrigin::signed(1),
+            notation("1d20"),
+            participants(&[5]),
+            None
+        ));
+
+        assert_noop!(
+            Dice::commit_seed(RuntimeOrigin::signed(5), 0, commitment(5, [5; 32])),
+            TokenError::FundsUnavailable
+        );
+    });
+}
@@ -0,0 +1,158 @@
+#![allow(non_snake_case)]
+
+use super::super::*;
+use crate::mock::{
+    mint, new_test_ext, notation, participants, target, Balances, Dice, RollDeposit,
+    RuntimeHoldReason, RuntimeOrigin, System, Test, COLLECTION_OWNER,
+};
+use frame_support::{assert_noop, assert_ok, traits::fungible::InspectHold};
+use pallet_nft_attributes::Error as AttributesError;
+use sp_runtime::TokenError;
+
+#[test]
+fn creates_a_roll() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Dice::create_roll(
+            RuntimeOrigin::signed(1),
+            notation("3d6+2"),
+            participants(&[2, 3]),
+            None
+        ));
+
+        let dice = crate::Dice::parse(b"3d6+2").unwrap();
+        System::assert_last_event(
+            Event::<Test>::RollCreated {
+                roll: 0,
+                dice,
+                creator: 1,
+                commit_deadline: 6,
+                reveal_deadline: 12,
+            }
+            .into(),
+        );
+        let roll = Rolls::<Test>::get(0).unwrap();
+        assert_eq!(roll.creator, 1);
+        assert_eq!(roll.dice, dice);
+        assert_eq!(roll.participants.to_vec(), vec![(2, Seed::Pending), (3, Seed::Pending)]);
+        assert_eq!(roll.deposit, 1_000_000_000_000);
+        assert_eq!(roll.creator_deposit, RollDeposit::get());
+        assert_eq!(roll.entropy_block, 7);
+        assert_eq!(roll.entropy, None);
+        assert_eq!(roll.target, None);
+        assert_eq!(NextRollId::<Test>::get(), 1);
+    });
+}
+
+#[test]
+fn rolls_get_consecutive_identifiers() {
+    new_test_ext().execute_with(|| {
+        for _ in 0..3 {
+            assert_ok!(Dice::create_roll(
+                RuntimeOrigin::signed(1),
+                notation("1d20"),
+                participants(&[1]),
+                None
+            ));
+        }
+        assert!(Rolls::<Test>::contains_key(2));
+        assert_eq!(NextRollId::<Test>::get(), 3);
+    });
+}
+
+#[test]
+fn rejects_invalid_dice() {
+    new_test_ext().execute_with(|| {
+        for (dice, error) in [
+            ("3x6", Error::<Test>::InvalidNotation),
+            ("1d20adv", Error::<Test>::InvalidNotation),
+            ("11d6", Error::<Test>::TooManyDice),
+            ("1d101", Error::<Test>::TooManySides),
+        ] {
+            assert_noop!(
+                Dice::create_roll(RuntimeOrigin::signed(1), notation(dice), participants(&[2]), None),
+                error
+            );
+        }
+    });
+}
+
+#[test]
+fn rejects_invalid_participants() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Dice::create_roll(RuntimeOrigin::signed(1), notation("1d6"), participants(&[]), None),
+            Error::<Test>::NoParticipants
+        );
+        assert_noop!(
+            Dice::create_roll(
+                RuntimeOrigin::signed(1),
+                notation("1d6"),
+                participants(&[2, 3, 2]),
+                None
+            ),
+            Error::<Test>::DuplicateParticipant
+        );
+    });
+}
+
+#[test]
+fn only_the_game_master_targets_an_attribute() {
+    new_test_ext().execute_with(|| {
+        mint(1, 1, 2);
+
+        assert_noop!(
+            Dice::create_roll(
+                RuntimeOrigin::signed(2),
+                notation("1d20"),
+                participants(&[2]),
+                target(1, 1, b"initiative")
+            ),
+            AttributesError::<Test>::NotGameMaster
+        );
+        assert_noop!(
+            Dice::create_roll(
+                RuntimeOrigin::signed(COLLECTION_OWNER),
+                notation("1d20"),
+                participants(&[2]),
+                target(1, 2, b"initiative")
+            ),
+            AttributesError::<Test>::TokenNotFound
+        );
+        assert_ok!(Dice::create_roll(
+            RuntimeOrigin::signed(COLLECTION_OWNER),
+            notation("1d20"),
+            participants(&[2]),
+            target(1, 1, b"initiative")
+        ));
+        assert_eq!(Rolls::<Test>::get(0).unwrap().target, target(1, 1, b"initiative"));
+    });
+}
+
+#[test]
+fn holds_the_deposit_of_the_creator() {
+    new_test_ext().execute_with(|| {
+        for _ in 0..2 {
+            assert_ok!(Dice::create_roll(
+                RuntimeOrigin::signed(1),
+                notation("1d20"),
+                participants(&[2]),
+                None
+            ));
+        }
+        assert_eq!(
+            Balances::balance_on_hold(&RuntimeHoldReason::Dice(HoldReason::RollCreation), &1),
+            2 * RollDeposit::get()
+        );
+
+        let poor = 5u64;
+        assert_noop!(
+            Dice::create_roll(
+                RuntimeOrigin::signed(poor),
+                notation("1d20"),
+                participants(&[2]),
+                None
+            ),
+            TokenError::FundsUnavailable
+        );
+    });
+}
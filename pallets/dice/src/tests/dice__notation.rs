@@ -0,0 +1,74 @@
+#![allow(non_snake_case)]
+
+use crate::{Dice, RollMode};
+
+fn dice(count: u32, sides: u32, modifier: i32, mode: RollMode) -> Option<Dice> {
+    Some(Dice { count, sides, modifier, mode })
+}
+
+#[test]
+fn parses_standard_notation() {
+    assert_eq!(Dice::parse(b"3d6+2"), dice(3, 6, 2, RollMode::Normal));
+    assert_eq!(Dice::parse(b"2d8-1"), dice(2, 8, -1, RollMode::Normal));
+    assert_eq!(Dice::parse(b"d20"), dice(1, 20, 0, RollMode::Normal));
+    assert_eq!(Dice::parse(b"4D10"), dice(4, 10, 0, RollMode::Normal));
+    assert_eq!(Dice::parse(b" 1d20 + 5 "), dice(1, 20, 5, RollMode::Normal));
+    assert_eq!(Dice::parse(b"1d20 adv"), dice(1, 20, 0, RollMode::Advantage));
+    assert_eq!(Dice::parse(b"1d20+3 dis"), dice(1, 20, 3, RollMode::Disadvantage));
+}
+
+#[test]
+fn rejects_invalid_notation() {
+    for notation in [
+        &b""[..],
+        b"20",
+        b"d",
+        b"3d",
+        b"0d6",
+        b"1d1",
+        b"1d0",
+        b"xd6",
+        b"1d6+",
+        b"1d6+x",
+        b"1d20adv",
+        b"adv",
+        b"1d20 fast",
+        b"1d6+1+1",
+        b"99999999999d6",
+        b"1d6+99999999999",
+    ] {
+        assert_eq!(Dice::parse(notation), None, "{}", String::from_utf8_lossy(notation));
+    }
+}
+
+#[test]
+fn sums_the_dice_with_the_modifier() {
+    let dice = Dice::parse(b"3d6+2").unwrap();
+    let mut faces = [4, 1, 6].into_iter();
+    assert_eq!(dice.roll(|_| faces.next().unwrap()), (vec![4, 1, 6], 13));
+}
+
+#[test]
+fn keeps_the_higher_throw_with_advantage() {
+    let dice = Dice::parse(b"2d6-1 adv").unwrap();
+    let mut faces = [1, 2, 5, 6].into_iter();
+    assert_eq!(dice.roll(|_| faces.next().unwrap()), (vec![1, 2, 5, 6], 10));
+}
+
+#[test]
+fn keeps_the_lower_throw_with_disadvantage() {
+    let dice = Dice::parse(b"1d20 dis").unwrap();
+    let mut faces = [17, 3].into_iter();
+    assert_eq!(dice.roll(|_| faces.next().unwrap()), (vec![17, 3], 3));
+    assert_eq!(dice.thrown(), 2);
+}
+
+#[test]
+fn throws_dice_of_the_given_sides() {
+    let dice = Dice::parse(b"5d12").unwrap();
+    let (faces, _) = dice.roll(|sides| {
+        assert_eq!(sides, 12);
+        sides
+    });
+    assert_eq!(faces.len(), 5);
+}
@@ -0,0 +1,174 @@
+#![allow(non_snake_case)]
+
+use super::super::*;
+use crate::mock::{
+    commitment, mint, new_test_ext, notation, participants, run_to_block, target, Balances, Dice,
+    NftAttributes, RollDeposit, RuntimeEvent, RuntimeHoldReason, RuntimeOrigin, System, Test,
+    COLLECTION_OWNER,
+};
+use frame_support::{
+    assert_noop, assert_ok,
+    traits::fungible::{Inspect, InspectHold},
+};
+use pallet_nft_attributes::Error as AttributesError;
+
+const DEPOSIT: u128 = 1_000_000_000_000;
+
+fn held(who: u64) -> u128 {
+    Balances::balance_on_hold(&RuntimeHoldReason::Dice(HoldReason::SeedCommitment), &who)
+}
+
+fn held_for_rolls(who: u64) -> u128 {
+    Balances::balance_on_hold(&RuntimeHoldReason::Dice(HoldReason::RollCreation), &who)
+}
+
+/// The total of the last roll.
+fn total() -> i64 {
+    System::events()
+        .into_iter()
+        .find_map(|record| match record.event {
+            RuntimeEvent::Dice(Event::Rolled { total, .. }) => Some(total),
+            _ => None,
+        })
+        .expect("the dice were rolled")
+}
+
+/// Create a roll of a d20 among the accounts 2 and 3, recorded in `target` if any, and commit
+/// both of them. The reveals open in the block 3.
+fn a_committed_roll(target: Option<TargetOf<Test>>) {
+    assert_ok!(Dice::create_roll(
+        RuntimeOrigin::signed(COLLECTION_OWNER),
+        notation("1d20+1"),
+        participants(&[2, 3]),
+        target
+    ));
+    assert_ok!(Dice::commit_seed(RuntimeOrigin::signed(2), 0, commitment(2, [2; 32])));
+    assert_ok!(Dice::commit_seed(RuntimeOrigin::signed(3), 0, commitment(3, [3; 32])));
+    run_to_block(3);
+}
+
+#[test]
+fn non_revealers_forfeit_their_deposits() {
+    new_test_ext().execute_with(|| {
+        a_committed_roll(None);
+        assert_ok!(Dice::reveal_seed(RuntimeOrigin::signed(2), 0, [2; 32]));
+        let balance = Balances::total_balance(&3);
+        let issuance = Balances::total_issuance();
+
+        run_to_block(13);
+        assert_ok!(Dice::resolve_roll(RuntimeOrigin::signed(4), 0));
+
+        System::assert_has_event(
+            Event::<Test>::SeedForfeited { roll: 0, who: 3, amount: DEPOSIT }.into(),
+        );
+        assert_eq!(held(3), 0);
+        assert_eq!(Balances::total_balance(&3), balance - DEPOSIT);
+        assert_eq!(Balances::total_issuance(), issuance - DEPOSIT);
+        assert!(!Rolls::<Test>::contains_key(0));
+        assert!((2..=21).contains(&total()));
+    });
+}
+
+#[test]
+fn a_roll_without_reveals_is_cancelled() {
+    new_test_ext().execute_with(|| {
+        a_committed_roll(None);
+
+        run_to_block(13);
+        assert_ok!(Dice::resolve_roll(RuntimeOrigin::signed(4), 0));
+
+        System::assert_last_event(Event::<Test>::RollCancelled { roll: 0 }.into());
+        assert_eq!(held(2), 0);
+        assert_eq!(held(3), 0);
+        assert!(!Rolls::<Test>::contains_key(0));
+    });
+}
+
+#[test]
+fn resolves_after_the_reveal_deadline() {
+    new_test_ext().execute_with(|| {
+        a_committed_roll(None);
+
+        run_to_block(12);
+        assert_noop!(
+            Dice::resolve_roll(RuntimeOrigin::signed(4), 0),
+            Error::<Test>::RevealsStillOpen
+        );
+        run_to_block(13);
+        assert_noop!(
+            Dice::resolve_roll(RuntimeOrigin::signed(4), 1),
+            Error::<Test>::RollNotFound
+        );
+        assert_ok!(Dice::resolve_roll(RuntimeOrigin::signed(4), 0));
+    });
+}
+
+#[test]
+fn records_the_total_in_the_target() {
+    new_test_ext().execute_with(|| {
+        mint(1, 1, 2);
+        a_committed_roll(target(1, 1, b"initiative"));
+
+        assert_ok!(Dice::reveal_seed(RuntimeOrigin::signed(2), 0, [2; 32]));
+        assert_ok!(Dice::reveal_seed(RuntimeOrigin::signed(3), 0, [3; 32]));
+
+        let total = format!("{}", total());
+        assert_eq!(
+            NftAttributes::attributes(1, 1),
+            vec![(b"initiative".to_vec(), total.into_bytes())]
+        );
+    });
+}
+
+#[test]
+fn reports_a_total_it_could_not_record() {
+    new_test_ext().execute_with(|| {
+        mint(1, 1, 2);
+        a_committed_roll(target(1, 1, b"initiative"));
+        assert_ok!(NftAttributes::freeze_attributes(
+            RuntimeOrigin::signed(COLLECTION_OWNER),
+            1,
+            Some(1),
+            false
+        ));
+
+        assert_ok!(Dice::reveal_seed(RuntimeOrigin::signed(2), 0, [2; 32]));
+        assert_ok!(Dice::reveal_seed(RuntimeOrigin::signed(3), 0, [3; 32]));
+
+        System::assert_last_event(
+            Event::<Test>::RollNotRecorded {
+                roll: 0,
+                error: AttributesError::<Test>::AttributesFrozen.into(),
+            }
+            .into(),
+        );
+        assert!(NftAttributes::attributes(1, 1).is_empty());
+    });
+}
+
+#[test]
+fn returns_the_deposit_of_the_creator() {
+    new_test_ext().execute_with(|| {
+        a_committed_roll(None);
+        assert_eq!(held_for_rolls(COLLECTION_OWNER), RollDeposit::get());
+        let balance = Balances::total_balance(&COLLECTION_OWNER);
+
+        // Anyone may clean up a roll whose reveals expired, which returns the deposit.
+        run_to_block(13);
+        assert_ok!(Dice::resolve_roll(RuntimeOrigin::signed(4), 0));
+        assert_eq!(held_for_rolls(COLLECTION_OWNER), 0);
+        assert_eq!(Balances::total_balance(&COLLECTION_OWNER), balance);
+    });
+}
+
+#[test]
+fn returns_the_deposit_of_the_creator_once_every_seed_is_revealed() {
+    new_test_ext().execute_with(|| {
+        a_committed_roll(None);
+
+        assert_ok!(Dice::reveal_seed(RuntimeOrigin::signed(2), 0, [2; 32]));
+        assert_eq!(held_for_rolls(COLLECTION_OWNER), RollDeposit::get());
+        assert_ok!(Dice::reveal_seed(RuntimeOrigin::signed(3), 0, [3; 32]));
+        assert_eq!(held_for_rolls(COLLECTION_OWNER), 0);
+    });
+}
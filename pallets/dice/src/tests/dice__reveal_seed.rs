@@ -0,0 +1,207 @@
+#![allow(non_snake_case)]
+
+use super::super::*;
+use crate::mock::{
+    block_hash, commitment, new_test_ext, run_to_block, notation, participants, Balances, Dice, RuntimeEvent,
+    RuntimeHoldReason, RuntimeOrigin, System, Test,
+};
+use frame_support::{assert_noop, assert_ok, traits::fungible::InspectHold};
+
+fn held(who: u64) -> u128 {
+    Balances::balance_on_hold(&RuntimeHoldReason::Dice(HoldReason::SeedCommitment), &who)
+}
+
+/// Create a roll of `dice` among the accounts 2 and 3, and commit both of them in the block 1, so
+/// that the reveals open in the block 3.
+fn a_committed_roll(dice: &str) {
+    assert_ok!(Dice::create_roll(
+        RuntimeOrigin::signed(1),
+        notation(dice),
+        participants(&[2, 3]),
+        None
+    ));
+    assert_ok!(Dice::commit_seed(RuntimeOrigin::signed(2), 0, commitment(2, [2; 32])));
+    assert_ok!(Dice::commit_seed(RuntimeOrigin::signed(3), 0, commitment(3, [3; 32])));
+}
+
+/// The faces and the total of the last roll.
+fn rolled() -> (Vec<u32>, i64) {
+    System::events()
+        .into_iter()
+        .find_map(|record| match record.event {
+            RuntimeEvent::Dice(Event::Rolled { faces, total, .. }) => Some((faces, total)),
+            _ => None,
+        })
+        .expect("the dice were rolled")
+}
+
+#[test]
+fn reveals_return_the_deposits_and_roll_the_dice() {
+    new_test_ext().execute_with(|| {
+        a_committed_roll("3d6+2");
+        run_to_block(3);
+
+        assert_ok!(Dice::reveal_seed(RuntimeOrigin::signed(2), 0, [2; 32]));
+        System::assert_last_event(Event::<Test>::SeedRevealed { roll: 0, who: 2 }.into());
+        assert_eq!(held(2), 0);
+        assert_eq!(Rolls::<Test>::get(0).unwrap().participants[0], (2, Seed::Revealed([2; 32])));
+
+        assert_ok!(Dice::reveal_seed(RuntimeOrigin::signed(3), 0, [3; 32]));
+        assert_eq!(held(3), 0);
+        assert!(!Rolls::<Test>::contains_key(0));
+        let (faces, total) = rolled();
+        assert_eq!(faces.len(), 3);
+        assert!(faces.iter().all(|face| (1..=6).contains(face)));
+        assert_eq!(total, faces.iter().map(|face| *face as i64).sum::<i64>() + 2);
+    });
+}
+
+#[test]
+fn the_same_seeds_roll_the_same_dice() {
+    let roll = || {
+        let mut result = None;
+        new_test_ext().execute_with(|| {
+            a_committed_roll("10d100 adv");
+            run_to_block(3);
+            assert_ok!(Dice::reveal_seed(RuntimeOrigin::signed(3), 0, [3; 32]));
+            assert_ok!(Dice::reveal_seed(RuntimeOrigin::signed(2), 0, [2; 32]));
+            result = Some(rolled());
+        });
+        result.unwrap()
+    };
+
+    let (faces, total) = roll();
+    assert_eq!(faces.len(), 20);
+    assert_eq!(roll(), (faces.clone(), total));
+    // Twenty dice of a hundred faces do not all fall on the same face.
+    assert!(faces.iter().any(|face| *face != faces[0]));
+}
+
+#[test]
+fn a_different_seed_rolls_different_dice() {
+    let roll = |seed: [u8; 32]| {
+        let mut result = None;
+        new_test_ext().execute_with(|| {
+            assert_ok!(Dice::create_roll(
+                RuntimeOrigin::signed(1),
+                notation("10d100"),
+                participants(&[2]),
+                None
+            ));
+            assert_ok!(Dice::commit_seed(RuntimeOrigin::signed(2), 0, commitment(2, seed)));
+            run_to_block(3);
+            assert_ok!(Dice::reveal_seed(RuntimeOrigin::signed(2), 0, seed));
+            result = Some(rolled());
+        });
+        result.unwrap()
+    };
+
+    assert_ne!(roll([1; 32]).0, roll([2; 32]).0);
+}
+
+#[test]
+fn the_seed_must_match_the_commitment() {
+    new_test_ext().execute_with(|| {
+        a_committed_roll("1d20");
+        run_to_block(3);
+
+        assert_noop!(
+            Dice::reveal_seed(RuntimeOrigin::signed(2), 0, [3; 32]),
+            Error::<Test>::WrongReveal
+        );
+        assert_noop!(
+            Dice::reveal_seed(RuntimeOrigin::signed(4), 0, [4; 32]),
+            Error::<Test>::NotParticipant
+        );
+        assert_ok!(Dice::reveal_seed(RuntimeOrigin::signed(2), 0, [2; 32]));
+        assert_noop!(
+            Dice::reveal_seed(RuntimeOrigin::signed(2), 0, [2; 32]),
+            Error::<Test>::NotCommitted
+        );
+    });
+}
+
+#[test]
+fn reveals_open_once_the_block_after_the_last_commitment_is_produced() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Dice::create_roll(
+            RuntimeOrigin::signed(1),
+            notation("1d20"),
+            participants(&[2, 3]),
+            None
+        ));
+        assert_ok!(Dice::commit_seed(RuntimeOrigin::signed(2), 0, commitment(2, [2; 32])));
+
+        assert_noop!(
+            Dice::reveal_seed(RuntimeOrigin::signed(2), 0, [2; 32]),
+            Error::<Test>::RevealsNotOpen
+        );
+        run_to_block(2);
+        assert_ok!(Dice::commit_seed(RuntimeOrigin::signed(3), 0, commitment(3, [3; 32])));
+        // The hash of the block 3 is not known before the block 4.
+        run_to_block(3);
+        assert_noop!(
+            Dice::reveal_seed(RuntimeOrigin::signed(2), 0, [2; 32]),
+            Error::<Test>::RevealsNotOpen
+        );
+        run_to_block(4);
+        assert_ok!(Dice::reveal_seed(RuntimeOrigin::signed(2), 0, [2; 32]));
+    });
+}
+
+#[test]
+fn the_first_reveal_stores_the_hash_of_the_entropy_block() {
+    new_test_ext().execute_with(|| {
+        a_committed_roll("1d20");
+        run_to_block(9);
+
+        assert_ok!(Dice::reveal_seed(RuntimeOrigin::signed(2), 0, [2; 32]));
+        assert_eq!(Rolls::<Test>::get(0).unwrap().entropy, Some(block_hash(2)));
+
+        // Later blocks do not change it.
+        frame_system::BlockHash::<Test>::insert(2, block_hash(99));
+        run_to_block(10);
+        assert_ok!(Dice::reveal_seed(RuntimeOrigin::signed(3), 0, [3; 32]));
+        assert!(!Rolls::<Test>::contains_key(0));
+    });
+}
+
+#[test]
+fn reveals_open_after_the_commit_deadline() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Dice::create_roll(
+            RuntimeOrigin::signed(1),
+            notation("1d20"),
+            participants(&[2, 3]),
+            None
+        ));
+        assert_ok!(Dice::commit_seed(RuntimeOrigin::signed(2), 0, commitment(2, [2; 32])));
+
+        // The entropy comes from the block after the commit deadline.
+        run_to_block(7);
+        assert_noop!(
+            Dice::reveal_seed(RuntimeOrigin::signed(2), 0, [2; 32]),
+            Error::<Test>::RevealsNotOpen
+        );
+        run_to_block(8);
+        assert_ok!(Dice::reveal_seed(RuntimeOrigin::signed(2), 0, [2; 32]));
+        // The participant who never committed does not hold the roll up.
+        assert!(!Rolls::<Test>::contains_key(0));
+        assert_eq!(rolled().0.len(), 1);
+    });
+}
+
+#[test]
+fn reveals_close_at_the_deadline() {
+    new_test_ext().execute_with(|| {
+        a_committed_roll("1d20");
+
+        run_to_block(12);
+        assert_ok!(Dice::reveal_seed(RuntimeOrigin::signed(2), 0, [2; 32]));
+        run_to_block(13);
+        assert_noop!(
+            Dice::reveal_seed(RuntimeOrigin::signed(3), 0, [3; 32]),
+            Error::<Test>::RevealsClosed
+        );
+    });
+}
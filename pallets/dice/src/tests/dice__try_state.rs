@@ -0,0 +1,107 @@
+#![allow(non_snake_case)]
+
+use super::super::*;
+use crate::mock::{
+    commitment, new_test_ext, new_unchecked_test_ext, notation, participants, Dice,
+    RuntimeOrigin, Test,
+};
+use frame_support::{assert_err, assert_ok};
+
+/// Create a roll among the accounts 2 and 3, and commit the account 2.
+fn a_roll() {
+    assert_ok!(Dice::create_roll(
+        RuntimeOrigin::signed(1),
+        notation("1d20"),
+        participants(&[2, 3]),
+        None
+    ));
+    assert_ok!(Dice::commit_seed(RuntimeOrigin::signed(2), 0, commitment(2, [2; 32])));
+}
+
+#[test]
+fn holds_for_the_rolls_in_progress() {
+    new_test_ext().execute_with(|| {
+        a_roll();
+        assert_ok!(Dice::do_try_state());
+    });
+}
+
+#[test]
+fn detects_a_roll_with_an_unassigned_identifier() {
+    new_unchecked_test_ext().execute_with(|| {
+        a_roll();
+        NextRollId::<Test>::put(0);
+        assert_err!(Dice::do_try_state(), "A roll has an identifier not assigned yet");
+    });
+}
+
+#[test]
+fn detects_a_duplicate_participant() {
+    new_unchecked_test_ext().execute_with(|| {
+        a_roll();
+        Rolls::<Test>::mutate(0, |roll| roll.as_mut().unwrap().participants[1].0 = 2);
+        assert_err!(Dice::do_try_state(), "A participant takes part twice in a roll");
+    });
+}
+
+#[test]
+fn detects_an_entropy_block_fixed_too_early() {
+    new_unchecked_test_ext().execute_with(|| {
+        a_roll();
+        Rolls::<Test>::mutate(0, |roll| roll.as_mut().unwrap().entropy_block = 2);
+        assert_err!(
+            Dice::do_try_state(),
+            "The entropy block of a roll is fixed before every participant commits"
+        );
+    });
+}
+
+#[test]
+fn detects_an_entropy_block_after_the_commit_deadline() {
+    new_unchecked_test_ext().execute_with(|| {
+        a_roll();
+        Rolls::<Test>::mutate(0, |roll| roll.as_mut().unwrap().entropy_block = 8);
+        assert_err!(
+            Dice::do_try_state(),
+            "A roll takes its entropy from a block after the commit deadline"
+        );
+    });
+}
+
+#[test]
+fn detects_entropy_stored_before_any_reveal() {
+    new_unchecked_test_ext().execute_with(|| {
+        a_roll();
+        Rolls::<Test>::mutate(0, |roll| roll.as_mut().unwrap().entropy = Some([1; 32].into()));
+        assert_err!(
+            Dice::do_try_state(),
+            "The entropy of a roll is stored without a revealed seed"
+        );
+    });
+}
+
+#[test]
+fn detects_a_missing_deposit() {
+    new_unchecked_test_ext().execute_with(|| {
+        a_roll();
+        Rolls::<Test>::mutate(0, |roll| {
+            roll.as_mut().unwrap().participants[1].1 = Seed::Committed(commitment(3, [3; 32]));
+        });
+        assert_err!(
+            Dice::do_try_state(),
+            "An account does not hold the deposits of its commitments"
+        );
+    });
+}
+
+#[test]
+fn detects_a_missing_deposit_of_the_creator() {
+    new_unchecked_test_ext().execute_with(|| {
+        a_roll();
+        Rolls::<Test>::mutate(0, |roll| roll.as_mut().unwrap().creator_deposit += 1);
+        assert_err!(
+            Dice::do_try_state(),
+            "An account does not hold the deposits of the rolls it created"
+        );
+    });
+}
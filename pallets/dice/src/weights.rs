@@ -0,0 +1,180 @@
+//! Weights for `pallet_dice`.
+//!
+//! PROVISIONAL: these weights were not produced by the node's `benchmark pallet` command nor on
+//! the reference hardware. They were measured by running the benchmarks of `benchmarking.rs`
+//! natively against the runtime, with 50 steps and 20 repeats, and counting the storage
+//! accesses of the worst case of each call. Regenerate them before any production deployment:
+//!
+//! ./target/release/telurean-chain-node benchmark pallet \
+//!     --runtime ./target/release/wbuild/telurean-chain-runtime/telurean_chain_runtime.wasm \
+//!     --genesis-builder=runtime --pallet pallet_dice --extrinsic '*' \
+//!     --steps 50 --repeat 20 --output pallets/dice/src/weights.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `pallet_dice`.
+pub trait WeightInfo {
+	fn create_roll(p: u32, ) -> Weight;
+	fn commit_seed() -> Weight;
+	fn reveal_seed() -> Weight;
+	fn resolve_roll(p: u32, ) -> Weight;
+}
+
+/// Weights for `pallet_dice`, with storage accesses priced by the database weights of the runtime.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `NftAttributes::GameMasters` (r:1 w:0)
+	/// Storage: `Uniques::Class` (r:1 w:0)
+	/// Storage: `Uniques::Asset` (r:1 w:0)
+	/// Storage: `NftAttributes::FrozenCollections` (r:1 w:0)
+	/// Storage: `NftAttributes::FrozenItems` (r:1 w:0)
+	/// Storage: `Dice::NextRollId` (r:1 w:1)
+	/// Storage: `Dice::Rolls` (r:0 w:1)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// The range of component `p` is `[1, 8]`.
+	fn create_roll(_p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `509`
+		// Minimum execution time: 22_374_000 picoseconds.
+		Weight::from_parts(23_581_785, 509)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Dice::Rolls` (r:1 w:1)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	fn commit_seed() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `932`
+		// Minimum execution time: 18_087_000 picoseconds.
+		Weight::from_parts(18_528_000, 932)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Dice::Rolls` (r:1 w:1)
+	/// Storage: `Balances::Holds` (r:2 w:2)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `System::BlockHash` (r:1 w:0)
+	/// Storage: `NftAttributes::GameMasters` (r:1 w:0)
+	/// Storage: `Uniques::Class` (r:1 w:0)
+	/// Storage: `Uniques::Asset` (r:1 w:0)
+	/// Storage: `NftAttributes::FrozenCollections` (r:1 w:0)
+	/// Storage: `NftAttributes::FrozenItems` (r:1 w:0)
+	/// Storage: `NftAttributes::Attributes` (r:1 w:1)
+	/// Storage: `NftAttributes::CollectionDefaults` (r:1 w:0)
+	/// Storage: `NftAttributes::ItemTemplates` (r:1 w:0)
+	/// Storage: `NftAttributes::Templates` (r:1 w:0)
+	fn reveal_seed() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `10851`
+		// Minimum execution time: 96_916_000 picoseconds.
+		Weight::from_parts(103_715_000, 10851)
+			.saturating_add(T::DbWeight::get().reads(14_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `Dice::Rolls` (r:1 w:1)
+	/// Storage: `Balances::Holds` (r:9 w:9)
+	/// Storage: `System::Account` (r:9 w:9)
+	/// Storage: `NftAttributes::GameMasters` (r:1 w:0)
+	/// Storage: `Uniques::Class` (r:1 w:0)
+	/// Storage: `Uniques::Asset` (r:1 w:0)
+	/// Storage: `NftAttributes::FrozenCollections` (r:1 w:0)
+	/// Storage: `NftAttributes::FrozenItems` (r:1 w:0)
+	/// Storage: `NftAttributes::Attributes` (r:1 w:1)
+	/// Storage: `NftAttributes::CollectionDefaults` (r:1 w:0)
+	/// Storage: `NftAttributes::ItemTemplates` (r:1 w:0)
+	/// Storage: `NftAttributes::Templates` (r:1 w:0)
+	/// The range of component `p` is `[1, 8]`.
+	fn resolve_roll(p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `13686`
+		// Minimum execution time: 90_646_000 picoseconds.
+		Weight::from_parts(107_494_121, 13686)
+			.saturating_add(Weight::from_parts(3_358_328, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(12_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(p.into())))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(p.into())))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	/// Storage: `NftAttributes::GameMasters` (r:1 w:0)
+	/// Storage: `Uniques::Class` (r:1 w:0)
+	/// Storage: `Uniques::Asset` (r:1 w:0)
+	/// Storage: `NftAttributes::FrozenCollections` (r:1 w:0)
+	/// Storage: `NftAttributes::FrozenItems` (r:1 w:0)
+	/// Storage: `Dice::NextRollId` (r:1 w:1)
+	/// Storage: `Dice::Rolls` (r:0 w:1)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// The range of component `p` is `[1, 8]`.
+	fn create_roll(_p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `509`
+		// Minimum execution time: 22_374_000 picoseconds.
+		Weight::from_parts(23_581_785, 509)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Dice::Rolls` (r:1 w:1)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	fn commit_seed() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `932`
+		// Minimum execution time: 18_087_000 picoseconds.
+		Weight::from_parts(18_528_000, 932)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Dice::Rolls` (r:1 w:1)
+	/// Storage: `Balances::Holds` (r:2 w:2)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `System::BlockHash` (r:1 w:0)
+	/// Storage: `NftAttributes::GameMasters` (r:1 w:0)
+	/// Storage: `Uniques::Class` (r:1 w:0)
+	/// Storage: `Uniques::Asset` (r:1 w:0)
+	/// Storage: `NftAttributes::FrozenCollections` (r:1 w:0)
+	/// Storage: `NftAttributes::FrozenItems` (r:1 w:0)
+	/// Storage: `NftAttributes::Attributes` (r:1 w:1)
+	/// Storage: `NftAttributes::CollectionDefaults` (r:1 w:0)
+	/// Storage: `NftAttributes::ItemTemplates` (r:1 w:0)
+	/// Storage: `NftAttributes::Templates` (r:1 w:0)
+	fn reveal_seed() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `10851`
+		// Minimum execution time: 96_916_000 picoseconds.
+		Weight::from_parts(103_715_000, 10851)
+			.saturating_add(RocksDbWeight::get().reads(14_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `Dice::Rolls` (r:1 w:1)
+	/// Storage: `Balances::Holds` (r:9 w:9)
+	/// Storage: `System::Account` (r:9 w:9)
+	/// Storage: `NftAttributes::GameMasters` (r:1 w:0)
+	/// Storage: `Uniques::Class` (r:1 w:0)
+	/// Storage: `Uniques::Asset` (r:1 w:0)
+	/// Storage: `NftAttributes::FrozenCollections` (r:1 w:0)
+	/// Storage: `NftAttributes::FrozenItems` (r:1 w:0)
+	/// Storage: `NftAttributes::Attributes` (r:1 w:1)
+	/// Storage: `NftAttributes::CollectionDefaults` (r:1 w:0)
+	/// Storage: `NftAttributes::ItemTemplates` (r:1 w:0)
+	/// Storage: `NftAttributes::Templates` (r:1 w:0)
+	/// The range of component `p` is `[1, 8]`.
+	fn resolve_roll(p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `13686`
+		// Minimum execution time: 90_646_000 picoseconds.
+		Weight::from_parts(107_494_121, 13686)
+			.saturating_add(Weight::from_parts(3_358_328, 0).saturating_mul(p.into()))
+			.saturating_add(RocksDbWeight::get().reads(12_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(p.into())))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(p.into())))
+	}
+}
@@ -0,0 +1,49 @@
+//! Types shared by the NFT attributes pallet and its runtime API, kept apart so that clients of
+//! the API do not depend on the whole pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use codec::{Decode, Encode};
+use scale_info::TypeInfo;
+
+/// Parse an attribute value as a signed decimal number, such as `14`, `+2` or `-1`.
+pub fn parse_number(value: &[u8]) -> Option<i64> {
+    let (negative, digits) = match value.split_first()? {
+        (b'+', rest) => (false, rest),
+        (b'-', rest) => (true, rest),
+        _ => (false, value),
+    };
+    if digits.is_empty() {
+        return None;
+    }
+    let mut number: i64 = 0;
+    for digit in digits {
+        if !digit.is_ascii_digit() {
+            return None;
+        }
+        number = number.checked_mul(10)?.checked_add((digit - b'0') as i64)?;
+    }
+    Some(if negative { -number } else { number })
+}
+
+/// Condition on the value of an attribute, used to search the items of a collection.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub enum AttributeFilter {
+    /// The value is exactly the given bytes.
+    Equals(Vec<u8>),
+    /// The value is a number within the inclusive bounds. A missing bound leaves that side open.
+    Range { min: Option<i64>, max: Option<i64> },
+}
+impl AttributeFilter {
+    pub fn matches(&self, value: &[u8]) -> bool {
+        match self {
+            AttributeFilter::Equals(expected) => value == &expected[..],
+            AttributeFilter::Range { min, max } => parse_number(value).is_some_and(|number| {
+                min.is_none_or(|min| number >= min) && max.is_none_or(|max| number <= max)
+            }),
+        }
+    }
+}
@@ -0,0 +1,42 @@
+//! Runtime API definition for the NFT attributes pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use codec::Codec;
+
+pub use pallet_nft_attributes_primitives::AttributeFilter;
+
+sp_api::decl_runtime_apis! {
+    /// Read access to the attributes of NFTs, so that clients neither build storage keys by hand
+    /// nor compute derived values on their own.
+    pub trait NftAttributesApi<CollectionId, ItemId>
+    where
+        CollectionId: Codec,
+        ItemId: Codec,
+    {
+        /// All the attributes of an item, as key-value pairs, including those it inherits.
+        fn attributes(collection: CollectionId, item: ItemId) -> Vec<(Vec<u8>, Vec<u8>)>;
+
+        /// The value of a single attribute of an item, looked up in the item itself, then in its
+        /// template and finally in the collection defaults.
+        fn attribute(collection: CollectionId, item: ItemId, key: Vec<u8>) -> Option<Vec<u8>>;
+
+        /// Items of a collection whose attribute `key` matches `filter`, one page at a time. Each
+        /// page comes with the cursor to pass as `start_after` to get the next one, which is
+        /// `None` once the whole collection has been searched.
+        fn items_matching(
+            collection: CollectionId,
+            key: Vec<u8>,
+            filter: AttributeFilter,
+            start_after: Option<ItemId>,
+            limit: u32,
+        ) -> (Vec<ItemId>, Option<ItemId>);
+
+        /// Attributes of an NFT once the modifiers of the assets it owns have been added to its
+        /// own attributes.
+        fn effective_attributes(collection: CollectionId, item: ItemId) -> Vec<(Vec<u8>, Vec<u8>)>;
+    }
+}
@@ -1,23 +1,326 @@
+//! Benchmarking setup for pallet-nft-attributes
+
 use super::*;
 
 #[allow(unused)]
-use crate::Pallet as Attributes;
+use crate::Pallet as NftAttributes;
+use alloc::{boxed::Box, vec};
 use frame_benchmarking::v2::*;
+use frame_support::{
+    traits::{
+        fungible::{Inspect, Mutate, MutateHold},
+        Get,
+    },
+    BoundedVec,
+};
 use frame_system::RawOrigin;
+use pallet_nft_hierarchy::BenchmarkHelper as _;
+use sp_runtime::traits::{Hash, IdentifyAccount, Saturating};
+
+/// Create a collection owned by `owner` and mint one item of it for `owner`.
+fn item<T: Config>(owner: &T::AccountId) -> (T::CollectionId, T::ItemId) {
+    let (collection, item) = (T::CollectionId::from(0), T::ItemId::from(0));
+    T::NftsHelper::mint(&collection, &item, owner);
+    (collection, item)
+}
+
+/// Give `who` enough balance to commit to as many hidden attributes as an item can hold.
+fn fund<T: Config>(who: &T::AccountId) {
+    let deposits = T::CommitmentDeposit::get().saturating_mul(T::AttributeLimit::get().into());
+    T::Currency::set_balance(who, T::Currency::minimum_balance().saturating_add(deposits));
+}
+
+/// Commit `committer` to the value filled with `b'h'` of the attribute `key` of an item, with a
+/// ciphertext of the maximum length, holding the deposit.
+fn commit<T: Config>(
+    committer: &T::AccountId,
+    collection: &T::CollectionId,
+    item: &T::ItemId,
+    key: AttributeKey<T>,
+    salt: [u8; 32],
+) {
+    let deposit = T::CommitmentDeposit::get();
+    T::Currency::hold(&HoldReason::AttributeCommitment.into(), committer, deposit).unwrap();
+    Commitments::<T>::insert(
+        (collection.clone(), *item, key.clone()),
+        Commitment {
+            committer: committer.clone(),
+            hash: T::Hashing::hash_of(&(&key, &value::<T>(b'h'), &salt)),
+            ciphertext: Some(BoundedVec::truncate_from(vec![
+                b'e';
+                T::CiphertextLimit::get() as usize
+            ])),
+            deposit,
+        },
+    );
+}
+
+/// Key of the maximum length, unique for each index.
+fn key<T: Config>(index: u32) -> AttributeKey<T> {
+    let mut key = index.to_le_bytes().to_vec();
+    key.resize(<T as Config>::KeyLimit::get() as usize, b'k');
+    BoundedVec::truncate_from(key)
+}
+
+/// Value of the maximum length.
+fn value<T: Config>(fill: u8) -> AttributeValue<T> {
+    BoundedVec::truncate_from(vec![fill; <T as Config>::ValueLimit::get() as usize])
+}
+
+/// `count` attributes of the maximum length, all with values filled with `fill`.
+fn list<T: Config>(count: u32, fill: u8) -> AttributeList<T> {
+    BoundedVec::truncate_from((0..count).map(|index| (key::<T>(index), value::<T>(fill))).collect())
+}
+
+/// `count` changes setting attributes of the maximum length.
+fn changes<T: Config>(count: u32) -> AttributeChanges<T> {
+    BoundedVec::truncate_from(
+        (0..count)
+            .map(|index| (key::<T>(index), Some(value::<T>(b'c'))))
+            .collect(),
+    )
+}
+
+/// Give the item full collection defaults and a full template, the worst case when comparing
+/// its attributes with the inherited ones.
+fn inherit<T: Config>(collection: &T::CollectionId, item: &T::ItemId) {
+    let limit = T::AttributeLimit::get();
+    CollectionDefaults::<T>::insert(collection, list::<T>(limit, b'd'));
+    Templates::<T>::insert(collection, 0, list::<T>(limit, b't'));
+    ItemTemplates::<T>::insert(collection, item, 0);
+}
 
 #[benchmarks]
 mod benchmarks {
     use super::*;
 
     #[benchmark]
-    fn set_attributes() {
-        // TODO
+    fn set_attributes(a: Linear<1, { T::AttributeLimit::get() }>) {
+        let caller: T::AccountId = whitelisted_caller();
+        let (collection, item) = item::<T>(&caller);
+        inherit::<T>(&collection, &item);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), collection.clone(), item, list::<T>(a, b'v'));
+
+        assert_eq!(Attributes::<T>::get(collection, item).len(), a as usize);
     }
 
     #[benchmark]
     fn clear_attributes() {
-        // TODO
+        let caller: T::AccountId = whitelisted_caller();
+        let (collection, item) = item::<T>(&caller);
+        Attributes::<T>::insert(&collection, item, list::<T>(T::AttributeLimit::get(), b'v'));
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), collection.clone(), item);
+
+        assert!(!Attributes::<T>::contains_key(collection, item));
+    }
+
+    #[benchmark]
+    fn set_collection_defaults(a: Linear<1, { T::AttributeLimit::get() }>) {
+        let caller: T::AccountId = whitelisted_caller();
+        let (collection, _) = item::<T>(&caller);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), collection.clone(), list::<T>(a, b'd'));
+
+        assert_eq!(CollectionDefaults::<T>::get(collection).len(), a as usize);
+    }
+
+    #[benchmark]
+    fn set_template(a: Linear<1, { T::AttributeLimit::get() }>) {
+        let caller: T::AccountId = whitelisted_caller();
+        let (collection, _) = item::<T>(&caller);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), collection.clone(), 0, list::<T>(a, b't'));
+
+        assert!(Templates::<T>::contains_key(collection, 0));
+    }
+
+    #[benchmark]
+    fn assign_template() {
+        let caller: T::AccountId = whitelisted_caller();
+        let (collection, item) = item::<T>(&caller);
+        Templates::<T>::insert(&collection, 0, list::<T>(T::AttributeLimit::get(), b't'));
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), collection.clone(), item, Some(0));
+
+        assert_eq!(ItemTemplates::<T>::get(collection, item), Some(0));
+    }
+
+    #[benchmark]
+    fn set_game_master() {
+        let caller: T::AccountId = whitelisted_caller();
+        let game_master: T::AccountId = account("game_master", 0, 0);
+        let (collection, _) = item::<T>(&caller);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), collection.clone(), Some(game_master.clone()));
+
+        assert_eq!(GameMasters::<T>::get(collection), Some(game_master));
+    }
+
+    #[benchmark]
+    fn propose_attributes(a: Linear<1, { T::AttributeLimit::get() }>) {
+        let caller: T::AccountId = whitelisted_caller();
+        let (collection, item) = item::<T>(&caller);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), collection.clone(), item, changes::<T>(a));
+
+        assert!(Proposals::<T>::contains_key(collection, item));
+    }
+
+    #[benchmark]
+    fn approve_attributes(a: Linear<1, { T::AttributeLimit::get() }>) {
+        let caller: T::AccountId = whitelisted_caller();
+        let (collection, item) = item::<T>(&caller);
+        inherit::<T>(&collection, &item);
+        Proposals::<T>::insert(
+            &collection,
+            item,
+            Proposal {
+                proposer: caller.clone(),
+                changes: changes::<T>(a),
+                expiry: T::ProposalLifetime::get(),
+            },
+        );
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), collection.clone(), item);
+
+        assert_eq!(Attributes::<T>::get(collection, item).len(), a as usize);
+    }
+
+    #[benchmark]
+    fn reject_attributes() {
+        let caller: T::AccountId = whitelisted_caller();
+        let (collection, item) = item::<T>(&caller);
+        Proposals::<T>::insert(
+            &collection,
+            item,
+            Proposal {
+                proposer: caller.clone(),
+                changes: changes::<T>(T::AttributeLimit::get()),
+                expiry: T::ProposalLifetime::get(),
+            },
+        );
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), collection.clone(), item);
+
+        assert!(!Proposals::<T>::contains_key(collection, item));
+    }
+
+    #[benchmark]
+    fn submit_signed_update(a: Linear<1, { T::AttributeLimit::get() }>) {
+        let caller: T::AccountId = whitelisted_caller();
+        let (collection, item) = item::<T>(&caller);
+        inherit::<T>(&collection, &item);
+
+        let signer = T::BenchmarkHelper::signer();
+        let update = SignedUpdate {
+            collection: collection.clone(),
+            item,
+            changes: changes::<T>(a),
+            nonce: 0,
+            deadline: T::ProposalLifetime::get(),
+        };
+        let signature = T::BenchmarkHelper::sign(&signer, &Pallet::<T>::signed_update_message(&update));
+        let signer = signer.into_account();
+        GameMasters::<T>::insert(&collection, signer.clone());
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), Box::new(update), signature, signer);
+
+        assert_eq!(UpdateNonces::<T>::get(collection, item), 1);
+    }
+
+    #[benchmark]
+    fn commit_attribute() {
+        let caller: T::AccountId = whitelisted_caller();
+        let (collection, item) = item::<T>(&caller);
+        fund::<T>(&caller);
+        // The item stores all the attributes but one, each with a value committed, so that every
+        // commitment is read and compared with the attributes before the new one fits.
+        let limit = T::AttributeLimit::get();
+        Attributes::<T>::insert(&collection, item, list::<T>(limit - 1, b'v'));
+        for index in 0..limit - 1 {
+            commit::<T>(&caller, &collection, &item, key::<T>(index), [0u8; 32]);
+        }
+        let key = key::<T>(limit);
+        let hash = T::Hashing::hash_of(&(&key, value::<T>(b'h'), [0u8; 32]));
+        let ciphertext =
+            BoundedVec::truncate_from(vec![b'e'; T::CiphertextLimit::get() as usize]);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), collection.clone(), item, key.clone(), hash, Some(ciphertext));
+
+        assert!(Commitments::<T>::contains_key((collection, item, key)));
+    }
+
+    #[benchmark]
+    fn reveal_attribute() {
+        let caller: T::AccountId = whitelisted_caller();
+        let (collection, item) = item::<T>(&caller);
+        inherit::<T>(&collection, &item);
+        Attributes::<T>::insert(&collection, item, list::<T>(T::AttributeLimit::get() - 1, b'v'));
+        let key = key::<T>(T::AttributeLimit::get());
+        let salt = [0u8; 32];
+        fund::<T>(&caller);
+        commit::<T>(&caller, &collection, &item, key.clone(), salt);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), collection.clone(), item, key.clone(), value::<T>(b'h'), salt);
+
+        assert_eq!(Attributes::<T>::get(collection, item).len(), T::AttributeLimit::get() as usize);
+    }
+
+    #[benchmark]
+    fn cancel_commitment() {
+        let caller: T::AccountId = whitelisted_caller();
+        let (collection, item) = item::<T>(&caller);
+        let key = key::<T>(0);
+        fund::<T>(&caller);
+        commit::<T>(&caller, &collection, &item, key.clone(), [0u8; 32]);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), collection.clone(), item, key.clone());
+
+        assert!(!Commitments::<T>::contains_key((collection, item, key)));
+    }
+
+    #[benchmark]
+    fn freeze_attributes() {
+        let caller: T::AccountId = whitelisted_caller();
+        let (collection, item) = item::<T>(&caller);
+        inherit::<T>(&collection, &item);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), collection.clone(), Some(item), false);
+
+        assert!(FrozenItems::<T>::contains_key(&collection, item));
+        assert_eq!(Attributes::<T>::get(collection, item).len(), T::AttributeLimit::get() as usize);
+    }
+
+    #[benchmark]
+    fn thaw_attributes() {
+        let caller: T::AccountId = whitelisted_caller();
+        let (collection, item) = item::<T>(&caller);
+        inherit::<T>(&collection, &item);
+        Attributes::<T>::insert(&collection, item, list::<T>(T::AttributeLimit::get(), b't'));
+        FrozenItems::<T>::insert(&collection, item, FreezeKind::Temporary);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), collection.clone(), Some(item));
+
+        assert!(!FrozenItems::<T>::contains_key(&collection, item));
+        assert!(!Attributes::<T>::contains_key(collection, item));
     }
 
-    impl_benchmark_test_suite!(Attributes, crate::mock::new_test_ext(), crate::mock::Test);
+    impl_benchmark_test_suite!(NftAttributes, crate::mock::new_test_ext(), crate::mock::Test);
 }
@@ -0,0 +1,75 @@
+use alloc::vec::Vec;
+use frame_support::pallet_prelude::{
+    Decode, DecodeWithMemTracking, Encode, MaxEncodedLen, RuntimeDebug, TypeInfo,
+};
+pub use pallet_nft_attributes_primitives::{parse_number, AttributeFilter};
+
+/// Parse an attribute value as a modifier. Only values with an explicit sign, such as `+2` or
+/// `-1`, are modifiers; a plain `2` is a base value.
+pub fn parse_modifier(value: &[u8]) -> Option<i64> {
+    match value.first() {
+        Some(b'+') | Some(b'-') => parse_number(value),
+        _ => None,
+    }
+}
+
+/// Render a number as an attribute value, the inverse of `parse_number`.
+pub fn render_number(number: i64) -> Vec<u8> {
+    let mut digits = Vec::new();
+    let mut rest = number.unsigned_abs();
+    loop {
+        digits.push(b'0' + (rest % 10) as u8);
+        rest /= 10;
+        if rest == 0 {
+            break;
+        }
+    }
+    if number < 0 {
+        digits.push(b'-');
+    }
+    digits.reverse();
+    digits
+}
+
+/// A change of attributes proposed by the owner of an item, pending the approval of the game
+/// master of its collection.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct Proposal<AccountId, Changes, BlockNumber> {
+    pub proposer: AccountId,
+    pub changes: Changes,
+    pub expiry: BlockNumber,
+}
+
+/// A change of attributes signed off-chain by the game master of the collection, which anyone can
+/// submit later. The nonce must match the one of the item, so that each update applies only once.
+#[derive(Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct SignedUpdate<CollectionId, ItemId, Changes, BlockNumber> {
+    pub collection: CollectionId,
+    pub item: ItemId,
+    pub changes: Changes,
+    pub nonce: u32,
+    pub deadline: BlockNumber,
+}
+
+/// A hidden attribute: a commitment to its value and, optionally, the value encrypted for those
+/// who hold the key. The value becomes public once revealed, which returns the deposit held from
+/// the committer.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct Commitment<AccountId, Hash, Ciphertext, Balance> {
+    pub committer: AccountId,
+    pub hash: Hash,
+    pub ciphertext: Option<Ciphertext>,
+    pub deposit: Balance,
+}
+
+/// How the attributes of an item or a collection are frozen.
+#[derive(
+    Encode, Decode, DecodeWithMemTracking, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo,
+    MaxEncodedLen,
+)]
+pub enum FreezeKind {
+    /// Frozen while, for instance, a character takes part in a running campaign, until thawed.
+    Temporary,
+    /// Frozen for good, for instance once a character retires. It can never be thawed.
+    Permanent,
+}
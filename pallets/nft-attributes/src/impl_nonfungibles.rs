@@ -0,0 +1,46 @@
+//! Implementation of the `nonfungibles_v2` inspection trait, so that other pallets and tooling read
+//! the attributes of this pallet through the same interface as those of the NFT pallet.
+
+use super::*;
+use alloc::vec::Vec;
+use frame_support::traits::tokens::nonfungibles_v2::Inspect;
+
+impl<T: Config> Inspect<T::AccountId> for Pallet<T> {
+    type ItemId = T::ItemId;
+    type CollectionId = T::CollectionId;
+
+    fn owner(collection: &Self::CollectionId, item: &Self::ItemId) -> Option<T::AccountId> {
+        T::Nfts::owner(collection, item)
+    }
+
+    fn collection_owner(collection: &Self::CollectionId) -> Option<T::AccountId> {
+        T::Nfts::collection_owner(collection)
+    }
+
+    /// Returns the value of an attribute as resolved by this pallet: the item itself, then its
+    /// template, then the collection defaults. Attributes only set in the NFT pallet are
+    /// returned when this pallet has no value for the key, and hidden attributes are not
+    /// returned until revealed.
+    fn attribute(
+        collection: &Self::CollectionId,
+        item: &Self::ItemId,
+        key: &[u8],
+    ) -> Option<Vec<u8>> {
+        Pallet::<T>::attribute(collection.clone(), *item, key)
+            .or_else(|| T::Nfts::attribute(collection, item, key))
+    }
+
+    /// Returns the default value of an attribute for the items of a collection, falling back on
+    /// the collection attributes of the NFT pallet.
+    fn collection_attribute(collection: &Self::CollectionId, key: &[u8]) -> Option<Vec<u8>> {
+        CollectionDefaults::<T>::get(collection)
+            .into_iter()
+            .find(|(k, _)| k[..] == *key)
+            .map(|(_, value)| value.into_inner())
+            .or_else(|| T::Nfts::collection_attribute(collection, key))
+    }
+
+    fn can_transfer(collection: &Self::CollectionId, item: &Self::ItemId) -> bool {
+        T::Nfts::can_transfer(collection, item)
+    }
+}
@@ -1,17 +1,19 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
+extern crate alloc;
+
 // Re-export pallet items so that they can be accessed from the crate namespace.
 pub use pallet::*;
 
 // FRAME pallets require their own "mock runtimes" to be able to run unit tests. This module
 // contains a mock runtime specific for testing this pallet's functionality.
-// #[cfg(test)]
-// mod mock;
+#[cfg(test)]
+mod mock;
 
 // This module contains the unit tests for this pallet.
 // Learn about pallet unit testing here: https://docs.substrate.io/test/unit-testing/
-// #[cfg(test)]
-// mod tests;
+#[cfg(test)]
+mod tests;
 
 // Every callable function or "dispatchable" a pallet exposes must have weight values that correctly
 // estimate a dispatchable's execution time. The benchmarking module is used to calculate weights
@@ -21,14 +23,63 @@ mod benchmarking;
 pub mod weights;
 pub use weights::*;
 
+// Parsing and matching of attribute values.
+mod common;
+pub use common::{AttributeFilter, Commitment, FreezeKind, Proposal, SignedUpdate};
+
+// Read access to the attributes through the `nonfungibles_v2` traits.
+mod impl_nonfungibles;
+
+/// Tag in front of the updates signed off-chain, so that their signatures cannot be taken for
+/// signatures of any other message.
+pub const SIGNED_UPDATE_TAG: &[u8] = b"nft-attributes:signed-update";
+
+// Traits through which other pallets write attributes.
+pub mod traits;
+
+/// Keys with which benchmarks sign updates of attributes off-chain.
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<Public, Signature> {
+    /// Generate a new key and return its public part.
+    fn signer() -> Public;
+    /// Sign `message` with the key of `signer`.
+    fn sign(signer: &Public, message: &[u8]) -> Signature;
+}
+#[cfg(feature = "runtime-benchmarks")]
+impl BenchmarkHelper<sp_runtime::MultiSigner, sp_runtime::MultiSignature> for () {
+    fn signer() -> sp_runtime::MultiSigner {
+        use sp_runtime::app_crypto::{sr25519, RuntimePublic};
+        sr25519::Public::generate_pair(sp_runtime::KeyTypeId(*b"nfta"), None).into()
+    }
+    /// Sign with the scheme of `signer`, whichever of those of `MultiSigner` it is.
+    fn sign(signer: &sp_runtime::MultiSigner, message: &[u8]) -> sp_runtime::MultiSignature {
+        use sp_runtime::{app_crypto::RuntimePublic, KeyTypeId, MultiSigner};
+        let key_type = KeyTypeId(*b"nfta");
+        let in_keystore = "the key of the signer is in the keystore";
+        match signer {
+            MultiSigner::Ed25519(public) => public.sign(key_type, &message).expect(in_keystore).into(),
+            MultiSigner::Sr25519(public) => public.sign(key_type, &message).expect(in_keystore).into(),
+            MultiSigner::Ecdsa(public) => public.sign(key_type, &message).expect(in_keystore).into(),
+        }
+    }
+}
+
 // All pallet logic is defined in its own module and must be annotated by the `pallet` attribute.
 #[frame_support::pallet]
 pub mod pallet {
     // Import various useful types required by all FRAME pallets.
     use super::*;
     use frame_support::pallet_prelude::*;
+    use frame_support::traits::{
+        fungible::{self, MutateHold},
+        tokens::Precision,
+    };
     use frame_system::pallet_prelude::*;
-    use pallet_uniques::{self as uniques};
+    use sp_runtime::traits::{Hash, IdentifyAccount, Saturating, Verify, Zero};
+    use pallet_nft_hierarchy::traits::{InspectItemsFrom, InspectOwnership};
+    use frame_support::traits::tokens::nonfungibles_v2::Inspect;
+    use alloc::{vec, vec::Vec};
+    use common::{parse_modifier, parse_number, render_number};
 
     // The `Pallet` struct serves as a placeholder to implement traits, methods and dispatchables
     // (`Call`s) in this pallet.
@@ -41,15 +92,132 @@ pub mod pallet {
     /// These types are defined generically and made concrete when the pallet is declared in the
     /// `runtime/src/lib.rs` file of your chain.
     #[pallet::config]
-    pub trait Config: frame_system::Config + uniques::Config {
+    pub trait Config: frame_system::Config {
         /// The overarching runtime event type.
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
         /// A type representing the weights required by the dispatchables of this pallet.
         type WeightInfo: WeightInfo;
 
+        /// Identifier of a collection of items.
+        type CollectionId: Member + Parameter + MaxEncodedLen + MaybeSerializeDeserialize + From<u32>;
+
+        /// Identifier of an item within a collection.
+        type ItemId: Member
+            + Parameter
+            + MaxEncodedLen
+            + MaybeSerializeDeserialize
+            + Copy
+            + From<u128>;
+
+        /// The NFTs whose attributes the pallet manages, which tell who holds each item and who
+        /// owns each collection, and list the items of a collection for searches.
+        type Nfts: Inspect<
+                Self::AccountId,
+                CollectionId = Self::CollectionId,
+                ItemId = Self::ItemId,
+            > + InspectItemsFrom<Self::CollectionId, Self::ItemId>;
+
         type KeyLimit: Get<u32>;
         type ValueLimit: Get<u32>;
         type AttributeLimit: Get<u32>;
+
+        /// Read access to the ownership relationship, used to gather the modifiers of the assets
+        /// an NFT owns.
+        type Inventory: InspectOwnership<Self::CollectionId, Self::ItemId>;
+
+        /// Maximum depth of the ownership tree explored when computing effective attributes. With
+        /// a depth of 1 only the assets owned directly are taken into account.
+        type MaxInventoryDepth: Get<u32>;
+
+        /// Maximum number of owned assets whose modifiers are added to the effective attributes.
+        type MaxInventoryItems: Get<u32>;
+
+        /// Maximum number of items returned by a single search over the items of a collection.
+        type MaxItemsPerQuery: Get<u32>;
+
+        /// Maximum number of items read by a single search, whether they match or not, so that
+        /// a search over a large collection is spread over several pages.
+        type MaxItemsScanned: Get<u32>;
+
+        /// Number of blocks a proposed change of attributes waits for the game master before it
+        /// expires.
+        #[pallet::constant]
+        type ProposalLifetime: Get<BlockNumberFor<Self>>;
+
+        /// Signature with which game masters sign updates of attributes off-chain.
+        type OffchainSignature: Verify<Signer = Self::OffchainPublic> + Parameter;
+
+        /// Public key that verifies an off-chain signature, identifying the account of the signer.
+        type OffchainPublic: IdentifyAccount<AccountId = Self::AccountId>;
+
+        /// Limit on the length of the encrypted value stored along with a hidden attribute.
+        #[pallet::constant]
+        type CiphertextLimit: Get<u32>;
+
+        /// The overarching hold reason.
+        type RuntimeHoldReason: From<HoldReason>;
+
+        /// The currency in which the committers of hidden attributes leave their deposits.
+        type Currency: fungible::Mutate<Self::AccountId>
+            + MutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason>;
+
+        /// Deposit held from the committer of each hidden attribute. It is returned when the value
+        /// is revealed or the commitment cancelled.
+        #[pallet::constant]
+        type CommitmentDeposit: Get<BalanceOf<Self>>;
+
+        /// Signs the updates of attributes submitted in benchmarks.
+        #[cfg(feature = "runtime-benchmarks")]
+        type BenchmarkHelper: BenchmarkHelper<Self::OffchainPublic, Self::OffchainSignature>;
+
+        /// Mints the NFTs whose attributes the benchmarks set.
+        #[cfg(feature = "runtime-benchmarks")]
+        type NftsHelper: pallet_nft_hierarchy::BenchmarkHelper<
+            Self::CollectionId,
+            Self::ItemId,
+            Self::AccountId,
+        >;
+    }
+
+    /// Key of an attribute.
+    pub type AttributeKey<T> = BoundedVec<u8, <T as Config>::KeyLimit>;
+
+    /// Value of an attribute.
+    pub type AttributeValue<T> = BoundedVec<u8, <T as Config>::ValueLimit>;
+
+    /// The attributes of a single item, as key-value pairs.
+    pub type AttributeList<T> =
+        BoundedVec<(AttributeKey<T>, AttributeValue<T>), <T as Config>::AttributeLimit>;
+
+    /// Changes of attributes: a new value for a key, or `None` to remove it.
+    pub type AttributeChanges<T> =
+        BoundedVec<(AttributeKey<T>, Option<AttributeValue<T>>), <T as Config>::AttributeLimit>;
+
+    /// An update of attributes signed off-chain.
+    pub type SignedUpdateOf<T> = SignedUpdate<
+        <T as Config>::CollectionId,
+        <T as Config>::ItemId,
+        AttributeChanges<T>,
+        BlockNumberFor<T>,
+    >;
+
+    /// The balance of the currency of the deposits.
+    pub type BalanceOf<T> =
+        <<T as Config>::Currency as fungible::Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+
+    /// Commitment to the value of a hidden attribute.
+    pub type CommitmentOf<T> = Commitment<
+        <T as frame_system::Config>::AccountId,
+        <T as frame_system::Config>::Hash,
+        BoundedVec<u8, <T as Config>::CiphertextLimit>,
+        BalanceOf<T>,
+    >;
+
+    /// A reason for the pallet placing a hold on funds.
+    #[pallet::composite_enum]
+    pub enum HoldReason {
+        /// Deposit of the committer of a hidden attribute, until it is revealed or cancelled.
+        AttributeCommitment,
     }
 
     /// Storage for attributes as key-value pairs.
@@ -60,16 +228,177 @@ pub mod pallet {
         T::CollectionId,
         Blake2_128Concat,
         T::ItemId,
-        BoundedVec<
-            (
-                BoundedVec<u8, <T as pallet::Config>::KeyLimit>,
-                BoundedVec<u8, <T as pallet::Config>::ValueLimit>,
-            ),
-            T::AttributeLimit,
-        >,
+        AttributeList<T>,
+        ValueQuery,
+    >;
+
+    /// Identifier of an attribute template within a collection.
+    pub type TemplateId = u32;
+
+    /// Default attributes shared by every item of a collection. An item only stores the attributes
+    /// in which it deviates from its template or from these defaults.
+    #[pallet::storage]
+    pub type CollectionDefaults<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::CollectionId, AttributeList<T>, ValueQuery>;
+
+    /// Attribute templates of a collection, such as the base stats of each kind of monster in a
+    /// bestiary. The attributes of a template take precedence over the collection defaults.
+    #[pallet::storage]
+    pub type Templates<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::CollectionId,
+        Twox64Concat,
+        TemplateId,
+        AttributeList<T>,
+        OptionQuery,
+    >;
+
+    /// Game master of each collection, who approves or rejects the changes of attributes proposed
+    /// by the owners of its items. The owner of the collection acts as game master if none is set.
+    #[pallet::storage]
+    pub type GameMasters<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::CollectionId, T::AccountId, OptionQuery>;
+
+    /// Pending change of attributes for each item. There can be only one at a time per item.
+    #[pallet::storage]
+    pub type Proposals<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::CollectionId,
+        Blake2_128Concat,
+        T::ItemId,
+        Proposal<T::AccountId, AttributeChanges<T>, BlockNumberFor<T>>,
+        OptionQuery,
+    >;
+
+    /// Nonce of the next signed update of each item, which protects against replays.
+    #[pallet::storage]
+    pub type UpdateNonces<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::CollectionId,
+        Blake2_128Concat,
+        T::ItemId,
+        u32,
         ValueQuery,
     >;
 
+    /// Hidden attributes, such as the true nature of a cursed item or the trigger of a trap. Only
+    /// a commitment is public until the value is revealed.
+    #[pallet::storage]
+    pub type Commitments<T: Config> = StorageNMap<
+        Key = (
+            NMapKey<Blake2_128Concat, T::CollectionId>,
+            NMapKey<Blake2_128Concat, T::ItemId>,
+            NMapKey<Blake2_128Concat, AttributeKey<T>>,
+        ),
+        Value = CommitmentOf<T>,
+        QueryKind = OptionQuery,
+    >;
+
+    /// Items whose attributes are frozen, rejecting every write until they are thawed. A frozen
+    /// item stores a copy of the attributes it inherited when it was frozen, and inherits
+    /// nothing else meanwhile.
+    #[pallet::storage]
+    pub type FrozenItems<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::CollectionId,
+        Blake2_128Concat,
+        T::ItemId,
+        FreezeKind,
+        OptionQuery,
+    >;
+
+    /// Collections whose attributes are frozen, including those of all their items.
+    #[pallet::storage]
+    pub type FrozenCollections<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::CollectionId, FreezeKind, OptionQuery>;
+
+    /// Template assigned to each item, if any.
+    #[pallet::storage]
+    pub type ItemTemplates<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::CollectionId,
+        Blake2_128Concat,
+        T::ItemId,
+        TemplateId,
+        OptionQuery,
+    >;
+
+    /// Attributes of a collection or an item at genesis, as key-value pairs.
+    pub type GenesisAttributes = Vec<(Vec<u8>, Vec<u8>)>;
+
+    /// The genesis config seeds the attributes of a world that already exists in the NFT pallet,
+    /// which is built before this one.
+    #[pallet::genesis_config]
+    #[derive(frame_support::DefaultNoBound)]
+    pub struct GenesisConfig<T: Config> {
+        /// Game masters of collections.
+        pub game_masters: Vec<(T::CollectionId, T::AccountId)>,
+        /// Default attributes of collections.
+        pub collection_defaults: Vec<(T::CollectionId, GenesisAttributes)>,
+        /// Attributes of items, of which only those that deviate from the defaults are stored.
+        pub attributes: Vec<(T::CollectionId, T::ItemId, GenesisAttributes)>,
+    }
+
+    #[pallet::genesis_build]
+    impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+        fn build(&self) {
+            for (collection, game_master) in &self.game_masters {
+                assert!(
+                    T::Nfts::collection_owner(collection).is_some(),
+                    "the collections of the game masters of the genesis exist"
+                );
+                GameMasters::<T>::insert(collection, game_master);
+            }
+            for (collection, attributes) in &self.collection_defaults {
+                assert!(
+                    T::Nfts::collection_owner(collection).is_some(),
+                    "the collections with defaults at genesis exist"
+                );
+                // As in set_collection_defaults, an empty list sets no defaults.
+                if !attributes.is_empty() {
+                    CollectionDefaults::<T>::insert(collection, genesis_list::<T>(attributes));
+                }
+            }
+            for (collection, item, attributes) in &self.attributes {
+                assert!(
+                    T::Nfts::owner(collection, item).is_some(),
+                    "the items with attributes at genesis exist"
+                );
+                assert!(
+                    !Attributes::<T>::contains_key(collection, item),
+                    "the items with attributes at genesis appear once"
+                );
+                Pallet::<T>::store_attributes(
+                    collection.clone(),
+                    *item,
+                    genesis_list::<T>(attributes),
+                );
+            }
+        }
+    }
+
+    /// Bound the attributes of the genesis, which must fit in the limits of the pallet.
+    fn genesis_list<T: Config>(attributes: &GenesisAttributes) -> AttributeList<T> {
+        let attributes: Vec<_> = attributes
+            .iter()
+            .map(|(key, value)| {
+                (
+                    AttributeKey::<T>::try_from(key.clone())
+                        .expect("the keys of the genesis fit in KeyLimit"),
+                    AttributeValue::<T>::try_from(value.clone())
+                        .expect("the values of the genesis fit in ValueLimit"),
+                )
+            })
+            .collect();
+        AttributeList::<T>::try_from(attributes)
+            .expect("the attributes of the genesis fit in AttributeLimit")
+    }
+
     /// Events that functions in this pallet can emit.
     ///
     /// Events are a simple means of indicating to the outside world (such as dApps, chain explorers
@@ -93,6 +422,85 @@ pub mod pallet {
             item: T::ItemId,
             who: T::AccountId,
         },
+        CollectionDefaultsSet {
+            collection: T::CollectionId,
+            who: T::AccountId,
+        },
+        TemplateSet {
+            collection: T::CollectionId,
+            template: TemplateId,
+            who: T::AccountId,
+        },
+        TemplateRemoved {
+            collection: T::CollectionId,
+            template: TemplateId,
+            who: T::AccountId,
+        },
+        TemplateAssigned {
+            collection: T::CollectionId,
+            item: T::ItemId,
+            template: Option<TemplateId>,
+            who: T::AccountId,
+        },
+        GameMasterSet {
+            collection: T::CollectionId,
+            game_master: Option<T::AccountId>,
+            who: T::AccountId,
+        },
+        AttributesProposed {
+            collection: T::CollectionId,
+            item: T::ItemId,
+            who: T::AccountId,
+            expiry: BlockNumberFor<T>,
+        },
+        ProposalApproved {
+            collection: T::CollectionId,
+            item: T::ItemId,
+            who: T::AccountId,
+        },
+        ProposalRejected {
+            collection: T::CollectionId,
+            item: T::ItemId,
+            who: T::AccountId,
+        },
+        SignedUpdateApplied {
+            collection: T::CollectionId,
+            item: T::ItemId,
+            signer: T::AccountId,
+            nonce: u32,
+            who: T::AccountId,
+        },
+        AttributeCommitted {
+            collection: T::CollectionId,
+            item: T::ItemId,
+            key: AttributeKey<T>,
+            hash: T::Hash,
+            who: T::AccountId,
+        },
+        AttributeRevealed {
+            collection: T::CollectionId,
+            item: T::ItemId,
+            key: AttributeKey<T>,
+            value: AttributeValue<T>,
+            who: T::AccountId,
+        },
+        CommitmentCancelled {
+            collection: T::CollectionId,
+            item: T::ItemId,
+            key: AttributeKey<T>,
+            who: T::AccountId,
+        },
+        AttributesFrozen {
+            collection: T::CollectionId,
+            item: Option<T::ItemId>,
+            kind: FreezeKind,
+            who: T::AccountId,
+        },
+        AttributesThawed {
+            collection: T::CollectionId,
+            item: Option<T::ItemId>,
+            who: T::AccountId,
+        },
     }
 
     /// Errors that can be returned by this pallet.
@@ -110,6 +518,30 @@ pub mod pallet {
         AttributeLimitReached,
         KeyTooLong,
         ValueTooLong,
+        NotCollectionOwner,
+        TemplateNotFound,
+        NotGameMaster,
+        ProposalPending,
+        ProposalNotFound,
+        ProposalExpired,
+        WrongSignature,
+        WrongNonce,
+        UpdateExpired,
+        AlreadyCommitted,
+        CommitmentNotFound,
+        WrongReveal,
+        NotCommitter,
+        AttributesFrozen,
+        PermanentlyFrozen,
+        NotFrozen,
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        #[cfg(feature = "try-runtime")]
+        fn try_state(_: BlockNumberFor<T>) -> Result<(), sp_runtime::TryRuntimeError> {
+            Self::do_try_state()
+        }
     }
 
     /// The pallet's dispatchable functions ([`Call`]s).
@@ -127,26 +559,21 @@ pub mod pallet {
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         #[pallet::call_index(0)]
-        #[pallet::weight(<T as pallet::Config>::WeightInfo::set_attributes())]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::set_attributes(attributes.len() as u32))]
         pub fn set_attributes(
             origin: OriginFor<T>,
             collection: T::CollectionId,
             item: T::ItemId,
-            attributes: BoundedVec<
-                (
-                    BoundedVec<u8, <T as pallet::Config>::KeyLimit>,
-                    BoundedVec<u8, <T as pallet::Config>::ValueLimit>,
-                ),
-                T::AttributeLimit,
-            >,
+            attributes: AttributeList<T>,
         ) -> DispatchResult {
             let who = ensure_signed(origin)?;
             ensure!(
-                uniques::Pallet::<T>::owner(collection.clone(), item) == Some(who.clone()),
+                T::Nfts::owner(&collection, &item) == Some(who.clone()),
                 Error::<T>::NotOwner
             );
+            Self::ensure_not_frozen(&collection, &item)?;
 
-            Attributes::<T>::insert(collection.clone(), item, attributes);
+            Self::store_attributes(collection.clone(), item, attributes);
             Self::deposit_event(Event::AttributesSet {
                 collection,
                 item,
@@ -164,9 +591,10 @@ pub mod pallet {
         ) -> DispatchResult {
             let who = ensure_signed(origin)?;
             ensure!(
-                uniques::Pallet::<T>::owner(collection.clone(), item) == Some(who.clone()),
+                T::Nfts::owner(&collection, &item) == Some(who.clone()),
                 Error::<T>::NotOwner
             );
+            Self::ensure_not_frozen(&collection, &item)?;
 
             Attributes::<T>::remove(collection.clone(), item);
             Self::deposit_event(Event::AttributesCleared {
@@ -176,5 +604,807 @@ pub mod pallet {
             });
             Ok(())
         }
+
+        /// Set the default attributes of a collection, which every item inherits unless its
+        /// template or the item itself overrides them. An empty list removes the defaults. Only
+        /// the owner of the collection may call it, while the collection is not frozen. Frozen
+        /// items keep the defaults they were frozen with.
+        #[pallet::call_index(2)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::set_collection_defaults(attributes.len() as u32))]
+        pub fn set_collection_defaults(
+            origin: OriginFor<T>,
+            collection: T::CollectionId,
+            attributes: AttributeList<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::ensure_collection_owner(&who, &collection)?;
+            Self::ensure_collection_not_frozen(&collection)?;
+
+            if attributes.is_empty() {
+                CollectionDefaults::<T>::remove(collection.clone());
+            } else {
+                CollectionDefaults::<T>::insert(collection.clone(), attributes);
+            }
+            Self::deposit_event(Event::CollectionDefaultsSet { collection, who });
+            Ok(())
+        }
+
+        /// Create or replace an attribute template of a collection. An empty list removes the
+        /// template; items it was assigned to fall back on the collection defaults. Only the
+        /// owner of the collection may call it, while the collection is not frozen. Frozen items
+        /// keep the template they were frozen with.
+        #[pallet::call_index(3)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::set_template(attributes.len() as u32))]
+        pub fn set_template(
+            origin: OriginFor<T>,
+            collection: T::CollectionId,
+            template: TemplateId,
+            attributes: AttributeList<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::ensure_collection_owner(&who, &collection)?;
+            Self::ensure_collection_not_frozen(&collection)?;
+
+            if attributes.is_empty() {
+                ensure!(
+                    Templates::<T>::contains_key(collection.clone(), template),
+                    Error::<T>::TemplateNotFound
+                );
+                Templates::<T>::remove(collection.clone(), template);
+                Self::deposit_event(Event::TemplateRemoved { collection, template, who });
+            } else {
+                Templates::<T>::insert(collection.clone(), template, attributes);
+                Self::deposit_event(Event::TemplateSet { collection, template, who });
+            }
+            Ok(())
+        }
+
+        /// Assign a template to an item, or unassign it with `None`. Only the owner of the
+        /// collection may call it, so that players cannot pick a stronger template.
+        #[pallet::call_index(4)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::assign_template())]
+        pub fn assign_template(
+            origin: OriginFor<T>,
+            collection: T::CollectionId,
+            item: T::ItemId,
+            template: Option<TemplateId>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::ensure_collection_owner(&who, &collection)?;
+            ensure!(
+                T::Nfts::owner(&collection, &item).is_some(),
+                Error::<T>::TokenNotFound
+            );
+            Self::ensure_not_frozen(&collection, &item)?;
+
+            match template {
+                Some(template) => {
+                    ensure!(
+                        Templates::<T>::contains_key(collection.clone(), template),
+                        Error::<T>::TemplateNotFound
+                    );
+                    ItemTemplates::<T>::insert(collection.clone(), item, template);
+                }
+                None => ItemTemplates::<T>::remove(collection.clone(), item),
+            }
+            Self::deposit_event(Event::TemplateAssigned {
+                collection,
+                item,
+                template,
+                who,
+            });
+            Ok(())
+        }
+
+        /// Set the game master of a collection, or unset it with `None` so that the owner of the
+        /// collection acts as game master. Only the owner of the collection may call it.
+        #[pallet::call_index(5)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::set_game_master())]
+        pub fn set_game_master(
+            origin: OriginFor<T>,
+            collection: T::CollectionId,
+            game_master: Option<T::AccountId>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::ensure_collection_owner(&who, &collection)?;
+
+            GameMasters::<T>::set(collection.clone(), game_master.clone());
+            Self::deposit_event(Event::GameMasterSet {
+                collection,
+                game_master,
+                who,
+            });
+            Ok(())
+        }
+
+        /// Propose a change of attributes for an item, such as learning a spell or spending
+        /// experience, to be approved by the game master of its collection. Removing a key reverts
+        /// it to the inherited value. Only the owner of the item may propose, and only one
+        /// proposal per item can be pending until it is approved, rejected or expires.
+        #[pallet::call_index(6)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::propose_attributes(changes.len() as u32))]
+        pub fn propose_attributes(
+            origin: OriginFor<T>,
+            collection: T::CollectionId,
+            item: T::ItemId,
+            changes: AttributeChanges<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(
+                T::Nfts::owner(&collection, &item) == Some(who.clone()),
+                Error::<T>::NotOwner
+            );
+            Self::ensure_not_frozen(&collection, &item)?;
+
+            let now = frame_system::Pallet::<T>::block_number();
+            if let Some(pending) = Proposals::<T>::get(collection.clone(), item) {
+                ensure!(pending.expiry < now, Error::<T>::ProposalPending);
+            }
+
+            let expiry = now.saturating_add(T::ProposalLifetime::get());
+            Proposals::<T>::insert(
+                collection.clone(),
+                item,
+                Proposal {
+                    proposer: who.clone(),
+                    changes,
+                    expiry,
+                },
+            );
+            Self::deposit_event(Event::AttributesProposed {
+                collection,
+                item,
+                who,
+                expiry,
+            });
+            Ok(())
+        }
+
+        /// Approve the pending proposal of an item, applying all its changes at once. Only the game
+        /// master of the collection may call it.
+        #[pallet::call_index(7)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::approve_attributes(T::AttributeLimit::get()))]
+        pub fn approve_attributes(
+            origin: OriginFor<T>,
+            collection: T::CollectionId,
+            item: T::ItemId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::ensure_game_master(&who, &collection)?;
+
+            let proposal =
+                Proposals::<T>::take(collection.clone(), item).ok_or(Error::<T>::ProposalNotFound)?;
+            ensure!(
+                proposal.expiry >= frame_system::Pallet::<T>::block_number(),
+                Error::<T>::ProposalExpired
+            );
+            ensure!(
+                T::Nfts::owner(&collection, &item).is_some(),
+                Error::<T>::TokenNotFound
+            );
+
+            Self::apply_changes(collection.clone(), item, proposal.changes)?;
+
+            Self::deposit_event(Event::ProposalApproved {
+                collection,
+                item,
+                who,
+            });
+            Ok(())
+        }
+
+        /// Reject the pending proposal of an item, discarding its changes. Only the game master of
+        /// the collection may call it.
+        #[pallet::call_index(8)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::reject_attributes())]
+        pub fn reject_attributes(
+            origin: OriginFor<T>,
+            collection: T::CollectionId,
+            item: T::ItemId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::ensure_game_master(&who, &collection)?;
+
+            ensure!(
+                Proposals::<T>::take(collection.clone(), item).is_some(),
+                Error::<T>::ProposalNotFound
+            );
+            Self::deposit_event(Event::ProposalRejected {
+                collection,
+                item,
+                who,
+            });
+            Ok(())
+        }
+
+        /// Apply an update of attributes signed off-chain by the game master of the collection,
+        /// for instance after a session played offline. Anyone may submit it and pay the fee. The
+        /// update is rejected once its deadline has passed or if its nonce was already used.
+        #[pallet::call_index(9)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::submit_signed_update(update.changes.len() as u32))]
+        pub fn submit_signed_update(
+            origin: OriginFor<T>,
+            update: Box<SignedUpdateOf<T>>,
+            signature: T::OffchainSignature,
+            signer: T::AccountId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let message = Self::signed_update_message(&update);
+            ensure!(signature.verify(&message[..], &signer), Error::<T>::WrongSignature);
+            Self::ensure_game_master(&signer, &update.collection)?;
+
+            let SignedUpdate {
+                collection,
+                item,
+                changes,
+                nonce,
+                deadline,
+            } = *update;
+            ensure!(
+                deadline >= frame_system::Pallet::<T>::block_number(),
+                Error::<T>::UpdateExpired
+            );
+            ensure!(
+                UpdateNonces::<T>::get(collection.clone(), item) == nonce,
+                Error::<T>::WrongNonce
+            );
+            ensure!(
+                T::Nfts::owner(&collection, &item).is_some(),
+                Error::<T>::TokenNotFound
+            );
+
+            UpdateNonces::<T>::insert(collection.clone(), item, nonce.saturating_add(1));
+            Self::apply_changes(collection.clone(), item, changes)?;
+
+            Self::deposit_event(Event::SignedUpdateApplied {
+                collection,
+                item,
+                signer,
+                nonce,
+                who,
+            });
+            Ok(())
+        }
+
+        /// Hide the value of an attribute behind a commitment: the hash of the SCALE-encoded
+        /// `(key, value, salt)`, computed with the hashing of the chain. The value may also be
+        /// stored encrypted. The owner of the item or the game master of the collection may commit,
+        /// and only the original committer may replace an existing commitment. A new commitment
+        /// holds `CommitmentDeposit` from the committer, and fails with `AttributeLimitReached` if
+        /// the attributes of the item and the values committed for it leave no room for its value.
+        #[pallet::call_index(10)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::commit_attribute())]
+        pub fn commit_attribute(
+            origin: OriginFor<T>,
+            collection: T::CollectionId,
+            item: T::ItemId,
+            key: AttributeKey<T>,
+            hash: T::Hash,
+            ciphertext: Option<BoundedVec<u8, T::CiphertextLimit>>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let owner = T::Nfts::owner(&collection, &item)
+                .ok_or(Error::<T>::TokenNotFound)?;
+            ensure!(
+                owner == who || Self::game_master(collection.clone()).as_ref() == Some(&who),
+                Error::<T>::NotOwner
+            );
+            Self::ensure_not_frozen(&collection, &item)?;
+            let deposit = match Commitments::<T>::get((collection.clone(), item, key.clone())) {
+                Some(existing) => {
+                    ensure!(existing.committer == who, Error::<T>::AlreadyCommitted);
+                    existing.deposit
+                }
+                None => {
+                    Self::ensure_room_for_commitment(&collection, &item, &key)?;
+                    let deposit = T::CommitmentDeposit::get();
+                    T::Currency::hold(&HoldReason::AttributeCommitment.into(), &who, deposit)?;
+                    deposit
+                }
+            };
+
+            Commitments::<T>::insert(
+                (collection.clone(), item, key.clone()),
+                Commitment {
+                    committer: who.clone(),
+                    hash,
+                    ciphertext,
+                    deposit,
+                },
+            );
+            Self::deposit_event(Event::AttributeCommitted {
+                collection,
+                item,
+                key,
+                hash,
+                who,
+            });
+            Ok(())
+        }
+
+        /// Reveal a hidden attribute by providing the value and salt behind its commitment. If they
+        /// match, the commitment is removed, the value is published as a regular attribute and the
+        /// deposit is returned to the committer. Anyone who knows the value and salt may reveal it.
+        #[pallet::call_index(11)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::reveal_attribute())]
+        pub fn reveal_attribute(
+            origin: OriginFor<T>,
+            collection: T::CollectionId,
+            item: T::ItemId,
+            key: AttributeKey<T>,
+            value: AttributeValue<T>,
+            salt: [u8; 32],
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let commitment = Commitments::<T>::take((collection.clone(), item, key.clone()))
+                .ok_or(Error::<T>::CommitmentNotFound)?;
+            ensure!(
+                T::Hashing::hash_of(&(&key, &value, &salt)) == commitment.hash,
+                Error::<T>::WrongReveal
+            );
+            ensure!(
+                T::Nfts::owner(&collection, &item).is_some(),
+                Error::<T>::TokenNotFound
+            );
+
+            let changes = BoundedVec::truncate_from(vec![(key.clone(), Some(value.clone()))]);
+            Self::apply_changes(collection.clone(), item, changes)?;
+            T::Currency::release(
+                &HoldReason::AttributeCommitment.into(),
+                &commitment.committer,
+                commitment.deposit,
+                Precision::BestEffort,
+            )?;
+
+            Self::deposit_event(Event::AttributeRevealed {
+                collection,
+                item,
+                key,
+                value,
+                who,
+            });
+            Ok(())
+        }
+
+        /// Freeze the attributes of an item, or of a whole collection when `item` is `None`, so
+        /// that every write is rejected until they are thawed. A permanent freeze can never be
+        /// thawed. An item copies the attributes it inherits when it is frozen, so that changes
+        /// of its template or of the defaults of its collection do not reach it, and fails with
+        /// `AttributeLimitReached` if they do not fit. Only the game master of the collection may
+        /// call it.
+        #[pallet::call_index(12)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::freeze_attributes())]
+        pub fn freeze_attributes(
+            origin: OriginFor<T>,
+            collection: T::CollectionId,
+            item: Option<T::ItemId>,
+            permanent: bool,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::ensure_game_master(&who, &collection)?;
+
+            let kind = if permanent { FreezeKind::Permanent } else { FreezeKind::Temporary };
+            let current = match item {
+                Some(item) => FrozenItems::<T>::get(collection.clone(), item),
+                None => FrozenCollections::<T>::get(collection.clone()),
+            };
+            ensure!(current != Some(FreezeKind::Permanent), Error::<T>::PermanentlyFrozen);
+
+            match item {
+                Some(item) => {
+                    if current.is_none() {
+                        Self::copy_inherited_attributes(&collection, &item)?;
+                    }
+                    FrozenItems::<T>::insert(collection.clone(), item, kind);
+                }
+                None => FrozenCollections::<T>::insert(collection.clone(), kind),
+            }
+            Self::deposit_event(Event::AttributesFrozen {
+                collection,
+                item,
+                kind,
+                who,
+            });
+            Ok(())
+        }
+
+        /// Thaw the attributes of an item, or of a whole collection when `item` is `None`, unless
+        /// they are permanently frozen. A thawed item inherits again the attributes whose copies
+        /// still match what it inherits, and keeps the others as its own. Only the game master of
+        /// the collection may call it.
+        #[pallet::call_index(13)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::thaw_attributes())]
+        pub fn thaw_attributes(
+            origin: OriginFor<T>,
+            collection: T::CollectionId,
+            item: Option<T::ItemId>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::ensure_game_master(&who, &collection)?;
+
+            let current = match item {
+                Some(item) => FrozenItems::<T>::get(collection.clone(), item),
+                None => FrozenCollections::<T>::get(collection.clone()),
+            };
+            match current {
+                Some(FreezeKind::Temporary) => {}
+                Some(FreezeKind::Permanent) => return Err(Error::<T>::PermanentlyFrozen.into()),
+                None => return Err(Error::<T>::NotFrozen.into()),
+            }
+
+            match item {
+                Some(item) => {
+                    FrozenItems::<T>::remove(collection.clone(), item);
+                    let attributes = Attributes::<T>::get(collection.clone(), item);
+                    Self::store_attributes(collection.clone(), item, attributes);
+                }
+                None => FrozenCollections::<T>::remove(collection.clone()),
+            }
+            Self::deposit_event(Event::AttributesThawed {
+                collection,
+                item,
+                who,
+            });
+            Ok(())
+        }
+
+        /// Cancel a hidden attribute without revealing it, which returns the deposit. Only the
+        /// committer may cancel it.
+        #[pallet::call_index(14)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::cancel_commitment())]
+        pub fn cancel_commitment(
+            origin: OriginFor<T>,
+            collection: T::CollectionId,
+            item: T::ItemId,
+            key: AttributeKey<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let commitment = Commitments::<T>::take((collection.clone(), item, key.clone()))
+                .ok_or(Error::<T>::CommitmentNotFound)?;
+            ensure!(commitment.committer == who, Error::<T>::NotCommitter);
+
+            T::Currency::release(
+                &HoldReason::AttributeCommitment.into(),
+                &who,
+                commitment.deposit,
+                Precision::BestEffort,
+            )?;
+            Self::deposit_event(Event::CommitmentCancelled {
+                collection,
+                item,
+                key,
+                who,
+            });
+            Ok(())
+        }
+    }
+    impl<T: Config> Pallet<T> {
+        /// Return the message the game master signs to authorize `update`: the update behind
+        /// `SIGNED_UPDATE_TAG` and the genesis hash, so that the signature holds on no other chain.
+        pub fn signed_update_message(update: &SignedUpdateOf<T>) -> Vec<u8> {
+            let genesis_hash = frame_system::Pallet::<T>::block_hash(BlockNumberFor::<T>::zero());
+            (SIGNED_UPDATE_TAG, genesis_hash, update).encode()
+        }
+
+        fn ensure_collection_owner(
+            who: &T::AccountId,
+            collection: &T::CollectionId,
+        ) -> DispatchResult {
+            ensure!(
+                T::Nfts::collection_owner(collection).as_ref() == Some(who),
+                Error::<T>::NotCollectionOwner
+            );
+            Ok(())
+        }
+
+        /// Return the game master of a collection: the account set for it or, failing that, the
+        /// owner of the collection.
+        pub fn game_master(collection: T::CollectionId) -> Option<T::AccountId> {
+            GameMasters::<T>::get(collection.clone())
+                .or_else(|| T::Nfts::collection_owner(&collection))
+        }
+
+        pub(crate) fn ensure_game_master(who: &T::AccountId, collection: &T::CollectionId) -> DispatchResult {
+            ensure!(
+                Self::game_master(collection.clone()).as_ref() == Some(who),
+                Error::<T>::NotGameMaster
+            );
+            Ok(())
+        }
+
+        /// Return whether the attributes of an item are frozen, either on their own or along with
+        /// their collection.
+        pub fn is_frozen(collection: &T::CollectionId, item: &T::ItemId) -> bool {
+            FrozenCollections::<T>::contains_key(collection)
+                || FrozenItems::<T>::contains_key(collection, item)
+        }
+
+        pub(crate) fn ensure_not_frozen(collection: &T::CollectionId, item: &T::ItemId) -> DispatchResult {
+            ensure!(!Self::is_frozen(collection, item), Error::<T>::AttributesFrozen);
+            Ok(())
+        }
+
+        /// Store in an item the attributes it inherits and does not override, the template
+        /// taking precedence over the collection defaults, as it is about to be frozen.
+        fn copy_inherited_attributes(collection: &T::CollectionId, item: &T::ItemId) -> DispatchResult {
+            let mut attributes = Attributes::<T>::get(collection, item);
+            let template = ItemTemplates::<T>::get(collection, item)
+                .and_then(|template| Templates::<T>::get(collection, template))
+                .unwrap_or_default();
+            for (key, value) in template.into_iter().chain(CollectionDefaults::<T>::get(collection)) {
+                if !attributes.iter().any(|(k, _)| *k == key) {
+                    attributes
+                        .try_push((key, value))
+                        .map_err(|_| Error::<T>::AttributeLimitReached)?;
+                }
+            }
+            if !attributes.is_empty() {
+                Attributes::<T>::insert(collection, item, attributes);
+            }
+            Ok(())
+        }
+
+        /// Ensure that an item can store the value of a new hidden attribute under `key`, counting
+        /// its attributes and the values committed for keys it does not store yet.
+        fn ensure_room_for_commitment(
+            collection: &T::CollectionId,
+            item: &T::ItemId,
+            key: &AttributeKey<T>,
+        ) -> DispatchResult {
+            let stored = Attributes::<T>::get(collection, item);
+            let stores = |key: &AttributeKey<T>| stored.iter().any(|(k, _)| k == key);
+            if stores(key) {
+                return Ok(());
+            }
+            let pending = Commitments::<T>::iter_key_prefix((collection, item))
+                .filter(|key| !stores(key))
+                .count();
+            ensure!(
+                stored.len() + pending < T::AttributeLimit::get() as usize,
+                Error::<T>::AttributeLimitReached
+            );
+            Ok(())
+        }
+
+        fn ensure_collection_not_frozen(collection: &T::CollectionId) -> DispatchResult {
+            ensure!(
+                !FrozenCollections::<T>::contains_key(collection),
+                Error::<T>::AttributesFrozen
+            );
+            Ok(())
+        }
+
+        /// Store the attributes of an item, keeping only those in which it deviates from the
+        /// attributes it inherits.
+        fn store_attributes(collection: T::CollectionId, item: T::ItemId, mut attributes: AttributeList<T>) {
+            let inherited = Self::inherited_attributes(collection.clone(), item);
+            attributes.retain(|(key, value)| {
+                !inherited.iter().any(|(k, v)| k[..] == key[..] && v[..] == value[..])
+            });
+
+            if attributes.is_empty() {
+                Attributes::<T>::remove(collection, item);
+            } else {
+                Attributes::<T>::insert(collection, item, attributes);
+            }
+        }
+
+        /// Apply a set of changes to the attributes stored for an item, failing as a whole if the
+        /// result exceeds `AttributeLimit`.
+        pub(crate) fn apply_changes(
+            collection: T::CollectionId,
+            item: T::ItemId,
+            changes: AttributeChanges<T>,
+        ) -> DispatchResult {
+            Self::ensure_not_frozen(&collection, &item)?;
+
+            let mut attributes = Attributes::<T>::get(collection.clone(), item);
+            for (key, value) in changes {
+                let position = attributes.iter().position(|(k, _)| *k == key);
+                match (position, value) {
+                    (Some(index), Some(value)) => attributes[index].1 = value,
+                    (Some(index), None) => {
+                        attributes.remove(index);
+                    }
+                    (None, Some(value)) => attributes
+                        .try_push((key, value))
+                        .map_err(|_| Error::<T>::AttributeLimitReached)?,
+                    (None, None) => {}
+                }
+            }
+            Self::store_attributes(collection, item, attributes);
+            Ok(())
+        }
+
+        /// Add `list` on top of `attributes`, replacing the values of the keys both contain.
+        fn overlay(attributes: &mut Vec<(Vec<u8>, Vec<u8>)>, list: AttributeList<T>) {
+            for (key, value) in list {
+                match attributes.iter_mut().find(|(k, _)| k[..] == key[..]) {
+                    Some((_, current)) => *current = value.into_inner(),
+                    None => attributes.push((key.into_inner(), value.into_inner())),
+                }
+            }
+        }
+
+        /// Return the attributes an item inherits: the collection defaults overridden by the
+        /// template assigned to the item, if any. An item frozen on its own inherits nothing, as
+        /// it stores a copy of what it inherited when it was frozen.
+        pub fn inherited_attributes(
+            collection: T::CollectionId,
+            item: T::ItemId,
+        ) -> Vec<(Vec<u8>, Vec<u8>)> {
+            let mut attributes = Vec::new();
+            if FrozenItems::<T>::contains_key(collection.clone(), item) {
+                return attributes;
+            }
+            Self::overlay(&mut attributes, CollectionDefaults::<T>::get(collection.clone()));
+            if let Some(template) = ItemTemplates::<T>::get(collection.clone(), item)
+                .and_then(|template| Templates::<T>::get(collection, template))
+            {
+                Self::overlay(&mut attributes, template);
+            }
+            attributes
+        }
+
+        /// Return all the attributes of an item, as key-value pairs: the inherited attributes
+        /// overridden by those stored for the item itself.
+        pub fn attributes(collection: T::CollectionId, item: T::ItemId) -> Vec<(Vec<u8>, Vec<u8>)> {
+            let mut attributes = Self::inherited_attributes(collection.clone(), item);
+            Self::overlay(&mut attributes, Attributes::<T>::get(collection, item));
+            attributes
+        }
+
+        /// Return the value of a single attribute of an item, looking it up in the item itself,
+        /// then in its template and finally in the collection defaults, unless the item is frozen
+        /// on its own and only has what it stores.
+        pub fn attribute(collection: T::CollectionId, item: T::ItemId, key: &[u8]) -> Option<Vec<u8>> {
+            let find = |list: AttributeList<T>| {
+                list.into_iter()
+                    .find(|(k, _)| k[..] == *key)
+                    .map(|(_, value)| value.into_inner())
+            };
+            find(Attributes::<T>::get(collection.clone(), item)).or_else(|| {
+                if FrozenItems::<T>::contains_key(collection.clone(), item) {
+                    return None;
+                }
+                ItemTemplates::<T>::get(collection.clone(), item)
+                    .and_then(|template| Templates::<T>::get(collection.clone(), template))
+                    .and_then(find)
+                    .or_else(|| find(CollectionDefaults::<T>::get(collection)))
+            })
+        }
+
+        /// Search the items of a collection whose attribute `key` matches `filter`. Results are
+        /// paginated: a page holds at most `limit` items, between one and `MaxItemsPerQuery`, and reads
+        /// at most `MaxItemsScanned` items. Along with the page comes the cursor to pass as
+        /// `start_after` to get the next one, or `None` once the whole collection has been read.
+        /// A page may be empty while the cursor is not, if none of the items read matched.
+        pub fn items_matching(
+            collection: T::CollectionId,
+            key: &[u8],
+            filter: &AttributeFilter,
+            start_after: Option<T::ItemId>,
+            limit: u32,
+        ) -> (Vec<T::ItemId>, Option<T::ItemId>) {
+            // Items may inherit the attribute without storing anything, so the search goes over
+            // every item minted in the collection.
+            let limit = limit.clamp(1, T::MaxItemsPerQuery::get()) as usize;
+            let max_scanned = T::MaxItemsScanned::get();
+            let mut found = Vec::new();
+            let (mut scanned, mut last) = (0, start_after);
+            for item in T::Nfts::items_from(&collection, start_after.as_ref()) {
+                // Some items are left, so the page ends with a cursor to the rest.
+                if found.len() == limit || scanned == max_scanned {
+                    return (found, last);
+                }
+                if Self::attribute(collection.clone(), item, key)
+                    .is_some_and(|value| filter.matches(&value))
+                {
+                    found.push(item);
+                }
+                scanned += 1;
+                last = Some(item);
+            }
+            (found, None)
+        }
+
+        /// Compute the effective attributes of an NFT: its own attributes plus the modifiers of
+        /// the assets it owns, directly or through other assets, bounded by `MaxInventoryDepth`
+        /// and `MaxInventoryItems`. A modifier is an attribute value with an explicit sign, such
+        /// as `ac: +2` or `str: -1`. Modifiers are added to numeric attributes of the owner,
+        /// leave non-numeric ones untouched, and keys the owner lacks start from zero.
+        pub fn effective_attributes(
+            collection: T::CollectionId,
+            item: T::ItemId,
+        ) -> Vec<(Vec<u8>, Vec<u8>)> {
+            let mut effective = Self::attributes(collection.clone(), item);
+
+            // Walk the ownership tree level by level, skipping assets already visited in case
+            // the relationships contain a cycle.
+            let max_items = T::MaxInventoryItems::get() as usize;
+            let root = (collection, item);
+            let mut inventory: Vec<(T::CollectionId, T::ItemId)> = Vec::new();
+            let mut level = vec![root.clone()];
+            for _ in 0..T::MaxInventoryDepth::get() {
+                let mut next_level = Vec::new();
+                for (owner_collection, owner) in level.iter() {
+                    let remaining = max_items.saturating_sub(inventory.len()) as u32;
+                    if remaining == 0 {
+                        break;
+                    }
+                    for asset in T::Inventory::owned_assets(owner_collection, owner, 0, remaining) {
+                        if asset != root && !inventory.contains(&asset) {
+                            inventory.push(asset.clone());
+                            next_level.push(asset);
+                        }
+                    }
+                }
+                level = next_level;
+            }
+
+            // The modifiers of an asset may come from its template or the defaults of its
+            // collection, like any other attribute.
+            for (asset_collection, asset) in inventory {
+                for (key, value) in Self::attributes(asset_collection, asset) {
+                    let Some(modifier) = parse_modifier(&value) else {
+                        continue;
+                    };
+                    match effective.iter_mut().find(|(k, _)| *k == key) {
+                        Some((_, current)) => {
+                            if let Some(base) = parse_number(current) {
+                                *current = render_number(base.saturating_add(modifier));
+                            }
+                        }
+                        None => effective.push((key, render_number(modifier))),
+                    }
+                }
+            }
+
+            effective
+        }
+
+        /// Check the invariants of the attributes: the items, collection defaults and templates
+        /// stored are never empty, as an empty list removes them, no proposal outlives
+        /// `ProposalLifetime`, and each account holds the deposits of the hidden attributes it has
+        /// committed to.
+        #[cfg(any(feature = "try-runtime", test))]
+        pub fn do_try_state() -> Result<(), sp_runtime::TryRuntimeError> {
+            ensure!(
+                Attributes::<T>::iter_values().all(|attributes| !attributes.is_empty()),
+                "an item stores an empty list of attributes"
+            );
+            ensure!(
+                CollectionDefaults::<T>::iter_values().all(|attributes| !attributes.is_empty()),
+                "a collection stores empty defaults"
+            );
+            ensure!(
+                Templates::<T>::iter_values().all(|attributes| !attributes.is_empty()),
+                "a collection stores an empty template"
+            );
+
+            let latest_expiry = frame_system::Pallet::<T>::block_number()
+                .saturating_add(T::ProposalLifetime::get());
+            ensure!(
+                Proposals::<T>::iter_values().all(|proposal| proposal.expiry <= latest_expiry),
+                "a proposal outlives ProposalLifetime"
+            );
+
+            use alloc::collections::btree_map::BTreeMap;
+            use frame_support::traits::fungible::InspectHold;
+            let mut held: BTreeMap<T::AccountId, BalanceOf<T>> = BTreeMap::new();
+            for commitment in Commitments::<T>::iter_values() {
+                let deposit = held.entry(commitment.committer).or_default();
+                *deposit = deposit.saturating_add(commitment.deposit);
+            }
+            for (who, deposit) in held {
+                ensure!(
+                    T::Currency::balance_on_hold(&HoldReason::AttributeCommitment.into(), &who)
+                        == deposit,
+                    "an account does not hold the deposits of its commitments"
+                );
+            }
+            Ok(())
+        }
     }
 }
@@ -1,46 +1,310 @@
-use crate as pallet_attr;
-use frame_support::derive_impl;
-use sp_runtime::BuildStorage;
+use crate as pallet_nft_attributes;
+use crate::{AttributeChanges, AttributeList};
+use pallet_nft_hierarchy_uniques_adapter::UniquesAdapter;
+use frame_support::{
+    derive_impl, parameter_types,
+    traits::{ConstU32, VariantCountOf},
+    BoundedVec, PalletId,
+};
+use frame_system::{EnsureRoot, EnsureSigned};
+use sp_runtime::{
+    testing::{TestSignature, UintAuthorityId},
+    BuildStorage,
+};
 
 type Block = frame_system::mocking::MockBlock<Test>;
 
 #[frame_support::runtime]
 mod runtime {
-	// The main runtime
-	#[runtime::runtime]
-	// Runtime Types to be generated
-	#[runtime::derive(
-		RuntimeCall,
-		RuntimeEvent,
-		RuntimeError,
-		RuntimeOrigin,
-		RuntimeFreezeReason,
-		RuntimeHoldReason,
-		RuntimeSlashReason,
-		RuntimeLockId,
-		RuntimeTask,
-		RuntimeViewFunction
-	)]
-	pub struct Test;
-
-	#[runtime::pallet_index(0)]
-	pub type System = frame_system::Pallet<Test>;
-
-	#[runtime::pallet_index(1)]
-	pub type Template = pallet_attr::Pallet<Test>;
+    #[runtime::runtime]
+    #[runtime::derive(
+        RuntimeCall,
+        RuntimeEvent,
+        RuntimeError,
+        RuntimeOrigin,
+        RuntimeFreezeReason,
+        RuntimeHoldReason,
+        RuntimeSlashReason,
+        RuntimeLockId,
+        RuntimeTask,
+        RuntimeViewFunction
+    )]
+    pub struct Test;
+
+    #[runtime::pallet_index(0)]
+    pub type System = frame_system::Pallet<Test>;
+
+    #[runtime::pallet_index(1)]
+    pub type Balances = pallet_balances::Pallet<Test>;
+
+    #[runtime::pallet_index(2)]
+    pub type Uniques = pallet_uniques::Pallet<Test>;
+
+    #[runtime::pallet_index(3)]
+    pub type NftHierarchy = pallet_nft_hierarchy::Pallet<Test>;
+
+    #[runtime::pallet_index(4)]
+    pub type NftAttributes = pallet_nft_attributes::Pallet<Test>;
 }
 
+// frame_system
 #[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
 impl frame_system::Config for Test {
-	type Block = Block;
+    type Block = Block;
+    type AccountId = u64;
+    type AccountData = pallet_balances::AccountData<u128>;
+}
+
+// pallet_balances
+parameter_types! {
+    pub const ExistentialDeposit: u128 = 1_000_000_000_000;
+}
+
+impl pallet_balances::Config for Test {
+    type MaxLocks = ConstU32<50>;
+    type MaxReserves = ();
+    type ReserveIdentifier = [u8; 8];
+    type Balance = u128;
+    type RuntimeEvent = RuntimeEvent;
+    type DustRemoval = ();
+    type ExistentialDeposit = ExistentialDeposit;
+    type AccountStore = System;
+    type WeightInfo = ();
+    type FreezeIdentifier = RuntimeFreezeReason;
+    type MaxFreezes = VariantCountOf<RuntimeFreezeReason>;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type RuntimeFreezeReason = RuntimeFreezeReason;
+    type DoneSlashHandler = ();
+}
+
+// pallet_uniques
+parameter_types! {
+    pub const CollectionDeposit: u128 = 1_000_000_000_000;
+    pub const ItemDeposit: u128 = 100_000_000_000;
+    pub const MetadataDepositBase: u128 = 100_000_000_000;
+    pub const AttributeDepositBase: u128 = 10_000_000_000;
+    pub const DepositPerByte: u128 = 1_000_000_000;
+    pub const UniquesStringLimit: u32 = 128;
+    pub const UniquesKeyLimit: u32 = 64;
+    pub const UniquesValueLimit: u32 = 256;
+}
+
+impl pallet_uniques::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type CollectionId = u32;
+    type ItemId = u128;
+    type Currency = Balances;
+    type ForceOrigin = EnsureRoot<Self::AccountId>;
+    type CreateOrigin = EnsureSigned<Self::AccountId>;
+    type Locker = ();
+    type CollectionDeposit = CollectionDeposit;
+    type ItemDeposit = ItemDeposit;
+    type MetadataDepositBase = MetadataDepositBase;
+    type AttributeDepositBase = AttributeDepositBase;
+    type DepositPerByte = DepositPerByte;
+    type StringLimit = UniquesStringLimit;
+    type KeyLimit = UniquesKeyLimit;
+    type ValueLimit = UniquesValueLimit;
+    type WeightInfo = pallet_uniques::weights::SubstrateWeight<Test>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type Helper = ();
+}
+
+// pallet_nft_hierarchy
+parameter_types! {
+    pub const StringLimit: u32 = 128;
+    pub const TypeLimit: u32 = 100;
+    pub const MaxAssetsPerTransaction: u32 = 10;
+    pub const MaxInventorySize: u32 = 50;
+    pub const MaxBatchSize: u32 = 10;
+    pub const MaxAgents: u32 = 3;
+    pub const MaxLoansPerBlock: u32 = 3;
+    pub const MaxProvenance: u32 = 4;
+    pub const ProvenanceRetention: u64 = 10;
+    pub const NftAccountsPalletId: PalletId = PalletId(*b"py/nftac");
+}
+
+impl pallet_nft_hierarchy::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type CollectionId = u32;
+    type ItemId = u128;
+    type StringLimit = StringLimit;
+    type TypeLimit = TypeLimit;
+    type MaxAssetsPerTransaction = MaxAssetsPerTransaction;
+    type MaxInventorySize = MaxInventorySize;
+    type MaxBatchSize = MaxBatchSize;
+    type MaxAgents = MaxAgents;
+    type MaxLoansPerBlock = MaxLoansPerBlock;
+    type MaxProvenance = MaxProvenance;
+    type ProvenanceRetention = ProvenanceRetention;
+    type RuntimeCall = RuntimeCall;
+    type PalletId = NftAccountsPalletId;
+    type ForceOrigin = EnsureRoot<Self::AccountId>;
+    type Nfts = UniquesAdapter<Test>;
+    type NftCollectionConfig = ();
+    type NftItemConfig = ();
+    type WeightInfo = pallet_nft_hierarchy::weights::SubstrateWeight<Test>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = BenchmarkHelper;
+}
+
+// pallet_nft_attributes
+parameter_types! {
+    pub const KeyLimit: u32 = 64;
+    pub const ValueLimit: u32 = 256;
+    pub const AttributeLimit: u32 = 10;
+    pub const MaxInventoryDepth: u32 = 3;
+    pub const MaxInventoryItems: u32 = 50;
+    pub const MaxItemsPerQuery: u32 = 10;
+    pub const MaxItemsScanned: u32 = 20;
+    pub const ProposalLifetime: u64 = 10;
+    pub const CiphertextLimit: u32 = 128;
+    pub const CommitmentDeposit: u128 = 1_000_000_000_000;
 }
 
-impl pallet_attr::Config for Test {
-	type RuntimeEvent = RuntimeEvent;
-	type WeightInfo = ();
+impl pallet_nft_attributes::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type WeightInfo = ();
+    type CollectionId = u32;
+    type ItemId = u128;
+    type Nfts = UniquesAdapter<Test>;
+    type KeyLimit = KeyLimit;
+    type ValueLimit = ValueLimit;
+    type AttributeLimit = AttributeLimit;
+    type Inventory = NftHierarchy;
+    type MaxInventoryDepth = MaxInventoryDepth;
+    type MaxInventoryItems = MaxInventoryItems;
+    type MaxItemsPerQuery = MaxItemsPerQuery;
+    type MaxItemsScanned = MaxItemsScanned;
+    type ProposalLifetime = ProposalLifetime;
+    type OffchainSignature = TestSignature;
+    type OffchainPublic = UintAuthorityId;
+    type CiphertextLimit = CiphertextLimit;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type Currency = Balances;
+    type CommitmentDeposit = CommitmentDeposit;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = BenchmarkHelper;
+    #[cfg(feature = "runtime-benchmarks")]
+    type NftsHelper = BenchmarkHelper;
+}
+
+/// Signs updates in benchmarks with test signatures, which carry the signer and the message, and
+/// mints their NFTs in pallet_uniques, in collections owned by their first owner.
+#[cfg(feature = "runtime-benchmarks")]
+pub struct BenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_nft_attributes::BenchmarkHelper<UintAuthorityId, TestSignature> for BenchmarkHelper {
+    fn signer() -> UintAuthorityId {
+        UintAuthorityId(42)
+    }
+    fn sign(signer: &UintAuthorityId, message: &[u8]) -> TestSignature {
+        TestSignature(signer.0, message.to_vec())
+    }
+}
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_nft_hierarchy::BenchmarkHelper<u32, u128, u64> for BenchmarkHelper {
+    fn mint(collection: &u32, item: &u128, owner: &u64) {
+        if Uniques::collection_owner(*collection).is_none() {
+            Uniques::force_create(RuntimeOrigin::root(), *collection, *owner, true).unwrap();
+        }
+        let issuer = Uniques::collection_owner(*collection).unwrap();
+        Uniques::mint(RuntimeOrigin::signed(issuer), *collection, *item, *owner).unwrap();
+    }
 }
 
 // Build genesis storage according to the mock runtime.
-pub fn new_test_ext() -> sp_io::TestExternalities {
-	frame_system::GenesisConfig::<Test>::default().build_storage().unwrap().into()
+pub fn new_test_ext() -> TestExt {
+    TestExt(new_unchecked_test_ext())
+}
+
+/// Build genesis storage as new_test_ext does, without checking the invariants of the pallet at
+/// the end, for the tests that break them on purpose.
+pub fn new_unchecked_test_ext() -> sp_io::TestExternalities {
+    let mut storage = frame_system::GenesisConfig::<Test>::default()
+        .build_storage()
+        .unwrap();
+
+    // Accounts 1 to 3 with enough balance to pay the deposits of pallet_uniques.
+    pallet_balances::GenesisConfig::<Test> {
+        balances: vec![
+            (1, 1_000_000_000_000_000),
+            (2, 1_000_000_000_000_000),
+            (3, 1_000_000_000_000_000),
+        ],
+        dev_accounts: None,
+    }
+    .assimilate_storage(&mut storage)
+    .unwrap();
+
+    let mut ext = sp_io::TestExternalities::new(storage);
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
+
+/// Test externalities that check the invariants of the pallet once the test is over.
+pub struct TestExt(sp_io::TestExternalities);
+
+impl TestExt {
+    pub fn execute_with<R>(&mut self, execute: impl FnOnce() -> R) -> R {
+        self.0.execute_with(|| {
+            let result = execute();
+            NftAttributes::do_try_state().expect("the invariants of the pallet hold");
+            result
+        })
+    }
+}
+
+/// Account owning the collections created by `mint`, and so their default game master.
+pub const COLLECTION_OWNER: u64 = 1;
+
+/// Mint `item` of `collection` for `owner`, creating the collection first if needed.
+pub fn mint(collection: u32, item: u128, owner: u64) {
+    if Uniques::collection_owner(collection).is_none() {
+        Uniques::create(
+            RuntimeOrigin::signed(COLLECTION_OWNER),
+            collection,
+            COLLECTION_OWNER,
+        )
+        .unwrap();
+    }
+    Uniques::mint(
+        RuntimeOrigin::signed(COLLECTION_OWNER),
+        collection,
+        item,
+        owner,
+    )
+    .unwrap();
+}
+
+/// Build a list of attributes from key-value pairs.
+pub fn attributes(pairs: &[(&[u8], &[u8])]) -> AttributeList<Test> {
+    BoundedVec::try_from(
+        pairs
+            .iter()
+            .map(|(key, value)| {
+                (
+                    BoundedVec::try_from(key.to_vec()).unwrap(),
+                    BoundedVec::try_from(value.to_vec()).unwrap(),
+                )
+            })
+            .collect::<Vec<_>>(),
+    )
+    .unwrap()
+}
+
+/// Build a list of changes from keys and new values, `None` removing the key.
+pub fn changes(pairs: &[(&[u8], Option<&[u8]>)]) -> AttributeChanges<Test> {
+    BoundedVec::try_from(
+        pairs
+            .iter()
+            .map(|(key, value)| {
+                (
+                    BoundedVec::try_from(key.to_vec()).unwrap(),
+                    value.map(|value| BoundedVec::try_from(value.to_vec()).unwrap()),
+                )
+            })
+            .collect::<Vec<_>>(),
+    )
+    .unwrap()
 }
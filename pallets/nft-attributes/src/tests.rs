@@ -1,24 +1,11 @@
-use crate::{mock::*, Error, Event, Something};
-use frame_support::{assert_noop, assert_ok};
-
-#[test]
-fn it_works_for_default_value() {
-	new_test_ext().execute_with(|| {
-		// Go past genesis block so events get deposited
-		System::set_block_number(1);
-		// Dispatch a signed extrinsic.
-		assert_ok!(Template::do_something(RuntimeOrigin::signed(1), 42));
-		// Read pallet storage and assert an expected result.
-		assert_eq!(Something::<Test>::get(), Some(42));
-		// Assert that the correct event was deposited
-		System::assert_last_event(Event::SomethingStored { something: 42, who: 1 }.into());
-	});
-}
-
-#[test]
-fn correct_error_for_none_value() {
-	new_test_ext().execute_with(|| {
-		// Ensure the expected error is thrown when no value is present.
-		assert_noop!(Template::cause_error(RuntimeOrigin::signed(1)), Error::<Test>::NoneValue);
-	});
-}
+pub mod nft_attributes__set_attributes;
+pub mod nft_attributes__clear_attributes;
+pub mod nft_attributes__templates;
+pub mod nft_attributes__proposals;
+pub mod nft_attributes__submit_signed_update;
+pub mod nft_attributes__hidden_attributes;
+pub mod nft_attributes__freeze_attributes;
+pub mod nft_attributes__queries;
+pub mod nft_attributes__genesis;
+pub mod nft_attributes__try_state;
+pub mod nft_attributes__mutate_attributes;
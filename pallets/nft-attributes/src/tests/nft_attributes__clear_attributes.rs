@@ -0,0 +1,116 @@
+#![allow(non_snake_case)]
+
+use super::super::*;
+use crate::mock::{
+    attributes, mint, new_test_ext, NftAttributes, RuntimeOrigin, System, Test, Uniques,
+};
+use frame_support::{assert_noop, assert_ok};
+
+#[test]
+fn works() {
+    new_test_ext().execute_with(|| {
+        let collection = 1u32;
+        let item = 1u128;
+        let who = 1u64;
+        mint(collection, item, who);
+        assert_ok!(NftAttributes::set_attributes(
+            RuntimeOrigin::signed(who),
+            collection,
+            item,
+            attributes(&[(b"strength", b"14")])
+        ));
+
+        assert_ok!(NftAttributes::clear_attributes(
+            RuntimeOrigin::signed(who),
+            collection,
+            item
+        ));
+        assert!(!Attributes::<Test>::contains_key(collection, item));
+        assert_eq!(
+            NftAttributes::attribute(collection, item, b"strength"),
+            None
+        );
+
+        // Verify event.
+        System::assert_last_event(
+            Event::<Test>::AttributesCleared {
+                collection,
+                item,
+                who,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn falls_back_on_collection_defaults() {
+    new_test_ext().execute_with(|| {
+        let collection = 1u32;
+        let item = 1u128;
+        let who = 1u64;
+        mint(collection, item, who);
+        assert_ok!(NftAttributes::set_collection_defaults(
+            RuntimeOrigin::signed(who),
+            collection,
+            attributes(&[(b"strength", b"10")])
+        ));
+        assert_ok!(NftAttributes::set_attributes(
+            RuntimeOrigin::signed(who),
+            collection,
+            item,
+            attributes(&[(b"strength", b"14")])
+        ));
+
+        assert_ok!(NftAttributes::clear_attributes(
+            RuntimeOrigin::signed(who),
+            collection,
+            item
+        ));
+        assert_eq!(
+            NftAttributes::attribute(collection, item, b"strength"),
+            Some(b"10".to_vec())
+        );
+    });
+}
+
+#[test]
+fn fails_for_non_owner() {
+    new_test_ext().execute_with(|| {
+        let collection = 1u32;
+        let item = 1u128;
+        mint(collection, item, 1);
+
+        assert_noop!(
+            NftAttributes::clear_attributes(RuntimeOrigin::signed(2), collection, item),
+            Error::<Test>::NotOwner
+        );
+    });
+}
+
+#[test]
+fn fails_for_burned_item() {
+    new_test_ext().execute_with(|| {
+        let collection = 1u32;
+        let item = 1u128;
+        let who = 1u64;
+        mint(collection, item, who);
+        assert_ok!(NftAttributes::set_attributes(
+            RuntimeOrigin::signed(who),
+            collection,
+            item,
+            attributes(&[(b"strength", b"14")])
+        ));
+        assert_ok!(Uniques::burn(
+            RuntimeOrigin::signed(who),
+            collection,
+            item,
+            None
+        ));
+
+        assert_noop!(
+            NftAttributes::clear_attributes(RuntimeOrigin::signed(who), collection, item),
+            Error::<Test>::NotOwner
+        );
+    });
+}
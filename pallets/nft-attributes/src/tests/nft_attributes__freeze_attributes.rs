@@ -0,0 +1,286 @@
+#![allow(non_snake_case)]
+
+use super::super::*;
+use crate::mock::{attributes, mint, new_test_ext, NftAttributes, RuntimeOrigin, System, Test};
+use frame_support::{assert_noop, assert_ok};
+
+#[test]
+fn frozen_item_rejects_changes() {
+    new_test_ext().execute_with(|| {
+        let collection = 1u32;
+        let item = 1u128;
+        let (game_master, player) = (1u64, 2u64);
+        mint(collection, item, player);
+
+        assert_ok!(NftAttributes::freeze_attributes(
+            RuntimeOrigin::signed(game_master),
+            collection,
+            Some(item),
+            false
+        ));
+        System::assert_last_event(
+            Event::<Test>::AttributesFrozen {
+                collection,
+                item: Some(item),
+                kind: FreezeKind::Temporary,
+                who: game_master,
+            }
+            .into(),
+        );
+        assert_noop!(
+            NftAttributes::set_attributes(
+                RuntimeOrigin::signed(player),
+                collection,
+                item,
+                attributes(&[(b"strength", b"14")])
+            ),
+            Error::<Test>::AttributesFrozen
+        );
+        assert_noop!(
+            NftAttributes::clear_attributes(RuntimeOrigin::signed(player), collection, item),
+            Error::<Test>::AttributesFrozen
+        );
+
+        assert_ok!(NftAttributes::thaw_attributes(
+            RuntimeOrigin::signed(game_master),
+            collection,
+            Some(item)
+        ));
+        assert_ok!(NftAttributes::set_attributes(
+            RuntimeOrigin::signed(player),
+            collection,
+            item,
+            attributes(&[(b"strength", b"14")])
+        ));
+    });
+}
+
+#[test]
+fn frozen_collection_rejects_changes() {
+    new_test_ext().execute_with(|| {
+        let collection = 1u32;
+        let who = 1u64;
+        mint(collection, 1, who);
+
+        assert_ok!(NftAttributes::freeze_attributes(
+            RuntimeOrigin::signed(who),
+            collection,
+            None,
+            false
+        ));
+        assert_noop!(
+            NftAttributes::set_collection_defaults(
+                RuntimeOrigin::signed(who),
+                collection,
+                attributes(&[(b"strength", b"10")])
+            ),
+            Error::<Test>::AttributesFrozen
+        );
+        assert_noop!(
+            NftAttributes::set_attributes(
+                RuntimeOrigin::signed(who),
+                collection,
+                1,
+                attributes(&[(b"strength", b"14")])
+            ),
+            Error::<Test>::AttributesFrozen
+        );
+    });
+}
+
+#[test]
+fn permanent_freeze_cannot_be_thawed() {
+    new_test_ext().execute_with(|| {
+        let collection = 1u32;
+        let item = 1u128;
+        let who = 1u64;
+        mint(collection, item, who);
+
+        assert_noop!(
+            NftAttributes::thaw_attributes(RuntimeOrigin::signed(who), collection, Some(item)),
+            Error::<Test>::NotFrozen
+        );
+        assert_noop!(
+            NftAttributes::freeze_attributes(
+                RuntimeOrigin::signed(2),
+                collection,
+                Some(item),
+                true
+            ),
+            Error::<Test>::NotGameMaster
+        );
+        assert_ok!(NftAttributes::freeze_attributes(
+            RuntimeOrigin::signed(who),
+            collection,
+            Some(item),
+            true
+        ));
+        assert_noop!(
+            NftAttributes::thaw_attributes(RuntimeOrigin::signed(who), collection, Some(item)),
+            Error::<Test>::PermanentlyFrozen
+        );
+        assert_noop!(
+            NftAttributes::freeze_attributes(
+                RuntimeOrigin::signed(who),
+                collection,
+                Some(item),
+                false
+            ),
+            Error::<Test>::PermanentlyFrozen
+        );
+    });
+}
+
+#[test]
+fn frozen_item_keeps_the_defaults_of_its_collection() {
+    new_test_ext().execute_with(|| {
+        let collection = 1u32;
+        let (item, other) = (1u128, 2u128);
+        let (game_master, player) = (1u64, 2u64);
+        mint(collection, item, player);
+        mint(collection, other, player);
+        assert_ok!(NftAttributes::set_collection_defaults(
+            RuntimeOrigin::signed(game_master),
+            collection,
+            attributes(&[(b"strength", b"10"), (b"speed", b"5")])
+        ));
+
+        assert_ok!(NftAttributes::freeze_attributes(
+            RuntimeOrigin::signed(game_master),
+            collection,
+            Some(item),
+            false
+        ));
+        assert_eq!(
+            Attributes::<Test>::get(collection, item),
+            attributes(&[(b"strength", b"10"), (b"speed", b"5")])
+        );
+        assert_ok!(NftAttributes::set_collection_defaults(
+            RuntimeOrigin::signed(game_master),
+            collection,
+            attributes(&[(b"strength", b"18"), (b"speed", b"5"), (b"luck", b"3")])
+        ));
+        assert_eq!(
+            NftAttributes::attribute(collection, item, b"strength"),
+            Some(b"10".to_vec())
+        );
+        assert_eq!(NftAttributes::attribute(collection, item, b"luck"), None);
+        // The items that are not frozen follow the new defaults.
+        assert_eq!(
+            NftAttributes::attribute(collection, other, b"strength"),
+            Some(b"18".to_vec())
+        );
+
+        // Once thawed, the item inherits again what its copies still match and keeps the rest.
+        assert_ok!(NftAttributes::thaw_attributes(
+            RuntimeOrigin::signed(game_master),
+            collection,
+            Some(item)
+        ));
+        assert_eq!(
+            Attributes::<Test>::get(collection, item),
+            attributes(&[(b"strength", b"10")])
+        );
+        assert_eq!(
+            NftAttributes::attribute(collection, item, b"luck"),
+            Some(b"3".to_vec())
+        );
+    });
+}
+
+#[test]
+fn frozen_item_keeps_its_template() {
+    new_test_ext().execute_with(|| {
+        let collection = 1u32;
+        let item = 1u128;
+        let (game_master, player) = (1u64, 2u64);
+        mint(collection, item, player);
+        assert_ok!(NftAttributes::set_collection_defaults(
+            RuntimeOrigin::signed(game_master),
+            collection,
+            attributes(&[(b"class", b"peasant"), (b"strength", b"10")])
+        ));
+        assert_ok!(NftAttributes::set_template(
+            RuntimeOrigin::signed(game_master),
+            collection,
+            7,
+            attributes(&[(b"class", b"warrior")])
+        ));
+        assert_ok!(NftAttributes::assign_template(
+            RuntimeOrigin::signed(game_master),
+            collection,
+            item,
+            Some(7)
+        ));
+
+        assert_ok!(NftAttributes::freeze_attributes(
+            RuntimeOrigin::signed(game_master),
+            collection,
+            Some(item),
+            true
+        ));
+        // The template takes precedence over the defaults in the copy.
+        assert_eq!(
+            Attributes::<Test>::get(collection, item),
+            attributes(&[(b"class", b"warrior"), (b"strength", b"10")])
+        );
+        assert_ok!(NftAttributes::set_template(
+            RuntimeOrigin::signed(game_master),
+            collection,
+            7,
+            attributes(&[(b"class", b"mage")])
+        ));
+        assert_ok!(NftAttributes::set_template(
+            RuntimeOrigin::signed(game_master),
+            collection,
+            7,
+            attributes(&[])
+        ));
+        assert_eq!(
+            NftAttributes::attribute(collection, item, b"class"),
+            Some(b"warrior".to_vec())
+        );
+        assert_eq!(
+            NftAttributes::attribute(collection, item, b"strength"),
+            Some(b"10".to_vec())
+        );
+    });
+}
+
+#[test]
+fn freezing_fails_if_the_inherited_attributes_do_not_fit() {
+    new_test_ext().execute_with(|| {
+        let collection = 1u32;
+        let item = 1u128;
+        let (game_master, player) = (1u64, 2u64);
+        mint(collection, item, player);
+        let limit = <Test as Config>::AttributeLimit::get();
+        let keys: Vec<Vec<u8>> = (0..limit)
+            .map(|index| index.to_le_bytes().to_vec())
+            .collect();
+        let list = |keys: &[Vec<u8>]| -> AttributeList<Test> {
+            keys.iter()
+                .map(|key| {
+                    (
+                        key.clone().try_into().unwrap(),
+                        b"1".to_vec().try_into().unwrap(),
+                    )
+                })
+                .collect::<Vec<_>>()
+                .try_into()
+                .unwrap()
+        };
+        CollectionDefaults::<Test>::insert(collection, list(&keys));
+        Attributes::<Test>::insert(collection, item, list(&[b"own".to_vec()]));
+
+        assert_noop!(
+            NftAttributes::freeze_attributes(
+                RuntimeOrigin::signed(game_master),
+                collection,
+                Some(item),
+                false
+            ),
+            Error::<Test>::AttributeLimitReached
+        );
+    });
+}
@@ -0,0 +1,85 @@
+#![allow(non_snake_case)]
+
+use super::super::*;
+use crate::mock::{attributes, NftAttributes, Test};
+use sp_runtime::BuildStorage;
+
+const HEROES: u32 = 1;
+const ARMORY: u32 = 2;
+const KNIGHT: u128 = 1;
+const SWORD: u128 = 101;
+
+/// Build the storage of the mock with a knight who owns a sword, both held by the account 2, and
+/// `genesis` for their attributes.
+fn new_world(genesis: GenesisConfig<Test>) -> sp_io::TestExternalities {
+    let mut storage = frame_system::GenesisConfig::<Test>::default()
+        .build_storage()
+        .unwrap();
+    pallet_balances::GenesisConfig::<Test> {
+        balances: vec![(1, 1_000_000_000_000_000)],
+        dev_accounts: None,
+    }
+    .assimilate_storage(&mut storage)
+    .unwrap();
+    pallet_nft_hierarchy::GenesisConfig::<Test> {
+        collections: vec![(HEROES, 1), (ARMORY, 1)],
+        items: vec![(HEROES, KNIGHT, 2), (ARMORY, SWORD, 2)],
+        nfts: vec![
+            (HEROES, KNIGHT, vec![b"owner".to_vec()]),
+            (ARMORY, SWORD, vec![b"weapon".to_vec()]),
+        ],
+        ownerships: vec![((HEROES, KNIGHT), (ARMORY, SWORD))],
+    }
+    .assimilate_storage(&mut storage)
+    .unwrap();
+    genesis.assimilate_storage(&mut storage).unwrap();
+    storage.into()
+}
+
+fn pairs(pairs: &[(&[u8], &[u8])]) -> GenesisAttributes {
+    pairs.iter().map(|(key, value)| (key.to_vec(), value.to_vec())).collect()
+}
+
+#[test]
+fn works() {
+    let genesis = GenesisConfig {
+        game_masters: vec![(HEROES, 3)],
+        collection_defaults: vec![(HEROES, pairs(&[(b"class", b"peasant"), (b"strength", b"10")]))],
+        attributes: vec![
+            (HEROES, KNIGHT, pairs(&[(b"class", b"knight"), (b"strength", b"10")])),
+            (ARMORY, SWORD, pairs(&[(b"strength", b"+2")])),
+        ],
+    };
+    new_world(genesis).execute_with(|| {
+        assert_eq!(GameMasters::<Test>::get(HEROES), Some(3));
+        // Only the attributes in which the knight deviates from the defaults are stored.
+        assert_eq!(Attributes::<Test>::get(HEROES, KNIGHT), attributes(&[(b"class", b"knight")]));
+        assert_eq!(
+            NftAttributes::effective_attributes(HEROES, KNIGHT),
+            vec![
+                (b"class".to_vec(), b"knight".to_vec()),
+                (b"strength".to_vec(), b"12".to_vec()),
+            ]
+        );
+        assert!(NftAttributes::do_try_state().is_ok());
+    });
+}
+
+#[test]
+#[should_panic(expected = "the items with attributes at genesis exist")]
+fn fails_with_a_missing_item() {
+    new_world(GenesisConfig {
+        attributes: vec![(ARMORY, 102, pairs(&[(b"strength", b"+1")]))],
+        ..Default::default()
+    });
+}
+
+#[test]
+#[should_panic(expected = "the keys of the genesis fit in KeyLimit")]
+fn fails_with_a_key_too_long() {
+    let key = vec![b'k'; 65];
+    new_world(GenesisConfig {
+        attributes: vec![(ARMORY, SWORD, pairs(&[(&key, b"+1")]))],
+        ..Default::default()
+    });
+}
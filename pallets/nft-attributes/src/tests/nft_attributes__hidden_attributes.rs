@@ -0,0 +1,292 @@
+#![allow(non_snake_case)]
+
+use super::super::*;
+use crate::mock::{
+    attributes, mint, new_test_ext, Balances, CommitmentDeposit, NftAttributes, RuntimeOrigin,
+    System, Test,
+};
+use frame_support::{
+    assert_noop, assert_ok,
+    traits::fungible::InspectHold,
+    BoundedVec,
+};
+use sp_runtime::{
+    traits::{BlakeTwo256, Hash},
+    TokenError,
+};
+
+fn key() -> AttributeKey<Test> {
+    BoundedVec::try_from(b"secret_quest".to_vec()).unwrap()
+}
+
+fn value() -> AttributeValue<Test> {
+    BoundedVec::try_from(b"dragon".to_vec()).unwrap()
+}
+
+fn held(who: u64) -> u128 {
+    Balances::balance_on_hold(&HoldReason::AttributeCommitment.into(), &who)
+}
+
+#[test]
+fn reveal_sets_the_attribute() {
+    new_test_ext().execute_with(|| {
+        let collection = 1u32;
+        let item = 1u128;
+        let who = 1u64;
+        let salt = [7u8; 32];
+        mint(collection, item, who);
+
+        let hash = BlakeTwo256::hash_of(&(&key(), &value(), &salt));
+        assert_ok!(NftAttributes::commit_attribute(
+            RuntimeOrigin::signed(who),
+            collection,
+            item,
+            key(),
+            hash,
+            None
+        ));
+        // Hidden attributes are not readable until revealed.
+        assert_eq!(
+            NftAttributes::attribute(collection, item, b"secret_quest"),
+            None
+        );
+
+        assert_ok!(NftAttributes::reveal_attribute(
+            RuntimeOrigin::signed(2),
+            collection,
+            item,
+            key(),
+            value(),
+            salt
+        ));
+        assert_eq!(
+            NftAttributes::attribute(collection, item, b"secret_quest"),
+            Some(b"dragon".to_vec())
+        );
+        assert!(!Commitments::<Test>::contains_key((
+            collection,
+            item,
+            key()
+        )));
+        System::assert_last_event(
+            Event::<Test>::AttributeRevealed {
+                collection,
+                item,
+                key: key(),
+                value: value(),
+                who: 2,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn fails_for_wrong_reveal() {
+    new_test_ext().execute_with(|| {
+        let collection = 1u32;
+        let item = 1u128;
+        let who = 1u64;
+        mint(collection, item, who);
+
+        let hash = BlakeTwo256::hash_of(&(&key(), &value(), &[7u8; 32]));
+        assert_ok!(NftAttributes::commit_attribute(
+            RuntimeOrigin::signed(who),
+            collection,
+            item,
+            key(),
+            hash,
+            None
+        ));
+        assert_noop!(
+            NftAttributes::reveal_attribute(
+                RuntimeOrigin::signed(who),
+                collection,
+                item,
+                key(),
+                value(),
+                [8u8; 32]
+            ),
+            Error::<Test>::WrongReveal
+        );
+        assert_noop!(
+            NftAttributes::commit_attribute(
+                RuntimeOrigin::signed(2),
+                collection,
+                item,
+                key(),
+                hash,
+                None
+            ),
+            Error::<Test>::NotOwner
+        );
+    });
+}
+
+#[test]
+fn fails_without_commitment() {
+    new_test_ext().execute_with(|| {
+        mint(1, 1, 1);
+
+        assert_noop!(
+            NftAttributes::reveal_attribute(
+                RuntimeOrigin::signed(1),
+                1,
+                1,
+                key(),
+                value(),
+                [7u8; 32]
+            ),
+            Error::<Test>::CommitmentNotFound
+        );
+    });
+}
+
+#[test]
+fn commitment_holds_a_deposit_until_revealed() {
+    new_test_ext().execute_with(|| {
+        let collection = 1u32;
+        let item = 1u128;
+        let who = 1u64;
+        let salt = [7u8; 32];
+        mint(collection, item, who);
+
+        let hash = BlakeTwo256::hash_of(&(&key(), &value(), &salt));
+        assert_ok!(NftAttributes::commit_attribute(
+            RuntimeOrigin::signed(who),
+            collection,
+            item,
+            key(),
+            hash,
+            None
+        ));
+        assert_eq!(held(who), CommitmentDeposit::get());
+        // Replacing the commitment keeps the deposit already held.
+        assert_ok!(NftAttributes::commit_attribute(
+            RuntimeOrigin::signed(who),
+            collection,
+            item,
+            key(),
+            hash,
+            None
+        ));
+        assert_eq!(held(who), CommitmentDeposit::get());
+
+        assert_ok!(NftAttributes::reveal_attribute(
+            RuntimeOrigin::signed(2),
+            collection,
+            item,
+            key(),
+            value(),
+            salt
+        ));
+        assert_eq!(held(who), 0);
+        assert_eq!(held(2), 0);
+    });
+}
+
+#[test]
+fn fails_without_funds_for_the_deposit() {
+    new_test_ext().execute_with(|| {
+        let poor = 4u64;
+        mint(1, 1, poor);
+
+        assert_noop!(
+            NftAttributes::commit_attribute(
+                RuntimeOrigin::signed(poor),
+                1,
+                1,
+                key(),
+                BlakeTwo256::hash_of(&(&key(), &value(), &[7u8; 32])),
+                None
+            ),
+            TokenError::FundsUnavailable
+        );
+    });
+}
+
+#[test]
+fn cancel_returns_the_deposit() {
+    new_test_ext().execute_with(|| {
+        let collection = 1u32;
+        let item = 1u128;
+        let who = 1u64;
+        mint(collection, item, who);
+        assert_ok!(NftAttributes::commit_attribute(
+            RuntimeOrigin::signed(who),
+            collection,
+            item,
+            key(),
+            BlakeTwo256::hash_of(&(&key(), &value(), &[7u8; 32])),
+            None
+        ));
+
+        assert_noop!(
+            NftAttributes::cancel_commitment(RuntimeOrigin::signed(2), collection, item, key()),
+            Error::<Test>::NotCommitter
+        );
+        assert_ok!(NftAttributes::cancel_commitment(
+            RuntimeOrigin::signed(who),
+            collection,
+            item,
+            key()
+        ));
+        System::assert_last_event(
+            Event::<Test>::CommitmentCancelled {
+                collection,
+                item,
+                key: key(),
+                who,
+            }
+            .into(),
+        );
+        assert!(!Commitments::<Test>::contains_key((collection, item, key())));
+        assert_eq!(held(who), 0);
+        assert_noop!(
+            NftAttributes::cancel_commitment(RuntimeOrigin::signed(who), collection, item, key()),
+            Error::<Test>::CommitmentNotFound
+        );
+    });
+}
+
+#[test]
+fn commitments_count_against_the_attribute_limit() {
+    new_test_ext().execute_with(|| {
+        let collection = 1u32;
+        let item = 1u128;
+        let who = 1u64;
+        mint(collection, item, who);
+        let limit = <Test as Config>::AttributeLimit::get();
+        assert_ok!(NftAttributes::set_attributes(
+            RuntimeOrigin::signed(who),
+            collection,
+            item,
+            attributes(&[(b"strength", b"14"), (b"speed", b"5")])
+        ));
+        let commit = |key: &[u8]| {
+            NftAttributes::commit_attribute(
+                RuntimeOrigin::signed(who),
+                collection,
+                item,
+                BoundedVec::try_from(key.to_vec()).unwrap(),
+                BlakeTwo256::hash_of(&(key, &value(), &[7u8; 32])),
+                None,
+            )
+        };
+        for index in 2..limit {
+            assert_ok!(commit(&index.to_le_bytes()));
+        }
+
+        assert_noop!(commit(b"secret_quest"), Error::<Test>::AttributeLimitReached);
+        // A value committed for a key the item stores replaces it when revealed.
+        assert_ok!(commit(b"strength"));
+        // Cancelling a commitment makes room again.
+        assert_ok!(NftAttributes::cancel_commitment(
+            RuntimeOrigin::signed(who),
+            collection,
+            item,
+            BoundedVec::try_from(2u32.to_le_bytes().to_vec()).unwrap()
+        ));
+        assert_ok!(commit(b"secret_quest"));
+    });
+}
@@ -0,0 +1,83 @@
+#![allow(non_snake_case)]
+
+use super::super::*;
+use crate::mock::{mint, new_test_ext, NftAttributes, RuntimeOrigin, System, Test, COLLECTION_OWNER};
+use crate::traits::MutateAttributes;
+use frame_support::{assert_noop, assert_ok};
+
+const PLAYER: u64 = 2;
+
+#[test]
+fn game_master_sets_an_attribute() {
+    new_test_ext().execute_with(|| {
+        mint(1, 1, PLAYER);
+
+        assert_ok!(NftAttributes::ensure_can_set(&COLLECTION_OWNER, &1, &1));
+        assert_ok!(<NftAttributes as MutateAttributes<_, _, _>>::set_attribute(
+            &COLLECTION_OWNER,
+            &1,
+            &1,
+            b"initiative",
+            b"17"
+        ));
+
+        System::assert_last_event(
+            Event::<Test>::AttributesSet { collection: 1, item: 1, who: COLLECTION_OWNER }.into(),
+        );
+        assert_eq!(NftAttributes::attributes(1, 1), vec![(b"initiative".to_vec(), b"17".to_vec())]);
+    });
+}
+
+#[test]
+fn only_the_game_master_sets_attributes() {
+    new_test_ext().execute_with(|| {
+        mint(1, 1, PLAYER);
+
+        assert_noop!(
+            NftAttributes::ensure_can_set(&PLAYER, &1, &1),
+            Error::<Test>::NotGameMaster
+        );
+        assert_noop!(
+            <NftAttributes as MutateAttributes<_, _, _>>::set_attribute(
+                &PLAYER,
+                &1,
+                &1,
+                b"initiative",
+                b"17"
+            ),
+            Error::<Test>::NotGameMaster
+        );
+    });
+}
+
+#[test]
+fn rejects_missing_items_frozen_attributes_and_long_keys() {
+    new_test_ext().execute_with(|| {
+        mint(1, 1, PLAYER);
+
+        assert_noop!(
+            NftAttributes::ensure_can_set(&COLLECTION_OWNER, &1, &2),
+            Error::<Test>::TokenNotFound
+        );
+        assert_noop!(
+            <NftAttributes as MutateAttributes<_, _, _>>::set_attribute(
+                &COLLECTION_OWNER,
+                &1,
+                &1,
+                &[b'k'; 65],
+                b"17"
+            ),
+            Error::<Test>::KeyTooLong
+        );
+        assert_ok!(NftAttributes::freeze_attributes(
+            RuntimeOrigin::signed(COLLECTION_OWNER),
+            1,
+            Some(1),
+            false
+        ));
+        assert_noop!(
+            NftAttributes::ensure_can_set(&COLLECTION_OWNER, &1, &1),
+            Error::<Test>::AttributesFrozen
+        );
+    });
+}
@@ -0,0 +1,150 @@
+#![allow(non_snake_case)]
+
+use super::super::*;
+use crate::mock::{changes, mint, new_test_ext, NftAttributes, RuntimeOrigin, System, Test};
+use frame_support::{assert_noop, assert_ok};
+
+#[test]
+fn approval_applies_changes() {
+    new_test_ext().execute_with(|| {
+        let collection = 1u32;
+        let item = 1u128;
+        let (game_master, player) = (1u64, 2u64);
+        mint(collection, item, player);
+
+        assert_ok!(NftAttributes::propose_attributes(
+            RuntimeOrigin::signed(player),
+            collection,
+            item,
+            changes(&[(b"level", Some(b"2"))])
+        ));
+        assert_noop!(
+            NftAttributes::propose_attributes(
+                RuntimeOrigin::signed(player),
+                collection,
+                item,
+                changes(&[(b"level", Some(b"3"))])
+            ),
+            Error::<Test>::ProposalPending
+        );
+        assert_noop!(
+            NftAttributes::approve_attributes(RuntimeOrigin::signed(player), collection, item),
+            Error::<Test>::NotGameMaster
+        );
+
+        assert_ok!(NftAttributes::approve_attributes(
+            RuntimeOrigin::signed(game_master),
+            collection,
+            item
+        ));
+        assert_eq!(
+            NftAttributes::attribute(collection, item, b"level"),
+            Some(b"2".to_vec())
+        );
+        assert!(!Proposals::<Test>::contains_key(collection, item));
+        System::assert_last_event(
+            Event::<Test>::ProposalApproved {
+                collection,
+                item,
+                who: game_master,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn rejection_discards_changes() {
+    new_test_ext().execute_with(|| {
+        let collection = 1u32;
+        let item = 1u128;
+        let (game_master, player) = (1u64, 2u64);
+        mint(collection, item, player);
+
+        assert_ok!(NftAttributes::propose_attributes(
+            RuntimeOrigin::signed(player),
+            collection,
+            item,
+            changes(&[(b"level", Some(b"2"))])
+        ));
+        assert_ok!(NftAttributes::reject_attributes(
+            RuntimeOrigin::signed(game_master),
+            collection,
+            item
+        ));
+        assert_eq!(NftAttributes::attribute(collection, item, b"level"), None);
+        assert_noop!(
+            NftAttributes::approve_attributes(RuntimeOrigin::signed(game_master), collection, item),
+            Error::<Test>::ProposalNotFound
+        );
+    });
+}
+
+#[test]
+fn fails_once_expired() {
+    new_test_ext().execute_with(|| {
+        let collection = 1u32;
+        let item = 1u128;
+        let (game_master, player) = (1u64, 2u64);
+        mint(collection, item, player);
+
+        assert_ok!(NftAttributes::propose_attributes(
+            RuntimeOrigin::signed(player),
+            collection,
+            item,
+            changes(&[(b"level", Some(b"2"))])
+        ));
+        System::set_block_number(1 + <Test as Config>::ProposalLifetime::get() + 1);
+
+        assert_noop!(
+            NftAttributes::approve_attributes(RuntimeOrigin::signed(game_master), collection, item),
+            Error::<Test>::ProposalExpired
+        );
+        // An expired proposal no longer blocks a new one.
+        assert_ok!(NftAttributes::propose_attributes(
+            RuntimeOrigin::signed(player),
+            collection,
+            item,
+            changes(&[(b"level", Some(b"3"))])
+        ));
+    });
+}
+
+#[test]
+fn fails_over_attribute_limit() {
+    new_test_ext().execute_with(|| {
+        let collection = 1u32;
+        let item = 1u128;
+        let (game_master, player) = (1u64, 2u64);
+        mint(collection, item, player);
+
+        // Fill the item up to the limit.
+        let keys: Vec<[u8; 1]> = (0..<Test as Config>::AttributeLimit::get() as u8)
+            .map(|index| [b'a' + index])
+            .collect();
+        let full: Vec<(&[u8], Option<&[u8]>)> =
+            keys.iter().map(|key| (&key[..], Some(&b"1"[..]))).collect();
+        assert_ok!(NftAttributes::propose_attributes(
+            RuntimeOrigin::signed(player),
+            collection,
+            item,
+            changes(&full)
+        ));
+        assert_ok!(NftAttributes::approve_attributes(
+            RuntimeOrigin::signed(game_master),
+            collection,
+            item
+        ));
+
+        assert_ok!(NftAttributes::propose_attributes(
+            RuntimeOrigin::signed(player),
+            collection,
+            item,
+            changes(&[(b"level", Some(b"2"))])
+        ));
+        assert_noop!(
+            NftAttributes::approve_attributes(RuntimeOrigin::signed(game_master), collection, item),
+            Error::<Test>::AttributeLimitReached
+        );
+    });
+}
@@ -0,0 +1,247 @@
+#![allow(non_snake_case)]
+
+use super::super::*;
+use crate::mock::{
+    attributes, mint, new_test_ext, NftAttributes, NftHierarchy, RuntimeOrigin, Test, Uniques,
+};
+use frame_support::{assert_ok, traits::tokens::nonfungibles_v2::Inspect, BoundedVec};
+
+fn register(item: u128, tags: &[&[u8]]) {
+    let tags = BoundedVec::try_from(
+        tags.iter()
+            .map(|tag| BoundedVec::try_from(tag.to_vec()).unwrap())
+            .collect::<Vec<_>>(),
+    )
+    .unwrap();
+    assert_ok!(NftHierarchy::register_asset(
+        RuntimeOrigin::signed(1),
+        1,
+        item,
+        tags
+    ));
+}
+
+#[test]
+fn effective_attributes_add_inventory_modifiers() {
+    new_test_ext().execute_with(|| {
+        let who = 1u64;
+        let (character, sword, gem) = (1u128, 2u128, 3u128);
+        for item in [character, sword, gem] {
+            mint(1, item, who);
+        }
+        register(character, &[b"owner"]);
+        register(sword, &[b"owner"]);
+        register(gem, &[b"entity"]);
+        assert_ok!(NftHierarchy::set_ownership(
+            RuntimeOrigin::signed(who),
+            1,
+            character,
+            1,
+            sword,
+            0
+        ));
+        assert_ok!(NftHierarchy::set_ownership(
+            RuntimeOrigin::signed(who),
+            1,
+            sword,
+            1,
+            gem,
+            0
+        ));
+
+        assert_ok!(NftAttributes::set_attributes(
+            RuntimeOrigin::signed(who),
+            1,
+            character,
+            attributes(&[(b"strength", b"14")])
+        ));
+        assert_ok!(NftAttributes::set_attributes(
+            RuntimeOrigin::signed(who),
+            1,
+            sword,
+            attributes(&[(b"strength", b"+2"), (b"name", b"Blade")])
+        ));
+        assert_ok!(NftAttributes::set_attributes(
+            RuntimeOrigin::signed(who),
+            1,
+            gem,
+            attributes(&[(b"strength", b"+1"), (b"luck", b"-1")])
+        ));
+
+        assert_eq!(
+            NftAttributes::effective_attributes(1, character),
+            vec![
+                (b"strength".to_vec(), b"17".to_vec()),
+                (b"luck".to_vec(), b"-1".to_vec()),
+            ]
+        );
+    });
+}
+
+#[test]
+fn effective_attributes_add_inherited_modifiers() {
+    new_test_ext().execute_with(|| {
+        let who = 1u64;
+        let (character, sword) = (1u128, 2u128);
+        for item in [character, sword] {
+            mint(1, item, who);
+        }
+        register(character, &[b"owner"]);
+        register(sword, &[b"entity"]);
+        assert_ok!(NftHierarchy::set_ownership(
+            RuntimeOrigin::signed(who),
+            1,
+            character,
+            1,
+            sword,
+            0
+        ));
+
+        // The sword stores nothing: its modifier comes from the template of swords.
+        assert_ok!(NftAttributes::set_template(
+            RuntimeOrigin::signed(who),
+            1,
+            7,
+            attributes(&[(b"ac", b"+2")])
+        ));
+        assert_ok!(NftAttributes::assign_template(
+            RuntimeOrigin::signed(who),
+            1,
+            sword,
+            Some(7)
+        ));
+        assert_ok!(NftAttributes::set_attributes(
+            RuntimeOrigin::signed(who),
+            1,
+            character,
+            attributes(&[(b"ac", b"10")])
+        ));
+
+        assert_eq!(
+            NftAttributes::effective_attributes(1, character),
+            vec![(b"ac".to_vec(), b"12".to_vec())]
+        );
+    });
+}
+
+#[test]
+fn items_matching_filters_and_pages() {
+    new_test_ext().execute_with(|| {
+        let who = 1u64;
+        for (item, strength) in [(1u128, b"8"), (2, b"5"), (3, b"9"), (4, b"7")] {
+            mint(1, item, who);
+            assert_ok!(NftAttributes::set_attributes(
+                RuntimeOrigin::signed(who),
+                1,
+                item,
+                attributes(&[(b"strength", strength)])
+            ));
+        }
+
+        let filter = AttributeFilter::Range {
+            min: Some(7),
+            max: None,
+        };
+        let (mut found, next) = NftAttributes::items_matching(1, b"strength", &filter, None, 10);
+        found.sort();
+        assert_eq!(found, vec![1, 3, 4]);
+        assert_eq!(next, None);
+
+        let (first, next) = NftAttributes::items_matching(1, b"strength", &filter, None, 1);
+        assert_eq!(first.len(), 1);
+        let (rest, last) = NftAttributes::items_matching(1, b"strength", &filter, next, 10);
+        assert_eq!(first.len() + rest.len(), 3);
+        assert!(!rest.contains(&first[0]));
+        assert_eq!(last, None);
+
+        let filter = AttributeFilter::Equals(b"5".to_vec());
+        assert_eq!(
+            NftAttributes::items_matching(1, b"strength", &filter, None, 10),
+            (vec![2], None)
+        );
+    });
+}
+
+#[test]
+fn items_matching_goes_on_after_the_cursor_is_burned() {
+    new_test_ext().execute_with(|| {
+        let who = 1u64;
+        for item in 1u128..=4 {
+            mint(1, item, who);
+        }
+        assert_ok!(NftAttributes::set_collection_defaults(
+            RuntimeOrigin::signed(who),
+            1,
+            attributes(&[(b"strength", b"8")])
+        ));
+        let filter = AttributeFilter::Equals(b"8".to_vec());
+
+        let (first, next) = NftAttributes::items_matching(1, b"strength", &filter, None, 2);
+        assert_eq!(first.len(), 2);
+        assert_eq!(next, first.last().copied());
+        assert_ok!(Uniques::burn(RuntimeOrigin::signed(who), 1, next.unwrap(), None));
+
+        let (mut found, last) = NftAttributes::items_matching(1, b"strength", &filter, next, 10);
+        assert_eq!(last, None);
+        found.extend(first);
+        found.sort();
+        assert_eq!(found, vec![1, 2, 3, 4]);
+    });
+}
+
+#[test]
+fn items_matching_reads_at_most_max_items_scanned() {
+    new_test_ext().execute_with(|| {
+        let who = 1u64;
+        let scanned = <Test as Config>::MaxItemsScanned::get() as u128;
+        for item in 1..=scanned + 1 {
+            mint(1, item, who);
+        }
+        // Only the last item minted matches, and it is beyond a single page of reads.
+        assert_ok!(NftAttributes::set_attributes(
+            RuntimeOrigin::signed(who),
+            1,
+            scanned + 1,
+            attributes(&[(b"strength", b"8")])
+        ));
+        let filter = AttributeFilter::Equals(b"8".to_vec());
+
+        let mut pages = 0;
+        let mut found = Vec::new();
+        let mut next = None;
+        loop {
+            let (items, cursor) = NftAttributes::items_matching(1, b"strength", &filter, next, 10);
+            found.extend(items);
+            pages += 1;
+            match cursor {
+                Some(cursor) => next = Some(cursor),
+                None => break,
+            }
+        }
+        assert_eq!(pages, 2);
+        assert_eq!(found, vec![scanned + 1]);
+    });
+}
+
+#[test]
+fn inspect_resolves_inherited_attributes() {
+    new_test_ext().execute_with(|| {
+        let who = 1u64;
+        mint(1, 1, who);
+        assert_ok!(NftAttributes::set_collection_defaults(
+            RuntimeOrigin::signed(who),
+            1,
+            attributes(&[(b"strength", b"10")])
+        ));
+
+        assert_eq!(
+            <NftAttributes as Inspect<u64>>::attribute(&1, &1, b"strength"),
+            Some(b"10".to_vec())
+        );
+        assert_eq!(
+            <NftAttributes as Inspect<u64>>::collection_attribute(&1, b"strength"),
+            Some(b"10".to_vec())
+        );
+        assert_eq!(<NftAttributes as Inspect<u64>>::owner(&1, &1), Some(who));
+    });
+}
@@ -0,0 +1,158 @@
+#![allow(non_snake_case)]
+
+use super::super::*;
+use crate::mock::{
+    attributes, mint, new_test_ext, NftAttributes, RuntimeCall, RuntimeOrigin, System, Test,
+    Uniques,
+};
+use codec::{Decode, Encode};
+use frame_support::{assert_noop, assert_ok};
+
+#[test]
+fn works() {
+    new_test_ext().execute_with(|| {
+        let collection = 1u32;
+        let item = 1u128;
+        let who = 1u64;
+        mint(collection, item, who);
+
+        let list = attributes(&[(b"strength", b"14"), (b"class", b"warrior")]);
+        assert_ok!(NftAttributes::set_attributes(
+            RuntimeOrigin::signed(who),
+            collection,
+            item,
+            list.clone()
+        ));
+        assert_eq!(Attributes::<Test>::get(collection, item), list);
+        assert_eq!(
+            NftAttributes::attribute(collection, item, b"strength"),
+            Some(b"14".to_vec())
+        );
+
+        // Verify event.
+        System::assert_last_event(
+            Event::<Test>::AttributesSet {
+                collection,
+                item,
+                who,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn replaces_previous_attributes() {
+    new_test_ext().execute_with(|| {
+        let collection = 1u32;
+        let item = 1u128;
+        let who = 1u64;
+        mint(collection, item, who);
+
+        assert_ok!(NftAttributes::set_attributes(
+            RuntimeOrigin::signed(who),
+            collection,
+            item,
+            attributes(&[(b"strength", b"14"), (b"class", b"warrior")])
+        ));
+        assert_ok!(NftAttributes::set_attributes(
+            RuntimeOrigin::signed(who),
+            collection,
+            item,
+            attributes(&[(b"strength", b"15")])
+        ));
+        assert_eq!(
+            Attributes::<Test>::get(collection, item),
+            attributes(&[(b"strength", b"15")])
+        );
+        assert_eq!(NftAttributes::attribute(collection, item, b"class"), None);
+    });
+}
+
+#[test]
+fn fails_for_non_owner() {
+    new_test_ext().execute_with(|| {
+        let collection = 1u32;
+        let item = 1u128;
+        mint(collection, item, 1);
+
+        assert_noop!(
+            NftAttributes::set_attributes(
+                RuntimeOrigin::signed(2),
+                collection,
+                item,
+                attributes(&[(b"strength", b"18")])
+            ),
+            Error::<Test>::NotOwner
+        );
+    });
+}
+
+#[test]
+fn fails_for_missing_item() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            NftAttributes::set_attributes(
+                RuntimeOrigin::signed(1),
+                1,
+                1,
+                attributes(&[(b"strength", b"14")])
+            ),
+            Error::<Test>::NotOwner
+        );
+    });
+}
+
+#[test]
+fn fails_for_burned_item() {
+    new_test_ext().execute_with(|| {
+        let collection = 1u32;
+        let item = 1u128;
+        let who = 1u64;
+        mint(collection, item, who);
+        assert_ok!(Uniques::burn(
+            RuntimeOrigin::signed(who),
+            collection,
+            item,
+            None
+        ));
+
+        assert_noop!(
+            NftAttributes::set_attributes(
+                RuntimeOrigin::signed(who),
+                collection,
+                item,
+                attributes(&[(b"strength", b"14")])
+            ),
+            Error::<Test>::NotOwner
+        );
+    });
+}
+
+#[test]
+fn rejects_lists_over_the_limits() {
+    new_test_ext().execute_with(|| {
+        // The limits are enforced by the bounded types, so oversized calls do not even decode.
+        let call = |list: Vec<(Vec<u8>, Vec<u8>)>| {
+            let mut encoded = RuntimeCall::NftAttributes(Call::set_attributes {
+                collection: 1,
+                item: 1,
+                attributes: Default::default(),
+            })
+            .encode();
+            encoded.truncate(encoded.len() - 1);
+            encoded.extend(list.encode());
+            RuntimeCall::decode(&mut &encoded[..])
+        };
+
+        let limit = <Test as Config>::AttributeLimit::get() as usize;
+        assert!(call(vec![(b"strength".to_vec(), b"14".to_vec()); limit]).is_ok());
+        assert!(call(vec![(b"strength".to_vec(), b"14".to_vec()); limit + 1]).is_err());
+
+        let key_limit = <Test as Config>::KeyLimit::get() as usize;
+        assert!(call(vec![(vec![b'k'; key_limit + 1], b"14".to_vec())]).is_err());
+
+        let value_limit = <Test as Config>::ValueLimit::get() as usize;
+        assert!(call(vec![(b"strength".to_vec(), vec![b'1'; value_limit + 1])]).is_err());
+    });
+}
@@ -0,0 +1,169 @@
+#![allow(non_snake_case)]
+
+use super::super::*;
+use crate::mock::{changes, mint, new_test_ext, NftAttributes, RuntimeOrigin, System, Test};
+use codec::Encode;
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::testing::TestSignature;
+
+fn update(nonce: u32, deadline: u64) -> SignedUpdateOf<Test> {
+    SignedUpdate {
+        collection: 1,
+        item: 1,
+        changes: changes(&[(b"experience", Some(b"300"))]),
+        nonce,
+        deadline,
+    }
+}
+
+fn sign(signer: u64, update: &SignedUpdateOf<Test>) -> TestSignature {
+    TestSignature(signer, NftAttributes::signed_update_message(update))
+}
+
+#[test]
+fn works() {
+    new_test_ext().execute_with(|| {
+        let (game_master, player) = (1u64, 2u64);
+        mint(1, 1, player);
+
+        let update = update(0, 10);
+        let signature = sign(game_master, &update);
+        assert_ok!(NftAttributes::submit_signed_update(
+            RuntimeOrigin::signed(player),
+            Box::new(update),
+            signature,
+            game_master
+        ));
+        assert_eq!(
+            NftAttributes::attribute(1, 1, b"experience"),
+            Some(b"300".to_vec())
+        );
+        assert_eq!(UpdateNonces::<Test>::get(1, 1), 1);
+        System::assert_last_event(
+            Event::<Test>::SignedUpdateApplied {
+                collection: 1,
+                item: 1,
+                signer: game_master,
+                nonce: 0,
+                who: player,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn fails_on_replay() {
+    new_test_ext().execute_with(|| {
+        let (game_master, player) = (1u64, 2u64);
+        mint(1, 1, player);
+
+        let update = update(0, 10);
+        let signature = sign(game_master, &update);
+        assert_ok!(NftAttributes::submit_signed_update(
+            RuntimeOrigin::signed(player),
+            Box::new(update.clone()),
+            signature.clone(),
+            game_master
+        ));
+        assert_noop!(
+            NftAttributes::submit_signed_update(
+                RuntimeOrigin::signed(player),
+                Box::new(update),
+                signature,
+                game_master
+            ),
+            Error::<Test>::WrongNonce
+        );
+    });
+}
+
+#[test]
+fn fails_for_wrong_signature_or_signer() {
+    new_test_ext().execute_with(|| {
+        let (game_master, player) = (1u64, 2u64);
+        mint(1, 1, player);
+
+        // Signed for another update.
+        let signature = sign(game_master, &update(1, 10));
+        assert_noop!(
+            NftAttributes::submit_signed_update(
+                RuntimeOrigin::signed(player),
+                Box::new(update(0, 10)),
+                signature,
+                game_master
+            ),
+            Error::<Test>::WrongSignature
+        );
+
+        // Signed by the player, who is not the game master.
+        let signature = sign(player, &update(0, 10));
+        assert_noop!(
+            NftAttributes::submit_signed_update(
+                RuntimeOrigin::signed(player),
+                Box::new(update(0, 10)),
+                signature,
+                player
+            ),
+            Error::<Test>::NotGameMaster
+        );
+    });
+}
+
+#[test]
+fn fails_for_signatures_of_another_domain() {
+    new_test_ext().execute_with(|| {
+        let (game_master, player) = (1u64, 2u64);
+        mint(1, 1, player);
+        let update = update(0, 10);
+
+        // The bare update, without the tag of the pallet nor the genesis hash.
+        let signature = TestSignature(game_master, update.encode());
+        assert_noop!(
+            NftAttributes::submit_signed_update(
+                RuntimeOrigin::signed(player),
+                Box::new(update.clone()),
+                signature,
+                game_master
+            ),
+            Error::<Test>::WrongSignature
+        );
+
+        // The same update signed for another chain.
+        let other_genesis = sp_core::H256::repeat_byte(7);
+        let signature = TestSignature(
+            game_master,
+            (SIGNED_UPDATE_TAG, other_genesis, &update).encode(),
+        );
+        assert_noop!(
+            NftAttributes::submit_signed_update(
+                RuntimeOrigin::signed(player),
+                Box::new(update),
+                signature,
+                game_master
+            ),
+            Error::<Test>::WrongSignature
+        );
+    });
+}
+
+#[test]
+fn fails_after_deadline() {
+    new_test_ext().execute_with(|| {
+        let (game_master, player) = (1u64, 2u64);
+        mint(1, 1, player);
+        System::set_block_number(11);
+
+        let update = update(0, 10);
+        let signature = sign(game_master, &update);
+        assert_noop!(
+            NftAttributes::submit_signed_update(
+                RuntimeOrigin::signed(player),
+                Box::new(update),
+                signature,
+                game_master
+            ),
+            Error::<Test>::UpdateExpired
+        );
+    });
+}
@@ -0,0 +1,121 @@
+#![allow(non_snake_case)]
+
+use super::super::*;
+use crate::mock::{attributes, mint, new_test_ext, NftAttributes, RuntimeOrigin, System, Test};
+use frame_support::{assert_noop, assert_ok};
+
+#[test]
+fn template_overrides_collection_defaults() {
+    new_test_ext().execute_with(|| {
+        let collection = 1u32;
+        let item = 1u128;
+        let who = 1u64;
+        mint(collection, item, who);
+
+        assert_ok!(NftAttributes::set_collection_defaults(
+            RuntimeOrigin::signed(who),
+            collection,
+            attributes(&[(b"strength", b"10"), (b"class", b"peasant")])
+        ));
+        assert_ok!(NftAttributes::set_template(
+            RuntimeOrigin::signed(who),
+            collection,
+            7,
+            attributes(&[(b"class", b"warrior")])
+        ));
+        assert_ok!(NftAttributes::assign_template(
+            RuntimeOrigin::signed(who),
+            collection,
+            item,
+            Some(7)
+        ));
+        System::assert_last_event(
+            Event::<Test>::TemplateAssigned {
+                collection,
+                item,
+                template: Some(7),
+                who,
+            }
+            .into(),
+        );
+
+        assert_eq!(
+            NftAttributes::attributes(collection, item),
+            vec![
+                (b"strength".to_vec(), b"10".to_vec()),
+                (b"class".to_vec(), b"warrior".to_vec()),
+            ]
+        );
+    });
+}
+
+#[test]
+fn stores_only_deviations() {
+    new_test_ext().execute_with(|| {
+        let collection = 1u32;
+        let item = 1u128;
+        let who = 1u64;
+        mint(collection, item, who);
+        assert_ok!(NftAttributes::set_collection_defaults(
+            RuntimeOrigin::signed(who),
+            collection,
+            attributes(&[(b"strength", b"10")])
+        ));
+
+        assert_ok!(NftAttributes::set_attributes(
+            RuntimeOrigin::signed(who),
+            collection,
+            item,
+            attributes(&[(b"strength", b"10"), (b"class", b"warrior")])
+        ));
+        assert_eq!(
+            Attributes::<Test>::get(collection, item),
+            attributes(&[(b"class", b"warrior")])
+        );
+    });
+}
+
+#[test]
+fn fails_for_non_collection_owner() {
+    new_test_ext().execute_with(|| {
+        let collection = 1u32;
+        mint(collection, 1, 1);
+
+        assert_noop!(
+            NftAttributes::set_collection_defaults(
+                RuntimeOrigin::signed(2),
+                collection,
+                attributes(&[(b"strength", b"10")])
+            ),
+            Error::<Test>::NotCollectionOwner
+        );
+        assert_noop!(
+            NftAttributes::set_template(
+                RuntimeOrigin::signed(2),
+                collection,
+                7,
+                attributes(&[(b"class", b"warrior")])
+            ),
+            Error::<Test>::NotCollectionOwner
+        );
+    });
+}
+
+#[test]
+fn fails_for_unknown_template() {
+    new_test_ext().execute_with(|| {
+        let collection = 1u32;
+        let item = 1u128;
+        let who = 1u64;
+        mint(collection, item, who);
+
+        assert_noop!(
+            NftAttributes::assign_template(RuntimeOrigin::signed(who), collection, item, Some(7)),
+            Error::<Test>::TemplateNotFound
+        );
+        assert_noop!(
+            NftAttributes::set_template(RuntimeOrigin::signed(who), collection, 7, attributes(&[])),
+            Error::<Test>::TemplateNotFound
+        );
+    });
+}
@@ -0,0 +1,106 @@
+#![allow(non_snake_case)]
+
+use super::super::*;
+use crate::mock::{
+    attributes, changes, mint, new_unchecked_test_ext, NftAttributes, RuntimeOrigin, Test,
+    COLLECTION_OWNER,
+};
+use frame_support::{assert_err, assert_ok, BoundedVec};
+
+const COLLECTION: u32 = 1;
+const ITEM: u128 = 1;
+const PLAYER: u64 = 2;
+
+/// Set the defaults of the collection and a template, and let the player set the attributes of
+/// its item, propose a change and commit to a hidden attribute.
+fn a_collection_with_attributes() {
+    mint(COLLECTION, ITEM, PLAYER);
+    assert_ok!(NftAttributes::set_collection_defaults(
+        RuntimeOrigin::signed(COLLECTION_OWNER),
+        COLLECTION,
+        attributes(&[(b"hp", b"10")])
+    ));
+    assert_ok!(NftAttributes::set_template(
+        RuntimeOrigin::signed(COLLECTION_OWNER),
+        COLLECTION,
+        0,
+        attributes(&[(b"hp", b"20")])
+    ));
+    assert_ok!(NftAttributes::set_attributes(
+        RuntimeOrigin::signed(PLAYER),
+        COLLECTION,
+        ITEM,
+        attributes(&[(b"class", b"knight")])
+    ));
+    assert_ok!(NftAttributes::propose_attributes(
+        RuntimeOrigin::signed(PLAYER),
+        COLLECTION,
+        ITEM,
+        changes(&[(b"level", Some(b"2"))])
+    ));
+    assert_ok!(NftAttributes::commit_attribute(
+        RuntimeOrigin::signed(PLAYER),
+        COLLECTION,
+        ITEM,
+        BoundedVec::try_from(b"secret".to_vec()).unwrap(),
+        Default::default(),
+        None
+    ));
+}
+
+#[test]
+fn works() {
+    new_unchecked_test_ext().execute_with(|| {
+        a_collection_with_attributes();
+        assert_ok!(NftAttributes::do_try_state());
+    });
+}
+
+#[test]
+fn detects_empty_lists() {
+    new_unchecked_test_ext().execute_with(|| {
+        a_collection_with_attributes();
+        Attributes::<Test>::insert(COLLECTION, ITEM, BoundedVec::new());
+        assert_err!(NftAttributes::do_try_state(), "an item stores an empty list of attributes");
+    });
+    new_unchecked_test_ext().execute_with(|| {
+        a_collection_with_attributes();
+        CollectionDefaults::<Test>::insert(COLLECTION, BoundedVec::new());
+        assert_err!(NftAttributes::do_try_state(), "a collection stores empty defaults");
+    });
+    new_unchecked_test_ext().execute_with(|| {
+        a_collection_with_attributes();
+        Templates::<Test>::insert(COLLECTION, 0, BoundedVec::new());
+        assert_err!(NftAttributes::do_try_state(), "a collection stores an empty template");
+    });
+}
+
+#[test]
+fn detects_a_proposal_that_outlives_its_lifetime() {
+    new_unchecked_test_ext().execute_with(|| {
+        a_collection_with_attributes();
+        Proposals::<Test>::mutate(COLLECTION, ITEM, |proposal| {
+            if let Some(proposal) = proposal {
+                proposal.expiry += 1;
+            }
+        });
+        assert_err!(NftAttributes::do_try_state(), "a proposal outlives ProposalLifetime");
+    });
+}
+
+#[test]
+fn detects_a_commitment_without_its_deposit() {
+    new_unchecked_test_ext().execute_with(|| {
+        a_collection_with_attributes();
+        let key = BoundedVec::try_from(b"secret".to_vec()).unwrap();
+        Commitments::<Test>::mutate((COLLECTION, ITEM, key), |commitment| {
+            if let Some(commitment) = commitment {
+                commitment.deposit += 1;
+            }
+        });
+        assert_err!(
+            NftAttributes::do_try_state(),
+            "an account does not hold the deposits of its commitments"
+        );
+    });
+}
@@ -0,0 +1,116 @@
+use crate::{AttributeKey, AttributeValue, Config, Error, Event, Pallet};
+use alloc::vec;
+use frame_support::{ensure, traits::tokens::nonfungibles_v2::Inspect, BoundedVec};
+use sp_runtime::{DispatchResult, TokenError};
+
+/// Write access to the attributes for other pallets, such as the dice that record their rolls on
+/// the sheet of a character. Only the game master of the collection may write through it.
+pub trait MutateAttributes<AccountId, CollectionId, ItemId> {
+    /// Check that `who` may set the attributes of the item `(collection, item)`.
+    fn ensure_can_set(who: &AccountId, collection: &CollectionId, item: &ItemId)
+        -> DispatchResult;
+
+    /// Set the attribute `key` of the item `(collection, item)` to `value` on behalf of `who`.
+    fn set_attribute(
+        who: &AccountId,
+        collection: &CollectionId,
+        item: &ItemId,
+        key: &[u8],
+        value: &[u8],
+    ) -> DispatchResult;
+
+    /// Create an item whose attributes `who` may set, for the benchmarks of the pallets that
+    /// write through this trait, if the implementation has any.
+    #[cfg(feature = "runtime-benchmarks")]
+    fn benchmark_item(who: &AccountId) -> Option<(CollectionId, ItemId)>;
+}
+
+impl<T: Config> MutateAttributes<T::AccountId, T::CollectionId, T::ItemId> for Pallet<T> {
+    fn ensure_can_set(
+        who: &T::AccountId,
+        collection: &T::CollectionId,
+        item: &T::ItemId,
+    ) -> DispatchResult {
+        Pallet::<T>::ensure_game_master(who, collection)?;
+        ensure!(
+            T::Nfts::owner(collection, item).is_some(),
+            Error::<T>::TokenNotFound
+        );
+        Pallet::<T>::ensure_not_frozen(collection, item)
+    }
+
+    fn set_attribute(
+        who: &T::AccountId,
+        collection: &T::CollectionId,
+        item: &T::ItemId,
+        key: &[u8],
+        value: &[u8],
+    ) -> DispatchResult {
+        Self::ensure_can_set(who, collection, item)?;
+        let key = AttributeKey::<T>::try_from(key.to_vec()).map_err(|_| Error::<T>::KeyTooLong)?;
+        let value =
+            AttributeValue::<T>::try_from(value.to_vec()).map_err(|_| Error::<T>::ValueTooLong)?;
+
+        let changes = BoundedVec::truncate_from(vec![(key, Some(value))]);
+        Pallet::<T>::apply_changes(collection.clone(), *item, changes)?;
+        Pallet::<T>::deposit_event(Event::AttributesSet {
+            collection: collection.clone(),
+            item: *item,
+            who: who.clone(),
+        });
+        Ok(())
+    }
+
+    /// The item inherits full collection defaults and a full template, and holds one attribute
+    /// short of the limit, the worst case for setting another one.
+    #[cfg(feature = "runtime-benchmarks")]
+    fn benchmark_item(who: &T::AccountId) -> Option<(T::CollectionId, T::ItemId)> {
+        use crate::{AttributeList, Attributes, CollectionDefaults, ItemTemplates, Templates};
+        use frame_support::traits::Get;
+        use pallet_nft_hierarchy::BenchmarkHelper as _;
+
+        let (collection, item) = (T::CollectionId::from(0), T::ItemId::from(0));
+        T::NftsHelper::mint(&collection, &item, who);
+        let list = |count: u32, fill: u8| -> AttributeList<T> {
+            let attribute = |index: u32| {
+                let mut key = index.to_le_bytes().to_vec();
+                key.resize(T::KeyLimit::get() as usize, b'k');
+                let value = vec![fill; T::ValueLimit::get() as usize];
+                (BoundedVec::truncate_from(key), BoundedVec::truncate_from(value))
+            };
+            BoundedVec::truncate_from((0..count).map(attribute).collect())
+        };
+        let limit = T::AttributeLimit::get();
+        CollectionDefaults::<T>::insert(&collection, list(limit, b'd'));
+        Templates::<T>::insert(&collection, 0, list(limit, b't'));
+        ItemTemplates::<T>::insert(&collection, item, 0);
+        Attributes::<T>::insert(&collection, item, list(limit.saturating_sub(1), b'a'));
+        Some((collection, item))
+    }
+}
+
+/// No attributes at all, for runtimes and mocks that do not use them.
+impl<AccountId, CollectionId, ItemId> MutateAttributes<AccountId, CollectionId, ItemId> for () {
+    fn ensure_can_set(
+        _who: &AccountId,
+        _collection: &CollectionId,
+        _item: &ItemId,
+    ) -> DispatchResult {
+        Err(TokenError::Unsupported.into())
+    }
+
+    fn set_attribute(
+        _who: &AccountId,
+        _collection: &CollectionId,
+        _item: &ItemId,
+        _key: &[u8],
+        _value: &[u8],
+    ) -> DispatchResult {
+        Err(TokenError::Unsupported.into())
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn benchmark_item(_who: &AccountId) -> Option<(CollectionId, ItemId)> {
+        None
+    }
+}
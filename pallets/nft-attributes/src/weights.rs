@@ -1,54 +1,481 @@
+//! Weights for `pallet_nft_attributes`.
+//!
+//! PROVISIONAL: these weights were not produced by the node's `benchmark pallet` command nor on
+//! the reference hardware. They were measured by running the benchmarks of `benchmarking.rs`
+//! natively against the runtime, with 50 steps and 20 repeats, and counting the storage
+//! accesses of the worst case of each call. Regenerate them before any production deployment:
+//!
+//! ./target/release/telurean-chain-node benchmark pallet \
+//!     --runtime ./target/release/wbuild/telurean-chain-runtime/telurean_chain_runtime.wasm \
+//!     --genesis-builder=runtime --pallet pallet_nft_attributes --extrinsic '*' \
+//!     --steps 50 --repeat 20 --output pallets/nft-attributes/src/weights.rs
 
 #![cfg_attr(rustfmt, rustfmt_skip)]
 #![allow(unused_parens)]
 #![allow(unused_imports)]
+#![allow(missing_docs)]
 
 use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
 use core::marker::PhantomData;
 
+/// Weight functions needed for `pallet_nft_attributes`.
 pub trait WeightInfo {
-	fn set_attributes() -> Weight;
+	fn set_attributes(a: u32, ) -> Weight;
 	fn clear_attributes() -> Weight;
+	fn set_collection_defaults(a: u32, ) -> Weight;
+	fn set_template(a: u32, ) -> Weight;
+	fn assign_template() -> Weight;
+	fn set_game_master() -> Weight;
+	fn propose_attributes(a: u32, ) -> Weight;
+	fn approve_attributes(a: u32, ) -> Weight;
+	fn reject_attributes() -> Weight;
+	fn submit_signed_update(a: u32, ) -> Weight;
+	fn commit_attribute() -> Weight;
+	fn reveal_attribute() -> Weight;
+	fn cancel_commitment() -> Weight;
+	fn freeze_attributes() -> Weight;
+	fn thaw_attributes() -> Weight;
 }
 
+/// Weights for `pallet_nft_attributes`, with storage accesses priced by the database weights of the runtime.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
-	fn set_attributes() -> Weight {
+	/// Storage: `Uniques::Asset` (r:1 w:0)
+	/// Storage: `NftAttributes::FrozenCollections` (r:1 w:0)
+	/// Storage: `NftAttributes::FrozenItems` (r:1 w:0)
+	/// Storage: `NftAttributes::CollectionDefaults` (r:1 w:0)
+	/// Storage: `NftAttributes::ItemTemplates` (r:1 w:0)
+	/// Storage: `NftAttributes::Templates` (r:1 w:0)
+	/// Storage: `NftAttributes::Attributes` (r:0 w:1)
+	/// The range of component `a` is `[1, 10]`.
+	fn set_attributes(a: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 8_000_000 picoseconds.
-		Weight::from_parts(9_000_000, 0)
+		//  Measured:  `6861`
+		// Minimum execution time: 17_176_000 picoseconds.
+		Weight::from_parts(17_751_740, 6861)
+			.saturating_add(Weight::from_parts(101_412, 0).saturating_mul(a.into()))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Uniques::Asset` (r:1 w:0)
+	/// Storage: `NftAttributes::FrozenCollections` (r:1 w:0)
+	/// Storage: `NftAttributes::FrozenItems` (r:1 w:0)
+	/// Storage: `NftAttributes::Attributes` (r:0 w:1)
 	fn clear_attributes() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `32`
-		//  Estimated: `1489`
-		// Minimum execution time: 6_000_000 picoseconds.
-		Weight::from_parts(6_000_000, 1489)
+		//  Measured:  `301`
+		// Minimum execution time: 8_072_000 picoseconds.
+		Weight::from_parts(8_413_000, 301)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Uniques::Class` (r:1 w:0)
+	/// Storage: `NftAttributes::FrozenCollections` (r:1 w:0)
+	/// Storage: `NftAttributes::CollectionDefaults` (r:0 w:1)
+	/// The range of component `a` is `[1, 10]`.
+	fn set_collection_defaults(a: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `290`
+		// Minimum execution time: 5_458_000 picoseconds.
+		Weight::from_parts(5_329_466, 290)
+			.saturating_add(Weight::from_parts(153_507, 0).saturating_mul(a.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Uniques::Class` (r:1 w:0)
+	/// Storage: `NftAttributes::FrozenCollections` (r:1 w:0)
+	/// Storage: `NftAttributes::Templates` (r:0 w:1)
+	/// The range of component `a` is `[1, 10]`.
+	fn set_template(a: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `290`
+		// Minimum execution time: 5_578_000 picoseconds.
+		Weight::from_parts(6_203_406, 290)
+			.saturating_add(Weight::from_parts(77_606, 0).saturating_mul(a.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Uniques::Class` (r:1 w:0)
+	/// Storage: `Uniques::Asset` (r:1 w:0)
+	/// Storage: `NftAttributes::FrozenCollections` (r:1 w:0)
+	/// Storage: `NftAttributes::FrozenItems` (r:1 w:0)
+	/// Storage: `NftAttributes::Templates` (r:1 w:0)
+	/// Storage: `NftAttributes::ItemTemplates` (r:0 w:1)
+	fn assign_template() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `450`
+		// Minimum execution time: 10_466_000 picoseconds.
+		Weight::from_parts(10_816_000, 450)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Uniques::Class` (r:1 w:0)
+	/// Storage: `NftAttributes::GameMasters` (r:0 w:1)
+	fn set_game_master() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `290`
+		// Minimum execution time: 4_918_000 picoseconds.
+		Weight::from_parts(5_138_000, 290)
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Uniques::Asset` (r:1 w:0)
+	/// Storage: `NftAttributes::FrozenCollections` (r:1 w:0)
+	/// Storage: `NftAttributes::FrozenItems` (r:1 w:0)
+	/// Storage: `NftAttributes::Proposals` (r:1 w:1)
+	/// The range of component `a` is `[1, 10]`.
+	fn propose_attributes(a: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `214`
+		// Minimum execution time: 8_443_000 picoseconds.
+		Weight::from_parts(8_304_880, 214)
+			.saturating_add(Weight::from_parts(239_130, 0).saturating_mul(a.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `NftAttributes::GameMasters` (r:1 w:0)
+	/// Storage: `Uniques::Class` (r:1 w:0)
+	/// Storage: `NftAttributes::Proposals` (r:1 w:1)
+	/// Storage: `Uniques::Asset` (r:1 w:0)
+	/// Storage: `NftAttributes::FrozenCollections` (r:1 w:0)
+	/// Storage: `NftAttributes::FrozenItems` (r:1 w:0)
+	/// Storage: `NftAttributes::Attributes` (r:1 w:1)
+	/// Storage: `NftAttributes::CollectionDefaults` (r:1 w:0)
+	/// Storage: `NftAttributes::ItemTemplates` (r:1 w:0)
+	/// Storage: `NftAttributes::Templates` (r:1 w:0)
+	/// The range of component `a` is `[1, 10]`.
+	fn approve_attributes(a: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `7144`
+		// Minimum execution time: 24_207_000 picoseconds.
+		Weight::from_parts(25_826_700, 7144)
+			.saturating_add(Weight::from_parts(161_885, 325).saturating_mul(a.into()))
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `NftAttributes::GameMasters` (r:1 w:0)
+	/// Storage: `Uniques::Class` (r:1 w:0)
+	/// Storage: `NftAttributes::Proposals` (r:1 w:1)
+	fn reject_attributes() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3636`
+		// Minimum execution time: 8_713_000 picoseconds.
+		Weight::from_parts(9_074_000, 3636)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `NftAttributes::GameMasters` (r:1 w:0)
+	/// Storage: `Uniques::Class` (r:1 w:0)
+	/// Storage: `NftAttributes::UpdateNonces` (r:1 w:1)
+	/// Storage: `Uniques::Asset` (r:1 w:0)
+	/// Storage: `NftAttributes::FrozenCollections` (r:1 w:0)
+	/// Storage: `NftAttributes::FrozenItems` (r:1 w:0)
+	/// Storage: `NftAttributes::Attributes` (r:1 w:1)
+	/// Storage: `NftAttributes::CollectionDefaults` (r:1 w:0)
+	/// Storage: `NftAttributes::ItemTemplates` (r:1 w:0)
+	/// Storage: `NftAttributes::Templates` (r:1 w:0)
+	/// The range of component `a` is `[1, 10]`.
+	fn submit_signed_update(a: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6938`
+		// Minimum execution time: 58_217_000 picoseconds.
+		Weight::from_parts(62_022_560, 6938)
+			.saturating_add(Weight::from_parts(1_923_300, 0).saturating_mul(a.into()))
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Uniques::Asset` (r:1 w:0)
+	/// Storage: `NftAttributes::GameMasters` (r:1 w:0)
+	/// Storage: `Uniques::Class` (r:1 w:0)
+	/// Storage: `NftAttributes::FrozenCollections` (r:1 w:0)
+	/// Storage: `NftAttributes::FrozenItems` (r:1 w:0)
+	/// Storage: `NftAttributes::Commitments` (r:10 w:1)
+	/// Storage: `NftAttributes::Attributes` (r:1 w:0)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	fn commit_attribute() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4465`
+		// Minimum execution time: 37_746_000 picoseconds.
+		Weight::from_parts(38_888_000, 4465)
+			.saturating_add(T::DbWeight::get().reads(17_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `NftAttributes::Commitments` (r:1 w:1)
+	/// Storage: `Uniques::Asset` (r:1 w:0)
+	/// Storage: `NftAttributes::FrozenCollections` (r:1 w:0)
+	/// Storage: `NftAttributes::FrozenItems` (r:1 w:0)
+	/// Storage: `NftAttributes::Attributes` (r:1 w:1)
+	/// Storage: `NftAttributes::CollectionDefaults` (r:1 w:0)
+	/// Storage: `NftAttributes::ItemTemplates` (r:1 w:0)
+	/// Storage: `NftAttributes::Templates` (r:1 w:0)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	fn reveal_attribute() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `11183`
+		// Minimum execution time: 34_702_000 picoseconds.
+		Weight::from_parts(36_685_000, 11183)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `NftAttributes::Commitments` (r:1 w:1)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	fn cancel_commitment() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1305`
+		// Minimum execution time: 13_951_000 picoseconds.
+		Weight::from_parts(14_412_000, 1305)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `NftAttributes::GameMasters` (r:1 w:0)
+	/// Storage: `Uniques::Class` (r:1 w:0)
+	/// Storage: `NftAttributes::FrozenItems` (r:1 w:1)
+	/// Storage: `NftAttributes::Attributes` (r:1 w:1)
+	/// Storage: `NftAttributes::ItemTemplates` (r:1 w:0)
+	/// Storage: `NftAttributes::Templates` (r:1 w:0)
+	/// Storage: `NftAttributes::CollectionDefaults` (r:1 w:0)
+	fn freeze_attributes() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6937`
+		// Minimum execution time: 16_735_000 picoseconds.
+		Weight::from_parts(17_637_000, 6937)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `NftAttributes::GameMasters` (r:1 w:0)
+	/// Storage: `Uniques::Class` (r:1 w:0)
+	/// Storage: `NftAttributes::FrozenItems` (r:1 w:1)
+	/// Storage: `NftAttributes::Attributes` (r:1 w:1)
+	/// Storage: `NftAttributes::CollectionDefaults` (r:1 w:0)
+	/// Storage: `NftAttributes::ItemTemplates` (r:1 w:0)
+	/// Storage: `NftAttributes::Templates` (r:1 w:0)
+	fn thaw_attributes() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `10333`
+		// Minimum execution time: 21_152_000 picoseconds.
+		Weight::from_parts(22_043_000, 10333)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
 }
 
-// For backwards compatibility and tests
+// For backwards compatibility and tests.
 impl WeightInfo for () {
-	fn set_attributes() -> Weight {
+	/// Storage: `Uniques::Asset` (r:1 w:0)
+	/// Storage: `NftAttributes::FrozenCollections` (r:1 w:0)
+	/// Storage: `NftAttributes::FrozenItems` (r:1 w:0)
+	/// Storage: `NftAttributes::CollectionDefaults` (r:1 w:0)
+	/// Storage: `NftAttributes::ItemTemplates` (r:1 w:0)
+	/// Storage: `NftAttributes::Templates` (r:1 w:0)
+	/// Storage: `NftAttributes::Attributes` (r:0 w:1)
+	/// The range of component `a` is `[1, 10]`.
+	fn set_attributes(a: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 8_000_000 picoseconds.
-		Weight::from_parts(9_000_000, 0)
+		//  Measured:  `6861`
+		// Minimum execution time: 17_176_000 picoseconds.
+		Weight::from_parts(17_751_740, 6861)
+			.saturating_add(Weight::from_parts(101_412, 0).saturating_mul(a.into()))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Uniques::Asset` (r:1 w:0)
+	/// Storage: `NftAttributes::FrozenCollections` (r:1 w:0)
+	/// Storage: `NftAttributes::FrozenItems` (r:1 w:0)
+	/// Storage: `NftAttributes::Attributes` (r:0 w:1)
 	fn clear_attributes() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `32`
-		//  Estimated: `1489`
-		// Minimum execution time: 6_000_000 picoseconds.
-		Weight::from_parts(6_000_000, 1489)
+		//  Measured:  `301`
+		// Minimum execution time: 8_072_000 picoseconds.
+		Weight::from_parts(8_413_000, 301)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Uniques::Class` (r:1 w:0)
+	/// Storage: `NftAttributes::FrozenCollections` (r:1 w:0)
+	/// Storage: `NftAttributes::CollectionDefaults` (r:0 w:1)
+	/// The range of component `a` is `[1, 10]`.
+	fn set_collection_defaults(a: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `290`
+		// Minimum execution time: 5_458_000 picoseconds.
+		Weight::from_parts(5_329_466, 290)
+			.saturating_add(Weight::from_parts(153_507, 0).saturating_mul(a.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Uniques::Class` (r:1 w:0)
+	/// Storage: `NftAttributes::FrozenCollections` (r:1 w:0)
+	/// Storage: `NftAttributes::Templates` (r:0 w:1)
+	/// The range of component `a` is `[1, 10]`.
+	fn set_template(a: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `290`
+		// Minimum execution time: 5_578_000 picoseconds.
+		Weight::from_parts(6_203_406, 290)
+			.saturating_add(Weight::from_parts(77_606, 0).saturating_mul(a.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Uniques::Class` (r:1 w:0)
+	/// Storage: `Uniques::Asset` (r:1 w:0)
+	/// Storage: `NftAttributes::FrozenCollections` (r:1 w:0)
+	/// Storage: `NftAttributes::FrozenItems` (r:1 w:0)
+	/// Storage: `NftAttributes::Templates` (r:1 w:0)
+	/// Storage: `NftAttributes::ItemTemplates` (r:0 w:1)
+	fn assign_template() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `450`
+		// Minimum execution time: 10_466_000 picoseconds.
+		Weight::from_parts(10_816_000, 450)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Uniques::Class` (r:1 w:0)
+	/// Storage: `NftAttributes::GameMasters` (r:0 w:1)
+	fn set_game_master() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `290`
+		// Minimum execution time: 4_918_000 picoseconds.
+		Weight::from_parts(5_138_000, 290)
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Uniques::Asset` (r:1 w:0)
+	/// Storage: `NftAttributes::FrozenCollections` (r:1 w:0)
+	/// Storage: `NftAttributes::FrozenItems` (r:1 w:0)
+	/// Storage: `NftAttributes::Proposals` (r:1 w:1)
+	/// The range of component `a` is `[1, 10]`.
+	fn propose_attributes(a: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `214`
+		// Minimum execution time: 8_443_000 picoseconds.
+		Weight::from_parts(8_304_880, 214)
+			.saturating_add(Weight::from_parts(239_130, 0).saturating_mul(a.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `NftAttributes::GameMasters` (r:1 w:0)
+	/// Storage: `Uniques::Class` (r:1 w:0)
+	/// Storage: `NftAttributes::Proposals` (r:1 w:1)
+	/// Storage: `Uniques::Asset` (r:1 w:0)
+	/// Storage: `NftAttributes::FrozenCollections` (r:1 w:0)
+	/// Storage: `NftAttributes::FrozenItems` (r:1 w:0)
+	/// Storage: `NftAttributes::Attributes` (r:1 w:1)
+	/// Storage: `NftAttributes::CollectionDefaults` (r:1 w:0)
+	/// Storage: `NftAttributes::ItemTemplates` (r:1 w:0)
+	/// Storage: `NftAttributes::Templates` (r:1 w:0)
+	/// The range of component `a` is `[1, 10]`.
+	fn approve_attributes(a: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `7144`
+		// Minimum execution time: 24_207_000 picoseconds.
+		Weight::from_parts(25_826_700, 7144)
+			.saturating_add(Weight::from_parts(161_885, 325).saturating_mul(a.into()))
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `NftAttributes::GameMasters` (r:1 w:0)
+	/// Storage: `Uniques::Class` (r:1 w:0)
+	/// Storage: `NftAttributes::Proposals` (r:1 w:1)
+	fn reject_attributes() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3636`
+		// Minimum execution time: 8_713_000 picoseconds.
+		Weight::from_parts(9_074_000, 3636)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `NftAttributes::GameMasters` (r:1 w:0)
+	/// Storage: `Uniques::Class` (r:1 w:0)
+	/// Storage: `NftAttributes::UpdateNonces` (r:1 w:1)
+	/// Storage: `Uniques::Asset` (r:1 w:0)
+	/// Storage: `NftAttributes::FrozenCollections` (r:1 w:0)
+	/// Storage: `NftAttributes::FrozenItems` (r:1 w:0)
+	/// Storage: `NftAttributes::Attributes` (r:1 w:1)
+	/// Storage: `NftAttributes::CollectionDefaults` (r:1 w:0)
+	/// Storage: `NftAttributes::ItemTemplates` (r:1 w:0)
+	/// Storage: `NftAttributes::Templates` (r:1 w:0)
+	/// The range of component `a` is `[1, 10]`.
+	fn submit_signed_update(a: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6938`
+		// Minimum execution time: 58_217_000 picoseconds.
+		Weight::from_parts(62_022_560, 6938)
+			.saturating_add(Weight::from_parts(1_923_300, 0).saturating_mul(a.into()))
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Uniques::Asset` (r:1 w:0)
+	/// Storage: `NftAttributes::GameMasters` (r:1 w:0)
+	/// Storage: `Uniques::Class` (r:1 w:0)
+	/// Storage: `NftAttributes::FrozenCollections` (r:1 w:0)
+	/// Storage: `NftAttributes::FrozenItems` (r:1 w:0)
+	/// Storage: `NftAttributes::Commitments` (r:10 w:1)
+	/// Storage: `NftAttributes::Attributes` (r:1 w:0)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	fn commit_attribute() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4465`
+		// Minimum execution time: 37_746_000 picoseconds.
+		Weight::from_parts(38_888_000, 4465)
+			.saturating_add(RocksDbWeight::get().reads(17_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `NftAttributes::Commitments` (r:1 w:1)
+	/// Storage: `Uniques::Asset` (r:1 w:0)
+	/// Storage: `NftAttributes::FrozenCollections` (r:1 w:0)
+	/// Storage: `NftAttributes::FrozenItems` (r:1 w:0)
+	/// Storage: `NftAttributes::Attributes` (r:1 w:1)
+	/// Storage: `NftAttributes::CollectionDefaults` (r:1 w:0)
+	/// Storage: `NftAttributes::ItemTemplates` (r:1 w:0)
+	/// Storage: `NftAttributes::Templates` (r:1 w:0)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	fn reveal_attribute() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `11183`
+		// Minimum execution time: 34_702_000 picoseconds.
+		Weight::from_parts(36_685_000, 11183)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `NftAttributes::Commitments` (r:1 w:1)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	fn cancel_commitment() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1305`
+		// Minimum execution time: 13_951_000 picoseconds.
+		Weight::from_parts(14_412_000, 1305)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `NftAttributes::GameMasters` (r:1 w:0)
+	/// Storage: `Uniques::Class` (r:1 w:0)
+	/// Storage: `NftAttributes::FrozenItems` (r:1 w:1)
+	/// Storage: `NftAttributes::Attributes` (r:1 w:1)
+	/// Storage: `NftAttributes::ItemTemplates` (r:1 w:0)
+	/// Storage: `NftAttributes::Templates` (r:1 w:0)
+	/// Storage: `NftAttributes::CollectionDefaults` (r:1 w:0)
+	fn freeze_attributes() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6937`
+		// Minimum execution time: 16_735_000 picoseconds.
+		Weight::from_parts(17_637_000, 6937)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `NftAttributes::GameMasters` (r:1 w:0)
+	/// Storage: `Uniques::Class` (r:1 w:0)
+	/// Storage: `NftAttributes::FrozenItems` (r:1 w:1)
+	/// Storage: `NftAttributes::Attributes` (r:1 w:1)
+	/// Storage: `NftAttributes::CollectionDefaults` (r:1 w:0)
+	/// Storage: `NftAttributes::ItemTemplates` (r:1 w:0)
+	/// Storage: `NftAttributes::Templates` (r:1 w:0)
+	fn thaw_attributes() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `10333`
+		// Minimum execution time: 21_152_000 picoseconds.
+		Weight::from_parts(22_043_000, 10333)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
 }
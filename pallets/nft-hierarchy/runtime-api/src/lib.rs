@@ -0,0 +1,31 @@
+//! Runtime API definition for the NFT hierarchy pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use codec::Codec;
+
+pub use pallet_nft_hierarchy::{CustodyChange, ProvenanceEntry};
+
+sp_api::decl_runtime_apis! {
+    /// Read access to the relationships of NFTs, so that clients do not build storage keys by
+    /// hand.
+    pub trait NftHierarchyApi<CollectionId, ItemId, BlockNumber>
+    where
+        CollectionId: Codec,
+        ItemId: Codec,
+        BlockNumber: Codec,
+    {
+        /// The provenance of an asset, one page at a time: up to `limit` entries with their
+        /// indexes, from the oldest one retained at or after the index `start`. The next page
+        /// starts after the index of the last entry of the previous one.
+        fn provenance(
+            collection: CollectionId,
+            item: ItemId,
+            start: u32,
+            limit: u32,
+        ) -> Vec<(u32, ProvenanceEntry<CollectionId, ItemId, BlockNumber>)>;
+    }
+}
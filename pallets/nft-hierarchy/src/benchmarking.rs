@@ -1,35 +1,585 @@
-//! Benchmarking setup for pallet-template
+//! Benchmarking setup for pallet-nft-hierarchy
 
 use super::*;
 
 #[allow(unused)]
-use crate::Pallet as Template;
+use crate::Pallet as NftHierarchy;
+use common::{
+    Approval, CustodyChange, Loan, NftInfo, OperatorScope, ProvenanceEntry, RelationshipProposal,
+};
 use frame_benchmarking::v2::*;
-use frame_system::RawOrigin;
+use frame_support::{
+    traits::{EnsureOrigin, Get},
+    BoundedVec,
+};
+use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
+use sp_std::{boxed::Box, vec, vec::Vec};
+
+fn tags<T: Config>(count: u32) -> BoundedVec<BoundedVec<u8, T::StringLimit>, T::TypeLimit> {
+    let tag = BoundedVec::truncate_from(vec![b'x'; T::StringLimit::get() as usize]);
+    BoundedVec::truncate_from(vec![tag; count as usize])
+}
+
+/// The collection of the assets, other than the collection of their owners.
+fn armory<T: Config>() -> T::CollectionId {
+    1u32.into()
+}
+
+/// Fill the provenance of `asset`, so that recording a change also drops its oldest entry.
+fn full_provenance<T: Config>(asset: (T::CollectionId, T::ItemId)) {
+    ProvenanceBounds::<T>::insert(asset, (0, T::MaxProvenance::get()));
+}
+
+/// Register the NFT `owner` as an owner of `assets` registered assets of the armory, with IDs
+/// following its own and full provenances.
+fn owner_with_assets<T: Config>(owner: u128, assets: u32) -> (T::CollectionId, T::ItemId) {
+    let collection: T::CollectionId = 0u32.into();
+    let owner_id: T::ItemId = owner.into();
+    registered::<T>(collection, owner_id);
+    for index in 0..assets as u128 {
+        let asset: T::ItemId = (owner + 1 + index).into();
+        OwnerAssets::<T>::insert((collection, owner_id, index), Some((armory::<T>(), asset)));
+        registered::<T>(armory::<T>(), asset);
+        OwnerCount::<T>::insert((armory::<T>(), asset), 1);
+        full_provenance::<T>((armory::<T>(), asset));
+    }
+    AssetCount::<T>::insert((collection, owner_id), assets as u128);
+    (collection, owner_id)
+}
+
+/// Mint the NFT `item` of `collection` for a holder who approves the caller as an operator with
+/// every scope, so that the calls made by the caller check the approval as well as the holder.
+fn operator_of<T: Config>(collection: T::CollectionId, item: T::ItemId) -> T::AccountId {
+    let holder: T::AccountId = account("holder", 0, 0);
+    let caller: T::AccountId = whitelisted_caller();
+    T::BenchmarkHelper::mint(&collection, &item, &holder);
+    Operators::<T>::insert(
+        (collection, item, caller.clone()),
+        Approval {
+            approver: holder,
+            scope: OperatorScope { ownership: true, containers: true, tags: true },
+            expiry: None,
+        },
+    );
+    caller
+}
+
+/// The collection of the agents, other than the collection of their principals.
+fn familiars<T: Config>() -> T::CollectionId {
+    2u32.into()
+}
+
+/// Mint the NFT `item` of `collection` for a holder who appoints as many agents as allowed, with
+/// IDs following `seed` times that number. The caller holds the last agent checked, so that the
+/// calls made by the caller go through every other check before being authorized.
+fn agent_of<T: Config>(collection: T::CollectionId, item: T::ItemId, seed: u32) -> T::AccountId {
+    let holder: T::AccountId = account("holder", 0, 0);
+    let caller: T::AccountId = whitelisted_caller();
+    T::BenchmarkHelper::mint(&collection, &item, &holder);
+    appoint_agents::<T>(collection, item, seed, &caller);
+    caller
+}
+
+/// Appoint as many agents of the NFT `item` of `collection` as allowed, with IDs following `seed`
+/// times that number. `last_holder` holds the last agent checked, and a holder the others.
+fn appoint_agents<T: Config>(
+    collection: T::CollectionId,
+    item: T::ItemId,
+    seed: u32,
+    last_holder: &T::AccountId,
+) {
+    let holder: T::AccountId = account("holder", 0, 0);
+    let max = T::MaxAgents::get();
+    for index in seed * max..(seed + 1) * max {
+        let agent: T::ItemId = (index as u128).into();
+        Agents::<T>::insert(
+            (collection, item, familiars::<T>(), agent),
+            OperatorScope { ownership: true, containers: true, tags: true },
+        );
+    }
+    AgentCount::<T>::insert((collection, item), max);
+    let agents = Agents::<T>::iter_key_prefix((collection, item)).collect::<Vec<_>>();
+    for (position, (agent_collection, agent)) in agents.iter().enumerate() {
+        let agent_holder = if position + 1 == agents.len() { last_holder } else { &holder };
+        T::BenchmarkHelper::mint(agent_collection, agent, agent_holder);
+    }
+}
+
+/// Propose that `owner` owns `asset` until the block `expiry`, from the side of the owner or not.
+fn proposed<T: Config>(owner: Nft<T>, asset: Nft<T>, by_owner: bool) -> BlockNumberFor<T> {
+    let expiry = frame_system::Pallet::<T>::block_number() + 10u32.into();
+    let proposer: T::AccountId = account("proposer", 0, 0);
+    Proposals::<T>::insert(
+        (owner.0, owner.1, asset.0, asset.1),
+        RelationshipProposal { proposer, by_owner, expiry },
+    );
+    expiry
+}
+
+/// The ID of the NFT that borrows assets, far from those of the lender and its assets.
+const BORROWER: u128 = 1_000_000;
+
+/// Fill the loans expiring at the block `until` with `count` assets that no owner has.
+fn expiring_loans<T: Config>(until: BlockNumberFor<T>, count: u32) {
+    let assets = (0..count as u128)
+        .map(|index| (armory::<T>(), T::ItemId::from(u128::MAX - index)))
+        .collect::<Vec<_>>();
+    LoanExpiries::<T>::insert(until, BoundedVec::truncate_from(assets));
+}
+
+/// Register `asset` in the collection `collection` with the tag of an owner.
+fn registered<T: Config>(collection: T::CollectionId, asset: T::ItemId) {
+    NftInfos::<T>::insert(
+        collection,
+        asset,
+        NftInfo::<T> {
+            collec_id: Some(collection),
+            owner_id: None,
+            tags: BoundedVec::truncate_from(vec![BoundedVec::truncate_from(b"owner".to_vec())]),
+        },
+    );
+}
 
 #[benchmarks]
 mod benchmarks {
     use super::*;
 
     #[benchmark]
-    fn do_something() {
-        let value = 100u32;
+    fn register_nft(t: Linear<0, { T::TypeLimit::get() }>) {
+        // Changing the tags of a registered asset as an operator is the most expensive case.
+        let asset: T::ItemId = 1u128.into();
+        registered::<T>(0u32.into(), asset);
+        let caller = agent_of::<T>(0u32.into(), asset, 0);
+
+        #[extrinsic_call]
+        register_asset(RawOrigin::Signed(caller), 0u32.into(), asset, tags::<T>(t));
+
+        assert_eq!(NftInfos::<T>::get(T::CollectionId::from(0u32), asset).tags.len(), t as usize);
+    }
+
+    #[benchmark]
+    fn create_ownership(n: Linear<0, { T::MaxInventorySize::get() - 1 }>) {
+        let (collection, owner) = owner_with_assets::<T>(0, n);
+        let caller = agent_of::<T>(collection, owner, 0);
+        let asset: T::ItemId = u128::MAX.into();
+        registered::<T>(armory::<T>(), asset);
+        full_provenance::<T>((armory::<T>(), asset));
+
+        #[extrinsic_call]
+        set_ownership(RawOrigin::Signed(caller), collection, owner, armory::<T>(), asset, n);
+
+        assert_eq!(AssetCount::<T>::get((collection, owner)), n as u128 + 1);
+    }
+
+    #[benchmark]
+    fn remove_ownership(n: Linear<1, { T::MaxInventorySize::get() }>) {
+        let (collection, owner) = owner_with_assets::<T>(0, n);
+        let caller = agent_of::<T>(collection, owner, 0);
+        // The last asset is the one found after the longest search.
+        let asset: T::ItemId = (n as u128).into();
+
+        #[extrinsic_call]
+        unset_ownership(RawOrigin::Signed(caller), collection, owner, armory::<T>(), asset, n);
+
+        assert_eq!(AssetCount::<T>::get((collection, owner)), n as u128 - 1);
+    }
+
+    #[benchmark]
+    fn register_nft_batch(b: Linear<1, { T::MaxBatchSize::get() }>) {
         let caller: T::AccountId = whitelisted_caller();
+        let assets = (1..=b as u128)
+            .map(|asset| {
+                registered::<T>(0u32.into(), asset.into());
+                agent_of::<T>(0u32.into(), asset.into(), asset as u32);
+                (asset.into(), tags::<T>(T::TypeLimit::get()))
+            })
+            .collect::<Vec<_>>();
+
         #[extrinsic_call]
-        do_something(RawOrigin::Signed(caller), value);
+        register_assets_batch(
+            RawOrigin::Signed(caller),
+            0u32.into(),
+            BoundedVec::truncate_from(assets),
+        );
 
-        assert_eq!(Something::<T>::get(), Some(value));
+        assert_eq!(NftInfos::<T>::get(T::CollectionId::from(0u32), T::ItemId::from(b as u128)).tags.len(), T::TypeLimit::get() as usize);
     }
 
     #[benchmark]
-    fn cause_error() {
-        Something::<T>::put(100u32);
+    fn create_ownership_batch(
+        n: Linear<0, { T::MaxInventorySize::get() - T::MaxBatchSize::get() }>,
+        b: Linear<1, { T::MaxBatchSize::get() }>,
+    ) {
+        let (collection, owner) = owner_with_assets::<T>(0, n);
+        let caller = agent_of::<T>(collection, owner, 0);
+        let assets = (0..b as u128)
+            .map(|index| (armory::<T>(), (u128::MAX - index).into()))
+            .collect::<Vec<_>>();
+        assets.iter().for_each(|asset| {
+            registered::<T>(asset.0, asset.1);
+            full_provenance::<T>(*asset);
+        });
+
+        #[extrinsic_call]
+        set_ownership_batch(
+            RawOrigin::Signed(caller),
+            collection,
+            owner,
+            BoundedVec::truncate_from(assets),
+            n,
+        );
+
+        assert_eq!(AssetCount::<T>::get((collection, owner)), (n + b) as u128);
+    }
+
+    #[benchmark]
+    fn remove_ownership_batch(
+        n: Linear<{ T::MaxBatchSize::get() }, { T::MaxInventorySize::get() }>,
+        b: Linear<1, { T::MaxBatchSize::get() }>,
+    ) {
+        let (collection, owner) = owner_with_assets::<T>(0, n);
+        let caller = agent_of::<T>(collection, owner, 0);
+        // Taking away the first assets moves as many relationships from the end.
+        let assets = (1..=b as u128).map(|asset| (armory::<T>(), asset.into())).collect::<Vec<_>>();
+
+        #[extrinsic_call]
+        unset_ownership_batch(
+            RawOrigin::Signed(caller),
+            collection,
+            owner,
+            BoundedVec::truncate_from(assets),
+            n,
+        );
+
+        assert_eq!(AssetCount::<T>::get((collection, owner)), (n - b) as u128);
+    }
+
+    #[benchmark]
+    fn get_owned_assets(n: Linear<0, { T::MaxAssetsPerTransaction::get() }>) {
         let caller: T::AccountId = whitelisted_caller();
+        let (collection, owner) = owner_with_assets::<T>(0, n);
+
+        #[extrinsic_call]
+        get_owned_assets(RawOrigin::Signed(caller), collection, owner, 0, n);
+    }
+
+    #[benchmark]
+    fn force_register(t: Linear<0, { T::TypeLimit::get() }>) -> Result<(), BenchmarkError> {
+        let origin =
+            T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let asset: T::ItemId = 1u128.into();
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, 0u32.into(), asset, tags::<T>(t));
+
+        assert_eq!(NftInfos::<T>::get(T::CollectionId::from(0u32), asset).tags.len(), t as usize);
+        Ok(())
+    }
+
+    #[benchmark]
+    fn force_set_ownership(
+        n: Linear<0, { T::MaxInventorySize::get() - 1 }>,
+    ) -> Result<(), BenchmarkError> {
+        let origin =
+            T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let (collection, owner) = owner_with_assets::<T>(0, n);
+        registered::<T>(armory::<T>(), u128::MAX.into());
+        full_provenance::<T>((armory::<T>(), u128::MAX.into()));
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, collection, owner, armory::<T>(), u128::MAX.into());
+
+        assert_eq!(AssetCount::<T>::get((collection, owner)), n as u128 + 1);
+        Ok(())
+    }
+
+    #[benchmark]
+    fn force_unset_ownership(
+        n: Linear<1, { T::MaxInventorySize::get() }>,
+    ) -> Result<(), BenchmarkError> {
+        let origin =
+            T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let (collection, owner) = owner_with_assets::<T>(0, n);
+        // The last asset is the one found after the longest search.
+        let asset: T::ItemId = (n as u128).into();
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, collection, owner, armory::<T>(), asset);
+
+        assert_eq!(AssetCount::<T>::get((collection, owner)), n as u128 - 1);
+        Ok(())
+    }
+
+    #[benchmark]
+    fn force_unregister(n: Linear<0, { T::MaxInventorySize::get() }>) -> Result<(), BenchmarkError> {
+        let origin =
+            T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let (collection, owner) = owner_with_assets::<T>(0, n);
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, collection, owner);
+
+        assert_eq!(AssetCount::<T>::get((collection, owner)), 0);
+        Ok(())
+    }
+
+    #[benchmark]
+    fn approve_operator() {
+        let collection: T::CollectionId = 0u32.into();
+        let nft: T::ItemId = 0u128.into();
+        let caller: T::AccountId = whitelisted_caller();
+        let operator: T::AccountId = account("operator", 0, 0);
+        T::BenchmarkHelper::mint(&collection, &nft, &caller);
+        let scope = OperatorScope { ownership: true, containers: true, tags: true };
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), collection, nft, operator.clone(), scope, Some(u32::MAX.into()));
+
+        assert!(Operators::<T>::contains_key((collection, nft, operator)));
+    }
+
+    #[benchmark]
+    fn revoke_operator() {
+        let (collection, nft) = (0u32.into(), 0u128.into());
+        let operator = operator_of::<T>(collection, nft);
+        let holder: T::AccountId = account("holder", 0, 0);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(holder), collection, nft, operator.clone());
+
+        assert!(!Operators::<T>::contains_key((collection, nft, operator)));
+    }
+
+    #[benchmark]
+    fn appoint_agent() {
+        let (collection, principal): (T::CollectionId, T::ItemId) = (0u32.into(), 0u128.into());
+        let caller: T::AccountId = whitelisted_caller();
+        T::BenchmarkHelper::mint(&collection, &principal, &caller);
+        // The last agent allowed.
+        let max = T::MaxAgents::get();
+        for index in 1..max {
+            Agents::<T>::insert(
+                (collection, principal, familiars::<T>(), T::ItemId::from(index as u128)),
+                OperatorScope::default(),
+            );
+        }
+        AgentCount::<T>::insert((collection, principal), max - 1);
+        let agent: T::ItemId = 0u128.into();
+        T::BenchmarkHelper::mint(&familiars::<T>(), &agent, &caller);
+        let scope = OperatorScope { ownership: true, containers: true, tags: true };
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), collection, principal, familiars::<T>(), agent, scope);
+
+        assert_eq!(AgentCount::<T>::get((collection, principal)), max);
+    }
+
+    #[benchmark]
+    fn dismiss_agent() {
+        let (collection, principal): (T::CollectionId, T::ItemId) = (0u32.into(), 0u128.into());
+        let caller = agent_of::<T>(collection, principal, 0);
+        let (_, agent) = Agents::<T>::iter_key_prefix((collection, principal)).last().unwrap();
+
+        // The agent resigns, after checking that the caller does not hold the principal.
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), collection, principal, familiars::<T>(), agent);
+
+        assert_eq!(AgentCount::<T>::get((collection, principal)), T::MaxAgents::get() - 1);
+    }
+
+    #[benchmark]
+    fn lend() {
+        let (collection, lender) = owner_with_assets::<T>(0, 0);
+        let (_, borrower) = owner_with_assets::<T>(BORROWER, 0);
+        let caller = agent_of::<T>(collection, lender, 0);
+        let asset: T::ItemId = 1u128.into();
+        let until = frame_system::Pallet::<T>::block_number() + 10u32.into();
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(caller),
+            collection,
+            lender,
+            armory::<T>(),
+            asset,
+            collection,
+            borrower,
+            until,
+        );
+
+        assert!(LoanOffers::<T>::contains_key((armory::<T>(), asset)));
+    }
+
+    #[benchmark]
+    fn accept_loan(n: Linear<1, { T::MaxInventorySize::get() - 1 }>) {
+        let (collection, lender) = owner_with_assets::<T>(0, n);
+        let (_, borrower) = owner_with_assets::<T>(BORROWER, n);
+        let caller = agent_of::<T>(collection, borrower, 0);
+        // The last asset of the lender, among as many loans expiring at the same block as allowed.
+        let asset = (armory::<T>(), T::ItemId::from(n as u128));
+        let until = frame_system::Pallet::<T>::block_number() + 10u32.into();
+        expiring_loans::<T>(until, T::MaxLoansPerBlock::get() - 1);
+        LoanOffers::<T>::insert(
+            asset,
+            Loan { lender: (collection, lender), borrower: (collection, borrower), until },
+        );
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), asset.0, asset.1);
+
+        assert_eq!(AssetCount::<T>::get((collection, borrower)), n as u128 + 1);
+    }
+
+    #[benchmark]
+    fn cancel_loan_offer() {
+        // The caller is not on the side of the lender, which is checked first, but on the side of
+        // the borrower.
+        let (collection, lender) = owner_with_assets::<T>(0, 0);
+        let holder: T::AccountId = account("holder", 0, 0);
+        T::BenchmarkHelper::mint(&collection, &lender, &holder);
+        appoint_agents::<T>(collection, lender, 0, &holder);
+        let (_, borrower) = owner_with_assets::<T>(BORROWER, 0);
+        let caller = agent_of::<T>(collection, borrower, 1);
+        let asset = (armory::<T>(), T::ItemId::from(1u128));
+        let until = frame_system::Pallet::<T>::block_number() + 10u32.into();
+        LoanOffers::<T>::insert(
+            asset,
+            Loan { lender: (collection, lender), borrower: (collection, borrower), until },
+        );
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), asset.0, asset.1);
+
+        assert!(!LoanOffers::<T>::contains_key(asset));
+    }
+
+    #[benchmark]
+    fn return_loan(n: Linear<1, { 2 * T::MaxInventorySize::get() - 1 }>) {
+        // The assets searched fill the inventory of the borrower first, and the rest are held by
+        // the lender, whose inventory is searched in full for the asset before it is given back.
+        let borrowed = n.min(T::MaxInventorySize::get());
+        let (collection, lender) = owner_with_assets::<T>(0, n - borrowed);
+        let (_, borrower) = owner_with_assets::<T>(BORROWER, borrowed);
+        let caller = agent_of::<T>(collection, borrower, 0);
+        // The last asset of the borrower, among as many loans expiring at the same block as allowed.
+        let asset = (armory::<T>(), T::ItemId::from(BORROWER + borrowed as u128));
+        let until = frame_system::Pallet::<T>::block_number() + 10u32.into();
+        expiring_loans::<T>(until, T::MaxLoansPerBlock::get() - 1);
+        LoanExpiries::<T>::mutate(until, |expiring| expiring.try_push(asset).unwrap());
+        Loans::<T>::insert(
+            asset,
+            Loan { lender: (collection, lender), borrower: (collection, borrower), until },
+        );
+        LentCount::<T>::insert((collection, lender), 1);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), asset.0, asset.1);
+
+        assert_eq!(AssetCount::<T>::get((collection, lender)), (n - borrowed) as u128 + 1);
+    }
+
+    #[benchmark]
+    fn propose_relationship() {
+        // The caller is not on the side of the owner, which is checked first, but on the side of
+        // the asset.
+        let (collection, owner) = owner_with_assets::<T>(0, 0);
+        let holder: T::AccountId = account("holder", 0, 0);
+        T::BenchmarkHelper::mint(&collection, &owner, &holder);
+        appoint_agents::<T>(collection, owner, 0, &holder);
+        let asset: T::ItemId = u128::MAX.into();
+        registered::<T>(armory::<T>(), asset);
+        let caller = agent_of::<T>(armory::<T>(), asset, 1);
+        let expiry = frame_system::Pallet::<T>::block_number() + 10u32.into();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), collection, owner, armory::<T>(), asset, expiry);
+
+        assert!(Proposals::<T>::contains_key((collection, owner, armory::<T>(), asset)));
+    }
+
+    #[benchmark]
+    fn accept_relationship(n: Linear<0, { T::MaxInventorySize::get() - 1 }>) {
+        let (collection, owner) = owner_with_assets::<T>(0, n);
+        let caller = agent_of::<T>(collection, owner, 0);
+        let asset = (armory::<T>(), T::ItemId::from(u128::MAX));
+        proposed::<T>((collection, owner), asset, false);
+        registered::<T>(asset.0, asset.1);
+        full_provenance::<T>(asset);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), collection, owner, asset.0, asset.1, n);
+
+        assert_eq!(AssetCount::<T>::get((collection, owner)), n as u128 + 1);
+    }
+
+    #[benchmark]
+    fn reject_relationship() {
+        let (collection, owner) = owner_with_assets::<T>(0, 0);
+        let caller = agent_of::<T>(collection, owner, 0);
+        let asset = (armory::<T>(), T::ItemId::from(u128::MAX));
+        proposed::<T>((collection, owner), asset, false);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), collection, owner, asset.0, asset.1);
+
+        assert!(!Proposals::<T>::contains_key((collection, owner, asset.0, asset.1)));
+    }
+
+    #[benchmark]
+    fn cancel_relationship() {
+        let (collection, owner) = owner_with_assets::<T>(0, 0);
+        let caller = agent_of::<T>(collection, owner, 0);
+        let asset = (armory::<T>(), T::ItemId::from(u128::MAX));
+        proposed::<T>((collection, owner), asset, true);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), collection, owner, asset.0, asset.1);
+
+        assert!(!Proposals::<T>::contains_key((collection, owner, asset.0, asset.1)));
+    }
+
+    #[benchmark]
+    fn dispatch_as_nft() {
+        let caller: T::AccountId = whitelisted_caller();
+        let (collection, item): (T::CollectionId, T::ItemId) = (0u32.into(), 0u128.into());
+        T::BenchmarkHelper::mint(&collection, &item, &caller);
+        let call: <T as Config>::RuntimeCall = frame_system::Call::<T>::remark { remark: vec![] }.into();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), collection, item, Box::new(call));
+
+        frame_system::Pallet::<T>::assert_last_event(
+            <T as Config>::RuntimeEvent::from(Event::DispatchedAsNft {
+                nft: (collection, item),
+                account: NftHierarchy::<T>::nft_account(&collection, &item),
+                result: Ok(()),
+            })
+            .into(),
+        );
+    }
+
+    #[benchmark]
+    fn prune_provenance(n: Linear<1, { T::MaxProvenance::get() }>) {
+        let caller: T::AccountId = whitelisted_caller();
+        let asset = (armory::<T>(), T::ItemId::from(u128::MAX));
+        let owner = (0u32.into(), 0u128.into());
+        // The n oldest entries expired, and the search stops at the next one if there is room.
+        let retention = T::ProvenanceRetention::get();
+        frame_system::Pallet::<T>::set_block_number(retention + 1u32.into());
+        let entries = (n + 1).min(T::MaxProvenance::get());
+        for index in 0..entries {
+            let at = if index < n { 0u32.into() } else { retention + 1u32.into() };
+            let change = CustodyChange::Acquired;
+            let entry = ProvenanceEntry { owner, change, at };
+            Provenance::<T>::insert((asset.0, asset.1, index), entry);
+        }
+        ProvenanceBounds::<T>::insert(asset, (0, entries));
+
         #[extrinsic_call]
-        cause_error(RawOrigin::Signed(caller));
+        _(RawOrigin::Signed(caller), asset.0, asset.1, n);
 
-        assert_eq!(Something::<T>::get(), Some(101u32));
+        assert_eq!(ProvenanceBounds::<T>::get(asset), (n, entries));
     }
 
-    impl_benchmark_test_suite!(Template, crate::mock::new_test_ext(), crate::mock::Test);
+    impl_benchmark_test_suite!(NftHierarchy, crate::mock::new_test_ext(), crate::mock::Test);
 }
@@ -2,7 +2,7 @@ use crate::Config;
 use sp_std::str;
 use scale_info::TypeInfo;
 use sp_runtime::{ BoundedVec, traits::Get };
-use frame_support::pallet_prelude::{ Encode, Decode, MaxEncodedLen };
+use frame_support::pallet_prelude::{ Encode, Decode, DecodeWithMemTracking, MaxEncodedLen, RuntimeDebug };
 
 /// This structure represents the information corresponding to a single NFT: the collection it belongs to, 
 /// its relationships (for the MVP only its owner  if it has one), and the tags that define its type.
@@ -15,7 +15,7 @@ pub struct NftInfo<T: Config> {
 impl<T: Config> NftInfo<T> {
     pub fn is_type(&self, tag: &str) -> bool {
         self.tags.iter().any(|bounded_vec| {
-            str::from_utf8(&bounded_vec)
+            str::from_utf8(bounded_vec)
                 .map(|s| s == tag)
                 .unwrap_or(false)
         })
@@ -41,4 +41,61 @@ impl<T: Config> Default for NftInfo<T> {
             tags: BoundedVec::default(),
         }
     }
-}
\ No newline at end of file
+}
+
+/// What an operator may manage on behalf of the holder of an NFT.
+#[derive(
+    Encode, Decode, DecodeWithMemTracking, Clone, Copy, Default, PartialEq, Eq, RuntimeDebug,
+    TypeInfo, MaxEncodedLen,
+)]
+pub struct OperatorScope {
+    /// The assets the NFT owns.
+    pub ownership: bool,
+    /// The contents of the NFT as a container, once the container relationship exists.
+    pub containers: bool,
+    /// The tags that make up the type of the NFT.
+    pub tags: bool,
+}
+
+/// The approval of an operator by the holder of an NFT. It only holds while the approver still
+/// holds the NFT, and until the block `expiry` if any.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct Approval<AccountId, BlockNumber> {
+    pub approver: AccountId,
+    pub scope: OperatorScope,
+    pub expiry: Option<BlockNumber>,
+}
+
+/// A loan of an asset from one owner to another, which returns the asset to the lender at the
+/// block `until` at the latest.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct Loan<CollectionId, ItemId, BlockNumber> {
+    pub lender: (CollectionId, ItemId),
+    pub borrower: (CollectionId, ItemId),
+    pub until: BlockNumber,
+}
+
+/// A proposal to create an ownership relationship between the NFTs of different accounts, which
+/// the other side accepts or rejects until the block `expiry`.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct RelationshipProposal<AccountId, BlockNumber> {
+    pub proposer: AccountId,
+    /// Whether the proposal comes from the side of the owner, rather than that of the asset.
+    pub by_owner: bool,
+    pub expiry: BlockNumber,
+}
+
+/// Whether an owner acquired or released an asset, in the provenance of the asset.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum CustodyChange {
+    Acquired,
+    Released,
+}
+
+/// An entry of the provenance of an asset: `owner` acquired or released it at the block `at`.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct ProvenanceEntry<CollectionId, ItemId, BlockNumber> {
+    pub owner: (CollectionId, ItemId),
+    pub change: CustodyChange,
+    pub at: BlockNumber,
+}
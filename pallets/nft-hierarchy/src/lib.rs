@@ -6,6 +6,12 @@ pub use pallet::*;
 // Common functionality.
 mod common;
 
+// Traits through which other pallets read the relationships.
+pub mod traits;
+
+// Migrations of the pallet storage between versions.
+pub mod migrations;
+
 #[cfg(test)]
 mod mock;
 
@@ -17,15 +23,39 @@ mod benchmarking;
 pub mod weights;
 pub use weights::*;
 
+pub use common::{
+    Approval, CustodyChange, Loan, OperatorScope, ProvenanceEntry, RelationshipProposal,
+};
+
+/// Mints the NFTs that the benchmarks manage, as the Nfts of the pallet only need to be inspected.
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<CollectionId, ItemId, AccountId> {
+    /// Mint the item `item` of the collection `collection` for `owner`, creating the collection
+    /// if it does not exist.
+    fn mint(collection: &CollectionId, item: &ItemId, owner: &AccountId);
+}
+
+// The dispatch code generated for calls returning post-dispatch info converts it into itself.
+#[allow(clippy::useless_conversion)]
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
-    use common::NftInfo;
+    use common::{CustodyChange, NftInfo, ProvenanceEntry};
+    use frame_support::dispatch::{extract_actual_weight, GetDispatchInfo, PostDispatchInfo};
     use frame_support::pallet_prelude::*;
     use frame_support::storage::Key;
+    use frame_support::traits::tokens::nonfungibles_v2::{Create, Inspect, Mutate};
     use frame_system::pallet_prelude::*;
+    use frame_support::{Hashable, PalletId};
+    use sp_runtime::traits::{Dispatchable, One, Saturating, TrailingZeroInput, Zero};
+    use sp_std::{boxed::Box, vec::Vec};
+    use frame_support::weights::WeightMeter;
+
+    /// The in-code storage version.
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
 
     #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
     /// The pallet's configuration trait.
@@ -42,10 +72,22 @@ pub mod pallet {
         type WeightInfo: WeightInfo;
 
 		/// Identifier for the collection of item.
-		type CollectionId: Member + Parameter + MaxEncodedLen + Copy + From<u32> + Into<u32>;
+		type CollectionId: Member
+			+ Parameter
+			+ MaxEncodedLen
+			+ MaybeSerializeDeserialize
+			+ Copy
+			+ From<u32>
+			+ Into<u32>;
 
 		/// The type used to identify a unique item within a collection.
-		type ItemId: Member + Parameter + MaxEncodedLen + Copy + From<u128> + Into<u128>;
+		type ItemId: Member
+			+ Parameter
+			+ MaxEncodedLen
+			+ MaybeSerializeDeserialize
+			+ Copy
+			+ From<u128>
+			+ Into<u128>;
 
         /// Limit on the string length for store elements.
         type StringLimit: Get<u32>;
@@ -55,26 +97,106 @@ pub mod pallet {
 
         /// Define the batch of NFTs retrieved per transaction.
         type MaxAssetsPerTransaction: Get<u32>;
+
+        /// Maximum number of assets an NFT can own, which bounds the weight of the calls that
+        /// go through its assets.
+        #[pallet::constant]
+        type MaxInventorySize: Get<u32>;
+
+        /// Maximum number of assets registered, given or taken away in a single batch call.
+        #[pallet::constant]
+        type MaxBatchSize: Get<u32>;
+
+        /// Maximum number of agents appointed by a single NFT.
+        #[pallet::constant]
+        type MaxAgents: Get<u32>;
+
+        /// Maximum number of loans that expire at the same block.
+        #[pallet::constant]
+        type MaxLoansPerBlock: Get<u32>;
+
+        /// The origin of the game administrators, who can fix the registrations and the
+        /// relationships through the force_* calls.
+        type ForceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Maximum number of entries retained in the provenance of each asset. Once reached, the
+        /// oldest entry is dropped for each new one.
+        #[pallet::constant]
+        type MaxProvenance: Get<u32>;
+
+        /// Number of blocks the entries of a provenance are retained before anyone can prune them.
+        #[pallet::constant]
+        type ProvenanceRetention: Get<BlockNumberFor<Self>>;
+
+        /// The overarching call type, which the token-bound accounts of the NFTs dispatch.
+        type RuntimeCall: Parameter
+            + Dispatchable<RuntimeOrigin = Self::RuntimeOrigin, PostInfo = PostDispatchInfo>
+            + GetDispatchInfo
+            + From<frame_system::Call<Self>>
+            + IsType<<Self as frame_system::Config>::RuntimeCall>;
+
+        /// The identifier from which the token-bound account of each NFT is derived.
+        #[pallet::constant]
+        type PalletId: Get<PalletId>;
+
+        /// The NFTs themselves, which tell who holds each one. The holder of an NFT manages its
+        /// relationships, or approves operators to do so. The collections and items of the genesis
+        /// are created through them.
+        type Nfts: Inspect<Self::AccountId, CollectionId = Self::CollectionId, ItemId = Self::ItemId>
+            + Create<Self::AccountId, Self::NftCollectionConfig>
+            + Mutate<Self::AccountId, Self::NftItemConfig>;
+
+        /// The configuration of the collections created at genesis, such as the settings of
+        /// `pallet_nfts`, which takes its default.
+        type NftCollectionConfig: Default;
+
+        /// The configuration of the items minted at genesis, which takes its default.
+        type NftItemConfig: Default;
+
+        /// Mints the NFTs that the benchmarks manage.
+        #[cfg(feature = "runtime-benchmarks")]
+        type BenchmarkHelper: BenchmarkHelper<Self::CollectionId, Self::ItemId, Self::AccountId>;
     }
 
-    /// The following list of storage elements represents the relationships between different types
-    /// of entities in Telurean Chain. In addition to specific relationships, there are two properties
-    /// for user-defined relationships: one paginated to storage an indeterminate number of relationships
-    /// and another limited. The purpose of storage segmentation is to minimize the gas impact of searches.
+    /// The tags that make up the type of an NFT.
+    pub type Tags<T> =
+        BoundedVec<BoundedVec<u8, <T as Config>::StringLimit>, <T as Config>::TypeLimit>;
+
+    /// An NFT, identified by its collection and its item.
+    pub type Nft<T> = (<T as Config>::CollectionId, <T as Config>::ItemId);
+
+    /// An entry of the provenance of an asset in this runtime.
+    pub type ProvenanceOf<T> = ProvenanceEntry<
+        <T as Config>::CollectionId,
+        <T as Config>::ItemId,
+        BlockNumberFor<T>,
+    >;
+
+    /// The tags of an NFT registered at genesis, which must fit in the limits of the pallet.
+    pub type GenesisTags = Vec<Vec<u8>>;
 
-    /// Map where each NFT, identified by its ID, corresponds to a structure that stores the information
-    /// about it. The key type is u128, so the system’s NFT limit is 2¹²⁸. Essentially infinite.
+    // The following list of storage elements represents the relationships between different types
+    // of entities in Telurean Chain. In addition to specific relationships, there are two properties
+    // for user-defined relationships: one paginated to storage an indeterminate number of relationships
+    // and another limited. The purpose of storage segmentation is to minimize the gas impact of searches.
+
+    /// Map where each NFT, identified by its collection and its ID, corresponds to a structure that
+    /// stores the information about it. Each collection numbers its items on its own, so the first
+    /// character of the Heroes and the first sword of the Armory may both be item 1.
     #[pallet::storage]
-    pub type NftInfos<T: Config> = StorageMap<
+    pub type NftInfos<T: Config> = StorageDoubleMap<
         _,
         Blake2_128Concat,
+        T::CollectionId,
+        Blake2_128Concat,
         T::ItemId,
         NftInfo<T>,
         ValueQuery,
     >;
 
     /// Ownership relationship. This relationship is paginated by an asset counter for each owner.
-    /// Only the ItemId is stored since the CollectionId matches that of the owner.
+    /// Assets are stored with their own CollectionId, as an owner may own assets from any
+    /// collection: a character from the Heroes collection owns a sword from the Armory.
     #[pallet::storage]
     pub type OwnerAssets<T: Config> = StorageNMap<
         Key = (
@@ -82,7 +204,7 @@ pub mod pallet {
             Key<Twox64Concat, T::ItemId>,
             Key<Twox64Concat, u128>, // Asset counter that acts as an index in pagination.
         ),
-        Value = Option<T::ItemId>,
+        Value = Option<(T::CollectionId, T::ItemId)>,
         QueryKind = ValueQuery,
     >;
 
@@ -95,6 +217,137 @@ pub mod pallet {
         u128,
         ValueQuery>;
 
+    /// Operators approved by the holder of an NFT to manage its relationships on their behalf.
+    #[pallet::storage]
+    pub type Operators<T: Config> = StorageNMap<
+        Key = (
+            Key<Twox64Concat, T::CollectionId>,
+            Key<Twox64Concat, T::ItemId>,
+            Key<Blake2_128Concat, T::AccountId>, // The operator.
+        ),
+        Value = Approval<T::AccountId, BlockNumberFor<T>>,
+        QueryKind = OptionQuery,
+    >;
+
+    /// Agent relationship: the agents appointed by an NFT, their principal, to manage some of its
+    /// relationships on its behalf, such as a familiar, a henchman or a steward. Whoever holds an
+    /// agent acts on the principal within the scope of the agent. The relationship is between the
+    /// NFTs, so it survives either of them changing hands.
+    #[pallet::storage]
+    pub type Agents<T: Config> = StorageNMap<
+        Key = (
+            Key<Twox64Concat, T::CollectionId>,
+            Key<Twox64Concat, T::ItemId>, // The principal.
+            Key<Twox64Concat, T::CollectionId>,
+            Key<Twox64Concat, T::ItemId>, // The agent.
+        ),
+        Value = OperatorScope,
+        QueryKind = OptionQuery,
+    >;
+
+    /// Counter of agents for each principal, bounded by `MaxAgents`.
+    #[pallet::storage]
+    pub type AgentCount<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        (T::CollectionId, T::ItemId),
+        u32,
+        ValueQuery>;
+
+    /// Assets on loan, with the owner that lent them and the owner that borrowed them. While on
+    /// loan, the asset is in the inventory of the borrower, who can neither give it away nor lend
+    /// it again, and its NFT is locked.
+    #[pallet::storage]
+    pub type Loans<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        (T::CollectionId, T::ItemId),
+        Loan<T::CollectionId, T::ItemId, BlockNumberFor<T>>,
+        OptionQuery,
+    >;
+
+    /// Loans offered by a lender and not accepted by the borrower yet, keyed by the asset. The
+    /// borrower can accept an offer until the block at which the loan would end.
+    #[pallet::storage]
+    pub type LoanOffers<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        (T::CollectionId, T::ItemId),
+        Loan<T::CollectionId, T::ItemId, BlockNumberFor<T>>,
+        OptionQuery,
+    >;
+
+    /// Counter of assets each owner has on loan. The lender keeps room in its inventory for each
+    /// of them, so that they can always come back.
+    #[pallet::storage]
+    pub type LentCount<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        (T::CollectionId, T::ItemId),
+        u32,
+        ValueQuery>;
+
+    /// Assets whose loans expire at each block.
+    #[pallet::storage]
+    pub type LoanExpiries<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        BlockNumberFor<T>,
+        BoundedVec<(T::CollectionId, T::ItemId), T::MaxLoansPerBlock>,
+        ValueQuery,
+    >;
+
+    /// The next block whose expired loans are to be returned. It starts with the first loan.
+    #[pallet::storage]
+    pub type NextLoanSweep<T: Config> = StorageValue<_, BlockNumberFor<T>, ValueQuery>;
+
+    /// Pending proposals of ownership relationships between the NFTs of different accounts,
+    /// keyed by the owner and the asset.
+    #[pallet::storage]
+    pub type Proposals<T: Config> = StorageNMap<
+        Key = (
+            Key<Twox64Concat, T::CollectionId>,
+            Key<Twox64Concat, T::ItemId>, // The owner.
+            Key<Twox64Concat, T::CollectionId>,
+            Key<Twox64Concat, T::ItemId>, // The asset.
+        ),
+        Value = RelationshipProposal<T::AccountId, BlockNumberFor<T>>,
+        QueryKind = OptionQuery,
+    >;
+
+    /// Provenance of each asset: the owners that acquired and released it, with the block of
+    /// each change, indexed in the order they happened. Only the latest `MaxProvenance` entries
+    /// are retained.
+    #[pallet::storage]
+    pub type Provenance<T: Config> = StorageNMap<
+        Key = (
+            Key<Twox64Concat, T::CollectionId>,
+            Key<Twox64Concat, T::ItemId>, // The asset.
+            Key<Twox64Concat, u32>, // Index of the entry.
+        ),
+        Value = ProvenanceOf<T>,
+        QueryKind = OptionQuery,
+    >;
+
+    /// Index of the oldest entry retained and of the next entry of the provenance of each asset.
+    #[pallet::storage]
+    pub type ProvenanceBounds<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        (T::CollectionId, T::ItemId),
+        (u32, u32),
+        ValueQuery>;
+
+    /// Number of inventories holding each asset, kept with its provenance. An NFT that some
+    /// owner still holds cannot be unregistered.
+    #[pallet::storage]
+    pub type OwnerCount<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        (T::CollectionId, T::ItemId),
+        u32,
+        ValueQuery>;
+
     // Other possible relationships for future implementation:
     //   · CharacterBadges ━━━━━ Possibly for a future pallet Audit. 
     //   · CarrierItems
@@ -115,16 +368,122 @@ pub mod pallet {
         OwnershipAdded {
             who: T::AccountId,
             owner: (T::CollectionId, T::ItemId),
-            asset: T::ItemId,
+            asset: (T::CollectionId, T::ItemId),
         },
         OwnershipRemoved {
             who: T::AccountId,
             owner: (T::CollectionId, T::ItemId),
-            asset: T::ItemId,
+            asset: (T::CollectionId, T::ItemId),
         },
         AssetsRetrieved {
             owner: (T::CollectionId, T::ItemId),
-            assets: BoundedVec<T::ItemId, T::MaxAssetsPerTransaction>,
+            assets: BoundedVec<(T::CollectionId, T::ItemId), T::MaxAssetsPerTransaction>,
+        },
+        /// An administrator registered an asset.
+        NftForceRegistered {
+            collection: T::CollectionId,
+            asset: T::ItemId,
+        },
+        /// An administrator gave an asset to an owner.
+        OwnershipForceAdded {
+            owner: (T::CollectionId, T::ItemId),
+            asset: (T::CollectionId, T::ItemId),
+        },
+        /// An administrator took an asset away from its owner.
+        OwnershipForceRemoved {
+            owner: (T::CollectionId, T::ItemId),
+            asset: (T::CollectionId, T::ItemId),
+        },
+        /// An administrator removed the registration of an asset, along with the assets it owned.
+        NftForceUnregistered {
+            collection: T::CollectionId,
+            asset: T::ItemId,
+        },
+        /// The holder of an NFT approved an operator to manage its relationships.
+        OperatorApproved {
+            nft: (T::CollectionId, T::ItemId),
+            operator: T::AccountId,
+            scope: OperatorScope,
+            expiry: Option<BlockNumberFor<T>>,
+        },
+        /// The holder of an NFT revoked the approval of an operator.
+        OperatorRevoked {
+            nft: (T::CollectionId, T::ItemId),
+            operator: T::AccountId,
+        },
+        /// An NFT appointed another one as its agent.
+        AgentAppointed {
+            principal: (T::CollectionId, T::ItemId),
+            agent: (T::CollectionId, T::ItemId),
+            scope: OperatorScope,
+        },
+        /// An agent was dismissed by the holder of its principal, or resigned.
+        AgentDismissed {
+            principal: (T::CollectionId, T::ItemId),
+            agent: (T::CollectionId, T::ItemId),
+        },
+        /// An owner offered to lend an asset to another one until the block `until`.
+        LoanOffered {
+            lender: (T::CollectionId, T::ItemId),
+            borrower: (T::CollectionId, T::ItemId),
+            asset: (T::CollectionId, T::ItemId),
+            until: BlockNumberFor<T>,
+        },
+        /// Either side cancelled the offer of a loan.
+        LoanOfferCancelled {
+            lender: (T::CollectionId, T::ItemId),
+            borrower: (T::CollectionId, T::ItemId),
+            asset: (T::CollectionId, T::ItemId),
+            who: T::AccountId,
+        },
+        /// The borrower accepted a loan, and the asset went to it until the block `until`.
+        AssetLent {
+            lender: (T::CollectionId, T::ItemId),
+            borrower: (T::CollectionId, T::ItemId),
+            asset: (T::CollectionId, T::ItemId),
+            until: BlockNumberFor<T>,
+        },
+        /// A loan ended, either returned early by the borrower or expired, and the asset went
+        /// back to the lender.
+        LoanReturned {
+            lender: (T::CollectionId, T::ItemId),
+            borrower: (T::CollectionId, T::ItemId),
+            asset: (T::CollectionId, T::ItemId),
+        },
+        /// One side proposed an ownership relationship to the other.
+        RelationshipProposed {
+            owner: (T::CollectionId, T::ItemId),
+            asset: (T::CollectionId, T::ItemId),
+            proposer: T::AccountId,
+            expiry: BlockNumberFor<T>,
+        },
+        /// The other side accepted a proposal, and the relationship was created.
+        RelationshipAccepted {
+            owner: (T::CollectionId, T::ItemId),
+            asset: (T::CollectionId, T::ItemId),
+            who: T::AccountId,
+        },
+        /// The other side rejected a proposal.
+        RelationshipRejected {
+            owner: (T::CollectionId, T::ItemId),
+            asset: (T::CollectionId, T::ItemId),
+            who: T::AccountId,
+        },
+        /// The proposing side cancelled a proposal.
+        RelationshipCancelled {
+            owner: (T::CollectionId, T::ItemId),
+            asset: (T::CollectionId, T::ItemId),
+        },
+        /// The holder of an NFT dispatched a call from its token-bound account.
+        DispatchedAsNft {
+            nft: (T::CollectionId, T::ItemId),
+            account: T::AccountId,
+            result: DispatchResult,
+        },
+        /// The oldest entries of the provenance of an asset were pruned.
+        ProvenancePruned {
+            asset: (T::CollectionId, T::ItemId),
+            entries: u32,
         },
     }
 
@@ -138,22 +497,123 @@ pub mod pallet {
         OwnershipNotFound,
         ExceededTypeLimit,
         ExceededMaxAssetsPerQuery,
+        ExceededMaxInventorySize,
+        InventoryHintTooLow,
         WrongNft,
+        NotRegistered,
+        ExpiryInThePast,
+        OperatorNotFound,
+        ExceededMaxAgents,
+        AgentNotFound,
+        AssetOnLoan,
+        LoanNotFound,
+        ExceededMaxLoansPerBlock,
+        ConsentRequired,
+        ProposalNotFound,
+        ProposalExpired,
+        NothingToPrune,
+        StillOwned,
+    }
+
+    #[pallet::genesis_config]
+    #[derive(frame_support::DefaultNoBound)]
+    pub struct GenesisConfig<T: Config> {
+        /// Collections created at genesis, with the account that owns them and pays their deposit.
+        pub collections: Vec<(T::CollectionId, T::AccountId)>,
+        /// Items minted at genesis, with the account that holds them.
+        pub items: Vec<(T::CollectionId, T::ItemId, T::AccountId)>,
+        /// NFTs registered at genesis, with the tags that make up their type.
+        pub nfts: Vec<(T::CollectionId, T::ItemId, GenesisTags)>,
+        /// Ownership relationships at genesis, as pairs of an owner and one of its assets. Both
+        /// must be registered in `nfts`.
+        pub ownerships: Vec<(Nft<T>, Nft<T>)>,
+    }
+
+    #[pallet::genesis_build]
+    impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+        fn build(&self) {
+            for (collection, owner) in &self.collections {
+                T::Nfts::create_collection_with_id(
+                    *collection,
+                    owner,
+                    owner,
+                    &T::NftCollectionConfig::default(),
+                )
+                .expect("the collections of the genesis are new and their owners have funds");
+            }
+            for (collection, item, holder) in &self.items {
+                // The owner of the collection pays the deposit of the items, as with uniques.
+                T::Nfts::mint_into(collection, item, holder, &T::NftItemConfig::default(), true)
+                    .expect("the items of the genesis are new and their collections exist");
+            }
+
+            for (collection, item, tags) in &self.nfts {
+                assert!(
+                    T::Nfts::owner(collection, item).is_some(),
+                    "the NFTs registered at genesis exist"
+                );
+                assert!(
+                    NftInfos::<T>::get(collection, item).collec_id.is_none(),
+                    "the NFTs registered at genesis are registered once"
+                );
+                let tags: Vec<_> = tags
+                    .iter()
+                    .map(|tag| {
+                        BoundedVec::try_from(tag.clone())
+                            .expect("the tags of the genesis fit in StringLimit")
+                    })
+                    .collect();
+                let tags =
+                    Tags::<T>::try_from(tags).expect("the tags of the genesis fit in TypeLimit");
+                NftInfos::<T>::insert(collection, item, NftInfo {
+                    collec_id: Some(*collection),
+                    owner_id: None,
+                    tags
+                });
+            }
+
+            for (owner, asset) in &self.ownerships {
+                assert!(
+                    NftInfos::<T>::get(owner.0, owner.1).is_type("owner"),
+                    "the owners of the genesis are registered with the owner tag"
+                );
+                assert!(
+                    NftInfos::<T>::contains_key(asset.0, asset.1),
+                    "the assets owned at genesis are registered"
+                );
+                Pallet::<T>::give_asset(owner, asset, u32::MAX)
+                    .expect("the owners of the genesis have room for their assets, once each");
+            }
+        }
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        /// Return the assets whose loans expired, as many as the remaining weight of the block
+        /// allows. The rest are returned in the following blocks.
+        fn on_idle(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            Self::sweep_loans(now, remaining_weight)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn try_state(_: BlockNumberFor<T>) -> Result<(), sp_runtime::TryRuntimeError> {
+            Self::do_try_state()
+        }
     }
 
     #[pallet::call]
     impl<T: Config> Pallet<T>
     {
         /// Register a new asset in the pallet, understanding an asset as an NFT that is not
-        /// a collection. Both the specified collection and the asset must have been previously 
-        /// created in the corresponding pallet, register_asset does not check this condition.
+        /// a collection. The asset must have been previously created in the corresponding
+        /// pallet, and only its holder, or an operator or agent for its tags, can register it.
         #[pallet::call_index(0)]
-        #[pallet::weight(<T as pallet::Config>::WeightInfo::register_nft())]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::register_nft(tags.len() as u32))]
         pub fn register_asset(
             origin: OriginFor<T>,
             collec_id: T::CollectionId,
             asset_id: T::ItemId,
-            tags: BoundedVec<BoundedVec<u8, T::StringLimit>, T::TypeLimit>,
+            tags: Tags<T>,
         ) -> DispatchResult {
 
             let who = ensure_signed(origin)?;
@@ -164,14 +624,16 @@ pub mod pallet {
                 Error::<T>::ExceededTypeLimit
             );
 
-            NftInfos::<T>::insert(asset_id, NftInfo { 
+            Self::ensure_can_retag(&who, &collec_id, &asset_id)?;
+
+            NftInfos::<T>::insert(collec_id, asset_id, NftInfo { 
                 collec_id: Some(collec_id),
                 owner_id: None,
-                tags: tags
+                tags
             });
             
             Self::deposit_event(Event::NftRegistered {
-                who: who,
+                who,
                 collection: collec_id,
                 asset: asset_id,
             });
@@ -180,86 +642,83 @@ pub mod pallet {
         }
 
         /// The ownership relationship between NFTs (one NFT being the owner of another NFT) 
-        /// is one of the basic relationships in Telurean Chain.
+        /// is one of the basic relationships in Telurean Chain. The asset may belong to a
+        /// collection other than the owner's.
+        ///
+        /// `inventory_hint` is an upper bound on the number of assets the owner already has. The
+        /// call is charged for that many assets upfront and the difference is refunded.
         #[pallet::call_index(1)]
-        #[pallet::weight(<T as pallet::Config>::WeightInfo::create_ownership())]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::create_ownership(
+            (*inventory_hint).min(T::MaxInventorySize::get())
+        ))]
         pub fn set_ownership(
             origin: OriginFor<T>,
             collec_id: T::CollectionId,
             owner_id: T::ItemId,
+            asset_collec_id: T::CollectionId,
             asset_id: T::ItemId,
-        ) -> DispatchResult {
+            inventory_hint: u32,
+        ) -> DispatchResultWithPostInfo {
 
             let who = ensure_signed(origin)?;
+            Self::ensure_can_manage(&who, &collec_id, &owner_id, |scope| scope.ownership)?;
 
-            let owner = NftInfos::<T>::get(owner_id);
             ensure!(
-                owner.collec_id.is_some() && owner.is_type("owner"),
+                NftInfos::<T>::get(collec_id, owner_id).is_type("owner"),
                 Error::<T>::WrongNft
             );
 
-            // Verify that the owner does not own the asset yet.
-            let count = AssetCount::<T>::get((collec_id, owner_id));
-            let mut found_asset = false;
-            for index in 0..count {
-                if OwnerAssets::<T>::get((collec_id, owner_id, index)) == Some(asset_id) {
-                    found_asset = true;
-                    break;
-                }
-            }
-            ensure!(!found_asset, Error::<T>::AlreadyOwner);
-
-            OwnerAssets::<T>::insert((collec_id, owner_id, count), Some(asset_id));
-            AssetCount::<T>::mutate((collec_id, owner_id), |count| *count += 1);
+            let asset = (asset_collec_id, asset_id);
+            Self::ensure_same_holder(&(collec_id, owner_id), &asset)?;
+            let count = Self::give_asset(&(collec_id, owner_id), &asset, inventory_hint)?;
 
             Self::deposit_event(Event::OwnershipAdded {
                 owner: (collec_id, owner_id),
-                asset: asset_id,
+                asset,
                 who,
             });
 
-            Ok(())
+            Ok(Some(<T as pallet::Config>::WeightInfo::create_ownership(count as u32)).into())
         }
 
         /// Operations on the ownership relationship in Telurean Chain are handled separately, 
         /// with unset_ownership being the symmetric function to set_ownership.
+        ///
+        /// `inventory_hint` is an upper bound on the number of assets the owner has. The call is
+        /// charged for that many assets upfront and refunded for those it did not go through.
         #[pallet::call_index(2)]
-        #[pallet::weight(<T as pallet::Config>::WeightInfo::remove_ownership())]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::remove_ownership(
+            (*inventory_hint).clamp(1, T::MaxInventorySize::get())
+        ))]
         pub fn unset_ownership(
             origin: OriginFor<T>,
             collec_id: T::CollectionId,
             owner_id: T::ItemId,
+            asset_collec_id: T::CollectionId,
             asset_id: T::ItemId,
-        ) -> DispatchResult {
+            inventory_hint: u32,
+        ) -> DispatchResultWithPostInfo {
 
             let who = ensure_signed(origin)?;
+            Self::ensure_can_manage(&who, &collec_id, &owner_id, |scope| scope.ownership)?;
+
+            let asset = (asset_collec_id, asset_id);
+            ensure!(!Loans::<T>::contains_key(asset), Error::<T>::AssetOnLoan);
 
             // Search for the ownership relationship.
             let count = AssetCount::<T>::get((collec_id, owner_id));
-            let mut found_index = None;
-            for index in 0..count {
-                if OwnerAssets::<T>::get((collec_id, owner_id, index)).is_some() {
-                    found_index = Some(index);
-                    break;
-                }
-            }
-            let index = found_index.ok_or(Error::<T>::OwnershipNotFound)?;
-
-            // Move the last relationship to the deleted index.
-            let last_index = count - 1;
-            if index < last_index {
-                let last_child = OwnerAssets::<T>::take((collec_id, owner_id, last_index));
-                OwnerAssets::<T>::insert((collec_id, owner_id, index), last_child);
-            }
-            AssetCount::<T>::mutate((collec_id, owner_id), |count| *count -= 1);
+            ensure!(count <= inventory_hint as u128, Error::<T>::InventoryHintTooLow);
+            let index = Self::remove_asset(&(collec_id, owner_id), &asset, count)
+                .ok_or(Error::<T>::OwnershipNotFound)?;
 
             Self::deposit_event(Event::OwnershipRemoved {
                 owner: (collec_id, owner_id),
-                asset: asset_id,
+                asset,
                 who,
             });
 
-            Ok(())
+            // The search stopped at the relationship found.
+            Ok(Some(<T as pallet::Config>::WeightInfo::remove_ownership(index as u32 + 1)).into())
         }
 
         /// Retrieve all NFTs that belong to another NFT through an ownership relationship. You must
@@ -269,14 +728,14 @@ pub mod pallet {
         /// 20 properties on the Valencia coast (4..23), and you want to retrieve the last 5 properties,
         /// start = 19 and num_assets = 5.
         #[pallet::call_index(3)]
-        #[pallet::weight(<T as pallet::Config>::WeightInfo::get_owned_assets())]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::get_owned_assets(*num_assets))]
         pub fn get_owned_assets(
             origin: OriginFor<T>,
             collec_id: T::CollectionId,
             owner_id: T::ItemId,
             start: u128,
             num_assets: u32,
-        ) -> DispatchResult {
+        ) -> DispatchResultWithPostInfo {
 
             let _ = ensure_signed(origin)?;
 
@@ -306,7 +765,1167 @@ pub mod pallet {
                 assets: owned_nfts,
             });
 
+            // Only the assets within the inventory were read.
+            let reads = end.saturating_sub(start) as u32;
+            Ok(Some(<T as pallet::Config>::WeightInfo::get_owned_assets(reads)).into())
+        }
+
+        /// Register several assets of a collection at once, as register_asset does for each of
+        /// them. Either all of them are registered or none is.
+        #[pallet::call_index(4)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::register_nft_batch(assets.len() as u32))]
+        pub fn register_assets_batch(
+            origin: OriginFor<T>,
+            collec_id: T::CollectionId,
+            assets: BoundedVec<(T::ItemId, Tags<T>), T::MaxBatchSize>,
+        ) -> DispatchResult {
+
+            let who = ensure_signed(origin)?;
+
+            for (asset_id, tags) in assets {
+                Self::ensure_can_retag(&who, &collec_id, &asset_id)?;
+
+                NftInfos::<T>::insert(collec_id, asset_id, NftInfo {
+                    collec_id: Some(collec_id),
+                    owner_id: None,
+                    tags
+                });
+
+                Self::deposit_event(Event::NftRegistered {
+                    who: who.clone(),
+                    collection: collec_id,
+                    asset: asset_id,
+                });
+            }
+
+            Ok(())
+        }
+
+        /// Give several assets to the same owner at once, such as the whole hoard of a dragon.
+        /// Either all of them are given or none is: the call fails if the owner already owns any
+        /// of them or if the batch repeats an asset.
+        ///
+        /// As in set_ownership, `inventory_hint` is an upper bound on the number of assets the
+        /// owner already has.
+        #[pallet::call_index(5)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::create_ownership_batch(
+            (*inventory_hint).min(T::MaxInventorySize::get()),
+            assets.len() as u32,
+        ))]
+        pub fn set_ownership_batch(
+            origin: OriginFor<T>,
+            collec_id: T::CollectionId,
+            owner_id: T::ItemId,
+            assets: BoundedVec<(T::CollectionId, T::ItemId), T::MaxBatchSize>,
+            inventory_hint: u32,
+        ) -> DispatchResultWithPostInfo {
+
+            let who = ensure_signed(origin)?;
+            Self::ensure_can_manage(&who, &collec_id, &owner_id, |scope| scope.ownership)?;
+
+            ensure!(
+                NftInfos::<T>::get(collec_id, owner_id).is_type("owner"),
+                Error::<T>::WrongNft
+            );
+
+            let count = AssetCount::<T>::get((collec_id, owner_id));
+            ensure!(count <= inventory_hint as u128, Error::<T>::InventoryHintTooLow);
+            ensure!(
+                Self::has_room(&(collec_id, owner_id), count, assets.len() as u32),
+                Error::<T>::ExceededMaxInventorySize
+            );
+
+            // The inventory is read once, and every asset of the batch is checked against it.
+            let mut owned: Vec<Option<(T::CollectionId, T::ItemId)>> = (0..count)
+                .map(|index| OwnerAssets::<T>::get((collec_id, owner_id, index)))
+                .collect();
+            for asset in assets.iter() {
+                Self::ensure_registered(asset)?;
+                ensure!(!Loans::<T>::contains_key(asset), Error::<T>::AssetOnLoan);
+                Self::ensure_same_holder(&(collec_id, owner_id), asset)?;
+                ensure!(!owned.contains(&Some(*asset)), Error::<T>::AlreadyOwner);
+                owned.push(Some(*asset));
+            }
+
+            for (index, asset) in (count..).zip(assets.iter()) {
+                OwnerAssets::<T>::insert((collec_id, owner_id, index), Some(*asset));
+                Self::record_provenance(asset, &(collec_id, owner_id), CustodyChange::Acquired);
+
+                Self::deposit_event(Event::OwnershipAdded {
+                    owner: (collec_id, owner_id),
+                    asset: *asset,
+                    who: who.clone(),
+                });
+            }
+            AssetCount::<T>::insert((collec_id, owner_id), count + assets.len() as u128);
+
+            Ok(Some(<T as pallet::Config>::WeightInfo::create_ownership_batch(
+                count as u32,
+                assets.len() as u32,
+            ))
+            .into())
+        }
+
+        /// Take several assets away from the same owner at once, the symmetric function to
+        /// set_ownership_batch. Either all of them are taken away or none is.
+        ///
+        /// As in unset_ownership, `inventory_hint` is an upper bound on the number of assets the
+        /// owner has.
+        #[pallet::call_index(6)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::remove_ownership_batch(
+            (*inventory_hint).min(T::MaxInventorySize::get()),
+            assets.len() as u32,
+        ))]
+        pub fn unset_ownership_batch(
+            origin: OriginFor<T>,
+            collec_id: T::CollectionId,
+            owner_id: T::ItemId,
+            assets: BoundedVec<(T::CollectionId, T::ItemId), T::MaxBatchSize>,
+            inventory_hint: u32,
+        ) -> DispatchResultWithPostInfo {
+
+            let who = ensure_signed(origin)?;
+            Self::ensure_can_manage(&who, &collec_id, &owner_id, |scope| scope.ownership)?;
+
+            let count = AssetCount::<T>::get((collec_id, owner_id));
+            ensure!(count <= inventory_hint as u128, Error::<T>::InventoryHintTooLow);
+
+            // The inventory is read once and the relationships are removed from it in memory, each
+            // one replaced by the last relationship as unset_ownership does.
+            let stored: Vec<Option<(T::CollectionId, T::ItemId)>> = (0..count)
+                .map(|index| OwnerAssets::<T>::get((collec_id, owner_id, index)))
+                .collect();
+            let mut owned = stored.clone();
+            for asset in assets.iter() {
+                ensure!(!Loans::<T>::contains_key(asset), Error::<T>::AssetOnLoan);
+                let index = owned
+                    .iter()
+                    .position(|owned_asset| *owned_asset == Some(*asset))
+                    .ok_or(Error::<T>::OwnershipNotFound)?;
+                owned.swap_remove(index);
+            }
+
+            // Only the relationships that moved or disappeared are written back.
+            for (index, (before, after)) in stored.iter().zip(owned.iter()).enumerate() {
+                if before != after {
+                    OwnerAssets::<T>::insert((collec_id, owner_id, index as u128), *after);
+                }
+            }
+            for index in owned.len() as u128..count {
+                OwnerAssets::<T>::remove((collec_id, owner_id, index));
+            }
+            AssetCount::<T>::insert((collec_id, owner_id), owned.len() as u128);
+
+            for asset in assets.iter() {
+                Self::record_provenance(asset, &(collec_id, owner_id), CustodyChange::Released);
+                Self::deposit_event(Event::OwnershipRemoved {
+                    owner: (collec_id, owner_id),
+                    asset: *asset,
+                    who: who.clone(),
+                });
+            }
+
+            Ok(Some(<T as pallet::Config>::WeightInfo::remove_ownership_batch(
+                count as u32,
+                assets.len() as u32,
+            ))
+            .into())
+        }
+
+        /// Register an asset as an administrator, as register_asset does, to fix a wrong or
+        /// missing registration.
+        #[pallet::call_index(7)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::force_register(tags.len() as u32))]
+        pub fn force_register(
+            origin: OriginFor<T>,
+            collec_id: T::CollectionId,
+            asset_id: T::ItemId,
+            tags: Tags<T>,
+        ) -> DispatchResult {
+
+            T::ForceOrigin::ensure_origin(origin)?;
+
+            NftInfos::<T>::insert(collec_id, asset_id, NftInfo {
+                collec_id: Some(collec_id),
+                owner_id: None,
+                tags
+            });
+
+            Self::deposit_event(Event::NftForceRegistered {
+                collection: collec_id,
+                asset: asset_id,
+            });
+
             Ok(())
         }
+
+        /// Give an asset to an owner as an administrator. Unlike set_ownership, the owner does not
+        /// need to be of the owner type, but both NFTs must be registered, the asset cannot be on
+        /// loan, and the owner cannot own the same asset twice nor more assets than
+        /// MaxInventorySize.
+        #[pallet::call_index(8)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::force_set_ownership(
+            T::MaxInventorySize::get()
+        ))]
+        pub fn force_set_ownership(
+            origin: OriginFor<T>,
+            collec_id: T::CollectionId,
+            owner_id: T::ItemId,
+            asset_collec_id: T::CollectionId,
+            asset_id: T::ItemId,
+        ) -> DispatchResultWithPostInfo {
+
+            T::ForceOrigin::ensure_origin(origin)?;
+
+            let asset = (asset_collec_id, asset_id);
+            Self::ensure_registered(&(collec_id, owner_id))?;
+            Self::ensure_registered(&asset)?;
+            ensure!(!Loans::<T>::contains_key(asset), Error::<T>::AssetOnLoan);
+            let count = AssetCount::<T>::get((collec_id, owner_id));
+            ensure!(
+                Self::has_room(&(collec_id, owner_id), count, 1),
+                Error::<T>::ExceededMaxInventorySize
+            );
+            let found_asset = (0..count)
+                .any(|index| OwnerAssets::<T>::get((collec_id, owner_id, index)) == Some(asset));
+            ensure!(!found_asset, Error::<T>::AlreadyOwner);
+
+            OwnerAssets::<T>::insert((collec_id, owner_id, count), Some(asset));
+            AssetCount::<T>::insert((collec_id, owner_id), count + 1);
+            Self::record_provenance(&asset, &(collec_id, owner_id), CustodyChange::Acquired);
+
+            Self::deposit_event(Event::OwnershipForceAdded {
+                owner: (collec_id, owner_id),
+                asset,
+            });
+
+            Ok(Some(<T as pallet::Config>::WeightInfo::force_set_ownership(count as u32)).into())
+        }
+
+        /// Take an asset away from its owner as an administrator. The relationship is searched
+        /// by the asset itself, so this also fixes inventories where unset_ownership removed the
+        /// wrong asset. Assets on loan are left to the return of their loan.
+        #[pallet::call_index(9)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::force_unset_ownership(
+            T::MaxInventorySize::get()
+        ))]
+        pub fn force_unset_ownership(
+            origin: OriginFor<T>,
+            collec_id: T::CollectionId,
+            owner_id: T::ItemId,
+            asset_collec_id: T::CollectionId,
+            asset_id: T::ItemId,
+        ) -> DispatchResultWithPostInfo {
+
+            T::ForceOrigin::ensure_origin(origin)?;
+
+            let asset = (asset_collec_id, asset_id);
+            ensure!(!Loans::<T>::contains_key(asset), Error::<T>::AssetOnLoan);
+            let count = AssetCount::<T>::get((collec_id, owner_id));
+            let index = (0..count)
+                .find(|index| OwnerAssets::<T>::get((collec_id, owner_id, *index)) == Some(asset))
+                .ok_or(Error::<T>::OwnershipNotFound)?;
+
+            // Move the last relationship to the deleted index.
+            let last_index = count - 1;
+            let last_child = OwnerAssets::<T>::take((collec_id, owner_id, last_index));
+            if index < last_index {
+                OwnerAssets::<T>::insert((collec_id, owner_id, index), last_child);
+            }
+            AssetCount::<T>::insert((collec_id, owner_id), last_index);
+            Self::record_provenance(&asset, &(collec_id, owner_id), CustodyChange::Released);
+
+            Self::deposit_event(Event::OwnershipForceRemoved {
+                owner: (collec_id, owner_id),
+                asset,
+            });
+
+            // The search stopped at the relationship found.
+            Ok(Some(<T as pallet::Config>::WeightInfo::force_unset_ownership(index as u32 + 1))
+                .into())
+        }
+
+        /// Remove the registration of an asset as an administrator, together with the assets it
+        /// owned, which are left without an owner. The asset must not be owned by another NFT,
+        /// nor have assets on loan, lent or borrowed.
+        #[pallet::call_index(10)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::force_unregister(
+            T::MaxInventorySize::get()
+        ))]
+        pub fn force_unregister(
+            origin: OriginFor<T>,
+            collec_id: T::CollectionId,
+            asset_id: T::ItemId,
+        ) -> DispatchResultWithPostInfo {
+
+            T::ForceOrigin::ensure_origin(origin)?;
+
+            let owner = (collec_id, asset_id);
+            Self::ensure_registered(&owner)?;
+            ensure!(OwnerCount::<T>::get(owner) == 0, Error::<T>::StillOwned);
+            ensure!(LentCount::<T>::get(owner) == 0, Error::<T>::AssetOnLoan);
+            let count = AssetCount::<T>::get(owner);
+            let assets: Vec<_> = (0..count)
+                .filter_map(|index| OwnerAssets::<T>::get((collec_id, asset_id, index)))
+                .collect();
+            ensure!(
+                !assets.iter().any(Loans::<T>::contains_key),
+                Error::<T>::AssetOnLoan
+            );
+
+            NftInfos::<T>::remove(collec_id, asset_id);
+            AssetCount::<T>::remove(owner);
+            // The assets left without an owner are released in their provenance.
+            for asset in assets {
+                Self::record_provenance(&asset, &owner, CustodyChange::Released);
+            }
+            let _ = OwnerAssets::<T>::clear_prefix(
+                (collec_id, asset_id),
+                count.min(T::MaxInventorySize::get() as u128) as u32,
+                None,
+            );
+
+            Self::deposit_event(Event::NftForceUnregistered {
+                collection: collec_id,
+                asset: asset_id,
+            });
+
+            Ok(Some(<T as pallet::Config>::WeightInfo::force_unregister(count as u32)).into())
+        }
+
+        /// Approve `operator` to manage the relationships of an NFT within `scope`, on behalf of
+        /// its holder, until the block `expiry` if any. Approving an operator again replaces its
+        /// scope and expiry. The approval lapses if the NFT changes hands.
+        #[pallet::call_index(11)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::approve_operator())]
+        pub fn approve_operator(
+            origin: OriginFor<T>,
+            collec_id: T::CollectionId,
+            nft_id: T::ItemId,
+            operator: T::AccountId,
+            scope: OperatorScope,
+            expiry: Option<BlockNumberFor<T>>,
+        ) -> DispatchResult {
+
+            let who = ensure_signed(origin)?;
+            ensure!(
+                T::Nfts::owner(&collec_id, &nft_id) == Some(who.clone()),
+                Error::<T>::NotOwner
+            );
+            if let Some(expiry) = expiry {
+                ensure!(
+                    expiry > frame_system::Pallet::<T>::block_number(),
+                    Error::<T>::ExpiryInThePast
+                );
+            }
+
+            Operators::<T>::insert(
+                (collec_id, nft_id, operator.clone()),
+                Approval { approver: who, scope, expiry },
+            );
+
+            Self::deposit_event(Event::OperatorApproved {
+                nft: (collec_id, nft_id),
+                operator,
+                scope,
+                expiry,
+            });
+
+            Ok(())
+        }
+
+        /// Revoke the approval of `operator` to manage the relationships of an NFT. Only the
+        /// holder of the NFT can revoke it.
+        #[pallet::call_index(12)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::revoke_operator())]
+        pub fn revoke_operator(
+            origin: OriginFor<T>,
+            collec_id: T::CollectionId,
+            nft_id: T::ItemId,
+            operator: T::AccountId,
+        ) -> DispatchResult {
+
+            let who = ensure_signed(origin)?;
+            ensure!(
+                T::Nfts::owner(&collec_id, &nft_id) == Some(who),
+                Error::<T>::NotOwner
+            );
+            ensure!(
+                Operators::<T>::take((collec_id, nft_id, operator.clone())).is_some(),
+                Error::<T>::OperatorNotFound
+            );
+
+            Self::deposit_event(Event::OperatorRevoked {
+                nft: (collec_id, nft_id),
+                operator,
+            });
+
+            Ok(())
+        }
+
+        /// Appoint the NFT `(agent_collec_id, agent_id)` as an agent of the NFT
+        /// `(collec_id, principal_id)`, so that whoever holds the agent manages the relationships
+        /// of the principal within `scope`. Only the holder of the principal can appoint agents,
+        /// and appointing an agent again replaces its scope.
+        #[pallet::call_index(13)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::appoint_agent())]
+        pub fn appoint_agent(
+            origin: OriginFor<T>,
+            collec_id: T::CollectionId,
+            principal_id: T::ItemId,
+            agent_collec_id: T::CollectionId,
+            agent_id: T::ItemId,
+            scope: OperatorScope,
+        ) -> DispatchResult {
+
+            let who = ensure_signed(origin)?;
+            ensure!(
+                T::Nfts::owner(&collec_id, &principal_id) == Some(who),
+                Error::<T>::NotOwner
+            );
+            ensure!(
+                (collec_id, principal_id) != (agent_collec_id, agent_id),
+                Error::<T>::WrongNft
+            );
+            ensure!(
+                T::Nfts::owner(&agent_collec_id, &agent_id).is_some(),
+                Error::<T>::TokenNotFound
+            );
+
+            let key = (collec_id, principal_id, agent_collec_id, agent_id);
+            if !Agents::<T>::contains_key(key) {
+                AgentCount::<T>::try_mutate((collec_id, principal_id), |count| {
+                    ensure!(*count < T::MaxAgents::get(), Error::<T>::ExceededMaxAgents);
+                    *count += 1;
+                    Ok::<_, Error<T>>(())
+                })?;
+            }
+            Agents::<T>::insert(key, scope);
+
+            Self::deposit_event(Event::AgentAppointed {
+                principal: (collec_id, principal_id),
+                agent: (agent_collec_id, agent_id),
+                scope,
+            });
+
+            Ok(())
+        }
+
+        /// Dismiss an agent of the NFT `(collec_id, principal_id)`. Either the holder of the
+        /// principal dismisses it, or the holder of the agent resigns.
+        #[pallet::call_index(14)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::dismiss_agent())]
+        pub fn dismiss_agent(
+            origin: OriginFor<T>,
+            collec_id: T::CollectionId,
+            principal_id: T::ItemId,
+            agent_collec_id: T::CollectionId,
+            agent_id: T::ItemId,
+        ) -> DispatchResult {
+
+            let who = ensure_signed(origin)?;
+            let holder = Some(who);
+            ensure!(
+                T::Nfts::owner(&collec_id, &principal_id) == holder
+                    || T::Nfts::owner(&agent_collec_id, &agent_id) == holder,
+                Error::<T>::NotOwner
+            );
+            ensure!(
+                Agents::<T>::take((collec_id, principal_id, agent_collec_id, agent_id)).is_some(),
+                Error::<T>::AgentNotFound
+            );
+            AgentCount::<T>::mutate((collec_id, principal_id), |count| {
+                *count = count.saturating_sub(1)
+            });
+
+            Self::deposit_event(Event::AgentDismissed {
+                principal: (collec_id, principal_id),
+                agent: (agent_collec_id, agent_id),
+            });
+
+            Ok(())
+        }
+
+        /// Offer to lend an asset of the owner `(collec_id, owner_id)` to the owner
+        /// `(to_collec_id, to_owner_id)` until the block `until`, when it returns to the lender.
+        /// The asset only moves when the side of the borrower accepts the offer with accept_loan.
+        #[pallet::call_index(15)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::lend())]
+        #[allow(clippy::too_many_arguments)]
+        pub fn lend(
+            origin: OriginFor<T>,
+            collec_id: T::CollectionId,
+            owner_id: T::ItemId,
+            asset_collec_id: T::CollectionId,
+            asset_id: T::ItemId,
+            to_collec_id: T::CollectionId,
+            to_owner_id: T::ItemId,
+            until: BlockNumberFor<T>,
+        ) -> DispatchResult {
+
+            let who = ensure_signed(origin)?;
+            Self::ensure_can_manage(&who, &collec_id, &owner_id, |scope| scope.ownership)?;
+
+            let now = frame_system::Pallet::<T>::block_number();
+            ensure!(until > now, Error::<T>::ExpiryInThePast);
+            let (lender, borrower) = ((collec_id, owner_id), (to_collec_id, to_owner_id));
+            ensure!(
+                lender != borrower && NftInfos::<T>::get(to_collec_id, to_owner_id).is_type("owner"),
+                Error::<T>::WrongNft
+            );
+            let asset = (asset_collec_id, asset_id);
+            ensure!(!Loans::<T>::contains_key(asset), Error::<T>::AssetOnLoan);
+            ensure!(!LoanOffers::<T>::contains_key(asset), Error::<T>::AlreadyExists);
+
+            LoanOffers::<T>::insert(asset, Loan { lender, borrower, until });
+
+            Self::deposit_event(Event::LoanOffered { lender, borrower, asset, until });
+
+            Ok(())
+        }
+
+        /// Accept the offer of a loan to an owner managed by the caller. The asset leaves the
+        /// inventory of the lender for that of the borrower, who may return it earlier, but can
+        /// neither give it away nor lend it again.
+        #[pallet::call_index(23)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::accept_loan(
+            T::MaxInventorySize::get()
+        ))]
+        pub fn accept_loan(
+            origin: OriginFor<T>,
+            asset_collec_id: T::CollectionId,
+            asset_id: T::ItemId,
+        ) -> DispatchResultWithPostInfo {
+
+            let who = ensure_signed(origin)?;
+            let asset = (asset_collec_id, asset_id);
+            let Loan { lender, borrower, until } =
+                LoanOffers::<T>::get(asset).ok_or(Error::<T>::LoanNotFound)?;
+            let (to_collec_id, to_owner_id) = borrower;
+            Self::ensure_can_manage(&who, &to_collec_id, &to_owner_id, |scope| scope.ownership)?;
+
+            let now = frame_system::Pallet::<T>::block_number();
+            ensure!(now < until, Error::<T>::ProposalExpired);
+            ensure!(!Loans::<T>::contains_key(asset), Error::<T>::AssetOnLoan);
+
+            // The borrower must have room for the asset, and not own it yet.
+            let borrower_count = AssetCount::<T>::get(borrower);
+            ensure!(
+                Self::has_room(&borrower, borrower_count, 1),
+                Error::<T>::ExceededMaxInventorySize
+            );
+            let found_asset = (0..borrower_count)
+                .any(|index| OwnerAssets::<T>::get((to_collec_id, to_owner_id, index)) == Some(asset));
+            ensure!(!found_asset, Error::<T>::AlreadyOwner);
+
+            let mut expiring = LoanExpiries::<T>::get(until);
+            expiring.try_push(asset).map_err(|_| Error::<T>::ExceededMaxLoansPerBlock)?;
+            let index = Self::remove_asset(&lender, &asset, AssetCount::<T>::get(lender))
+                .ok_or(Error::<T>::OwnershipNotFound)?;
+            LoanOffers::<T>::remove(asset);
+            LoanExpiries::<T>::insert(until, expiring);
+            OwnerAssets::<T>::insert((to_collec_id, to_owner_id, borrower_count), Some(asset));
+            AssetCount::<T>::insert(borrower, borrower_count + 1);
+            Self::record_provenance(&asset, &borrower, CustodyChange::Acquired);
+            LentCount::<T>::mutate(lender, |count| *count += 1);
+            Loans::<T>::insert(asset, Loan { lender, borrower, until });
+            NextLoanSweep::<T>::mutate(|next| {
+                if next.is_zero() {
+                    *next = now;
+                }
+            });
+
+            Self::deposit_event(Event::AssetLent { lender, borrower, asset, until });
+
+            let searched = (index + 1).max(borrower_count) as u32;
+            Ok(Some(<T as pallet::Config>::WeightInfo::accept_loan(searched)).into())
+        }
+
+        /// Cancel the offer of a loan, from the side of either the lender or the borrower,
+        /// whether it expired or not.
+        #[pallet::call_index(24)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::cancel_loan_offer())]
+        pub fn cancel_loan_offer(
+            origin: OriginFor<T>,
+            asset_collec_id: T::CollectionId,
+            asset_id: T::ItemId,
+        ) -> DispatchResult {
+
+            let who = ensure_signed(origin)?;
+            let asset = (asset_collec_id, asset_id);
+            let Loan { lender, borrower, .. } =
+                LoanOffers::<T>::get(asset).ok_or(Error::<T>::LoanNotFound)?;
+            Self::ensure_can_manage_side(&who, &lender, &borrower)?;
+            LoanOffers::<T>::remove(asset);
+
+            Self::deposit_event(Event::LoanOfferCancelled { lender, borrower, asset, who });
+
+            Ok(())
+        }
+
+        /// Return a borrowed asset to its lender before the loan expires.
+        #[pallet::call_index(16)]
+        // Charged for a full inventory of the borrower and of the lender, who kept room for it.
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::return_loan(
+            2 * T::MaxInventorySize::get() - 1
+        ))]
+        pub fn return_loan(
+            origin: OriginFor<T>,
+            asset_collec_id: T::CollectionId,
+            asset_id: T::ItemId,
+        ) -> DispatchResultWithPostInfo {
+
+            let who = ensure_signed(origin)?;
+            let asset = (asset_collec_id, asset_id);
+            let loan = Loans::<T>::get(asset).ok_or(Error::<T>::LoanNotFound)?;
+            let (borrower_collec_id, borrower_id) = loan.borrower;
+            Self::ensure_can_manage(&who, &borrower_collec_id, &borrower_id, |scope| {
+                scope.ownership
+            })?;
+
+            LoanExpiries::<T>::mutate(loan.until, |expiring| {
+                expiring.retain(|expiring_asset| *expiring_asset != asset)
+            });
+            let searched = Self::end_loan(&asset);
+
+            Ok(Some(<T as pallet::Config>::WeightInfo::return_loan(searched)).into())
+        }
+
+        /// Propose that the NFT `(collec_id, owner_id)` owns the NFT `(asset_collec_id, asset_id)`
+        /// when they are held by different accounts. Either side proposes, and the other one
+        /// accepts or rejects the proposal until the block `expiry`.
+        #[pallet::call_index(17)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::propose_relationship())]
+        pub fn propose_relationship(
+            origin: OriginFor<T>,
+            collec_id: T::CollectionId,
+            owner_id: T::ItemId,
+            asset_collec_id: T::CollectionId,
+            asset_id: T::ItemId,
+            expiry: BlockNumberFor<T>,
+        ) -> DispatchResult {
+
+            let who = ensure_signed(origin)?;
+            let (owner, asset) = ((collec_id, owner_id), (asset_collec_id, asset_id));
+            let by_owner = Self::ensure_can_manage_side(&who, &owner, &asset)?;
+
+            ensure!(
+                NftInfos::<T>::get(collec_id, owner_id).is_type("owner"),
+                Error::<T>::WrongNft
+            );
+            Self::ensure_registered(&asset)?;
+            ensure!(
+                expiry > frame_system::Pallet::<T>::block_number(),
+                Error::<T>::ExpiryInThePast
+            );
+            let key = (collec_id, owner_id, asset_collec_id, asset_id);
+            ensure!(!Proposals::<T>::contains_key(key), Error::<T>::AlreadyExists);
+
+            Proposals::<T>::insert(
+                key,
+                RelationshipProposal { proposer: who.clone(), by_owner, expiry },
+            );
+
+            Self::deposit_event(Event::RelationshipProposed { owner, asset, proposer: who, expiry });
+
+            Ok(())
+        }
+
+        /// Accept a proposal from the other side, which creates the ownership relationship as
+        /// set_ownership does, with `inventory_hint` as an upper bound on the number of assets the
+        /// owner already has.
+        #[pallet::call_index(18)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::accept_relationship(
+            (*inventory_hint).min(T::MaxInventorySize::get())
+        ))]
+        pub fn accept_relationship(
+            origin: OriginFor<T>,
+            collec_id: T::CollectionId,
+            owner_id: T::ItemId,
+            asset_collec_id: T::CollectionId,
+            asset_id: T::ItemId,
+            inventory_hint: u32,
+        ) -> DispatchResultWithPostInfo {
+
+            let who = ensure_signed(origin)?;
+            let (owner, asset) = ((collec_id, owner_id), (asset_collec_id, asset_id));
+            let key = (collec_id, owner_id, asset_collec_id, asset_id);
+            let proposal = Proposals::<T>::get(key).ok_or(Error::<T>::ProposalNotFound)?;
+            Self::ensure_can_manage_other_side(&who, &proposal, &owner, &asset)?;
+            ensure!(
+                frame_system::Pallet::<T>::block_number() < proposal.expiry,
+                Error::<T>::ProposalExpired
+            );
+
+            let count = Self::give_asset(&owner, &asset, inventory_hint)?;
+            Proposals::<T>::remove(key);
+
+            Self::deposit_event(Event::RelationshipAccepted { owner, asset, who: who.clone() });
+            Self::deposit_event(Event::OwnershipAdded { owner, asset, who });
+
+            Ok(Some(<T as pallet::Config>::WeightInfo::accept_relationship(count as u32)).into())
+        }
+
+        /// Reject a proposal from the other side. Proposals can be rejected after they expire.
+        #[pallet::call_index(19)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::reject_relationship())]
+        pub fn reject_relationship(
+            origin: OriginFor<T>,
+            collec_id: T::CollectionId,
+            owner_id: T::ItemId,
+            asset_collec_id: T::CollectionId,
+            asset_id: T::ItemId,
+        ) -> DispatchResult {
+
+            let who = ensure_signed(origin)?;
+            let (owner, asset) = ((collec_id, owner_id), (asset_collec_id, asset_id));
+            let key = (collec_id, owner_id, asset_collec_id, asset_id);
+            let proposal = Proposals::<T>::get(key).ok_or(Error::<T>::ProposalNotFound)?;
+            Self::ensure_can_manage_other_side(&who, &proposal, &owner, &asset)?;
+            Proposals::<T>::remove(key);
+
+            Self::deposit_event(Event::RelationshipRejected { owner, asset, who });
+
+            Ok(())
+        }
+
+        /// Cancel a proposal of the side of the caller, whether it expired or not.
+        #[pallet::call_index(20)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::cancel_relationship())]
+        pub fn cancel_relationship(
+            origin: OriginFor<T>,
+            collec_id: T::CollectionId,
+            owner_id: T::ItemId,
+            asset_collec_id: T::CollectionId,
+            asset_id: T::ItemId,
+        ) -> DispatchResult {
+
+            let who = ensure_signed(origin)?;
+            let (owner, asset) = ((collec_id, owner_id), (asset_collec_id, asset_id));
+            let key = (collec_id, owner_id, asset_collec_id, asset_id);
+            let proposal = Proposals::<T>::get(key).ok_or(Error::<T>::ProposalNotFound)?;
+            let (collection, item) = if proposal.by_owner { owner } else { asset };
+            Self::ensure_can_manage(&who, &collection, &item, |scope| scope.ownership)?;
+            Proposals::<T>::remove(key);
+
+            Self::deposit_event(Event::RelationshipCancelled { owner, asset });
+
+            Ok(())
+        }
+
+        /// Dispatch `call` from the token-bound account of the NFT, which only its current holder
+        /// controls. The funds and items of that account go with the NFT when it is transferred.
+        #[pallet::call_index(21)]
+        #[pallet::weight({
+            let dispatch_info = call.get_dispatch_info();
+            (
+                <T as pallet::Config>::WeightInfo::dispatch_as_nft()
+                    .saturating_add(dispatch_info.call_weight),
+                dispatch_info.class,
+            )
+        })]
+        pub fn dispatch_as_nft(
+            origin: OriginFor<T>,
+            collec_id: T::CollectionId,
+            item_id: T::ItemId,
+            call: Box<<T as Config>::RuntimeCall>,
+        ) -> DispatchResultWithPostInfo {
+
+            let who = ensure_signed(origin)?;
+            ensure!(T::Nfts::owner(&collec_id, &item_id) == Some(who), Error::<T>::NotOwner);
+
+            let account = Self::nft_account(&collec_id, &item_id);
+            let info = call.get_dispatch_info();
+            let result = call.dispatch(frame_system::RawOrigin::Signed(account.clone()).into());
+
+            Self::deposit_event(Event::DispatchedAsNft {
+                nft: (collec_id, item_id),
+                account,
+                result: result.map(|_| ()).map_err(|error| error.error),
+            });
+
+            let weight = <T as pallet::Config>::WeightInfo::dispatch_as_nft()
+                .saturating_add(extract_actual_weight(&result, &info));
+            Ok(Some(weight).into())
+        }
+
+        /// Prune up to `max_entries` of the oldest entries of the provenance of an asset, as long
+        /// as they are older than `ProvenanceRetention`. Anyone can prune them.
+        #[pallet::call_index(22)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::prune_provenance(
+            (*max_entries).min(T::MaxProvenance::get())
+        ))]
+        pub fn prune_provenance(
+            origin: OriginFor<T>,
+            asset_collec_id: T::CollectionId,
+            asset_id: T::ItemId,
+            max_entries: u32,
+        ) -> DispatchResultWithPostInfo {
+
+            let _ = ensure_signed(origin)?;
+
+            let asset = (asset_collec_id, asset_id);
+            let (first, next) = ProvenanceBounds::<T>::get(asset);
+            let now = frame_system::Pallet::<T>::block_number();
+            let retention = T::ProvenanceRetention::get();
+            let max_entries = max_entries.min(T::MaxProvenance::get());
+            let mut pruned = 0;
+            while pruned < max_entries && first.wrapping_add(pruned) != next {
+                let index = first.wrapping_add(pruned);
+                let expired = Provenance::<T>::get((asset_collec_id, asset_id, index))
+                    .is_none_or(|entry| entry.at.saturating_add(retention) <= now);
+                if !expired {
+                    break;
+                }
+                Provenance::<T>::remove((asset_collec_id, asset_id, index));
+                pruned += 1;
+            }
+            ensure!(pruned > 0, Error::<T>::NothingToPrune);
+            ProvenanceBounds::<T>::insert(asset, (first.wrapping_add(pruned), next));
+
+            Self::deposit_event(Event::ProvenancePruned { asset, entries: pruned });
+
+            Ok(Some(<T as pallet::Config>::WeightInfo::prune_provenance(pruned)).into())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Give the registered `asset` to `owner`, which must have room for it and not own it yet,
+        /// and return the number of assets the owner had. An asset on loan stays with its
+        /// borrower.
+        fn give_asset(
+            owner: &Nft<T>,
+            asset: &Nft<T>,
+            inventory_hint: u32,
+        ) -> Result<u128, DispatchError> {
+            let (collection, item) = *owner;
+            Self::ensure_registered(asset)?;
+            ensure!(!Loans::<T>::contains_key(asset), Error::<T>::AssetOnLoan);
+            let count = AssetCount::<T>::get(owner);
+            ensure!(count <= inventory_hint as u128, Error::<T>::InventoryHintTooLow);
+            ensure!(Self::has_room(owner, count, 1), Error::<T>::ExceededMaxInventorySize);
+            let found_asset =
+                (0..count).any(|index| OwnerAssets::<T>::get((collection, item, index)) == Some(*asset));
+            ensure!(!found_asset, Error::<T>::AlreadyOwner);
+
+            OwnerAssets::<T>::insert((collection, item, count), Some(*asset));
+            AssetCount::<T>::insert(owner, count + 1);
+            Self::record_provenance(asset, owner, CustodyChange::Acquired);
+            Ok(count)
+        }
+
+        /// Ensure that `nft` is registered, as every NFT in an ownership relationship must be.
+        fn ensure_registered(nft: &Nft<T>) -> DispatchResult {
+            ensure!(NftInfos::<T>::contains_key(nft.0, nft.1), Error::<T>::NotRegistered);
+            Ok(())
+        }
+
+        /// Ensure that `asset` is held by nobody or by the holder of `owner`. Relationships
+        /// between the NFTs of different accounts need the consent of both through a proposal.
+        fn ensure_same_holder(owner: &Nft<T>, asset: &Nft<T>) -> DispatchResult {
+            let asset_holder = T::Nfts::owner(&asset.0, &asset.1);
+            ensure!(
+                asset_holder.is_none() || asset_holder == T::Nfts::owner(&owner.0, &owner.1),
+                Error::<T>::ConsentRequired
+            );
+            Ok(())
+        }
+
+        /// Ensure that `who` manages the ownership relationships of `owner` or of `asset`, and
+        /// return whether it is the side of the owner.
+        fn ensure_can_manage_side(
+            who: &T::AccountId,
+            owner: &Nft<T>,
+            asset: &Nft<T>,
+        ) -> Result<bool, DispatchError> {
+            if Self::ensure_can_manage(who, &owner.0, &owner.1, |scope| scope.ownership).is_ok() {
+                return Ok(true);
+            }
+            Self::ensure_can_manage(who, &asset.0, &asset.1, |scope| scope.ownership)?;
+            Ok(false)
+        }
+
+        /// Ensure that `who` manages the side of a relationship that did not make `proposal`.
+        fn ensure_can_manage_other_side(
+            who: &T::AccountId,
+            proposal: &RelationshipProposal<T::AccountId, BlockNumberFor<T>>,
+            owner: &Nft<T>,
+            asset: &Nft<T>,
+        ) -> DispatchResult {
+            let (collection, item) = if proposal.by_owner { *asset } else { *owner };
+            Self::ensure_can_manage(who, &collection, &item, |scope| scope.ownership)
+        }
+
+        /// Append to the provenance of `asset` that `owner` acquired or released it, dropping the
+        /// oldest entry if the provenance is full, and count the inventories that hold it.
+        fn record_provenance(asset: &Nft<T>, owner: &Nft<T>, change: CustodyChange) {
+            let (collection, item) = *asset;
+            let (mut first, next) = ProvenanceBounds::<T>::get(asset);
+            if next.wrapping_sub(first) >= T::MaxProvenance::get() {
+                Provenance::<T>::remove((collection, item, first));
+                first = first.wrapping_add(1);
+            }
+            let at = frame_system::Pallet::<T>::block_number();
+            let entry = ProvenanceEntry { owner: *owner, change, at };
+            Provenance::<T>::insert((collection, item, next), entry);
+            ProvenanceBounds::<T>::insert(asset, (first, next.wrapping_add(1)));
+            OwnerCount::<T>::mutate_exists(asset, |count| {
+                let owners = count.unwrap_or_default();
+                let owners = match change {
+                    CustodyChange::Acquired => owners.saturating_add(1),
+                    CustodyChange::Released => owners.saturating_sub(1),
+                };
+                *count = (owners > 0).then_some(owners);
+            });
+        }
+
+        /// Up to `limit` entries of the provenance of the asset `(collection, item)`, with their
+        /// indexes, from the oldest one retained at or after the index `start`. The next page
+        /// starts after the index of the last entry of the previous one.
+        pub fn provenance(
+            collection: T::CollectionId,
+            item: T::ItemId,
+            start: u32,
+            limit: u32,
+        ) -> Vec<(u32, ProvenanceOf<T>)> {
+            let (first, next) = ProvenanceBounds::<T>::get((collection, item));
+            let start = start.max(first);
+            let limit = limit.min(T::MaxProvenance::get());
+            (start..next)
+                .take(limit as usize)
+                .filter_map(|index| {
+                    Provenance::<T>::get((collection, item, index)).map(|entry| (index, entry))
+                })
+                .collect()
+        }
+
+        /// Whether `owner`, which owns `count` assets, has room for `extra` more, keeping room for
+        /// the assets it has on loan.
+        fn has_room(owner: &Nft<T>, count: u128, extra: u32) -> bool {
+            count + (LentCount::<T>::get(owner) + extra) as u128
+                <= T::MaxInventorySize::get() as u128
+        }
+
+        /// Remove `asset` from the first `count` assets of `owner`, moving the last relationship
+        /// to its place, and return the index where it was found. The owner releases the asset in
+        /// its provenance.
+        fn remove_asset(owner: &Nft<T>, asset: &Nft<T>, count: u128) -> Option<u128> {
+            let (collection, item) = *owner;
+            let index = (0..count)
+                .find(|index| OwnerAssets::<T>::get((collection, item, *index)) == Some(*asset))?;
+            let last_index = count - 1;
+            let last_child = OwnerAssets::<T>::take((collection, item, last_index));
+            if index < last_index {
+                OwnerAssets::<T>::insert((collection, item, index), last_child);
+            }
+            AssetCount::<T>::insert(owner, last_index);
+            Self::record_provenance(asset, owner, CustodyChange::Released);
+            Some(index)
+        }
+
+        /// End the loan of `asset`, giving it back to its lender, and return the number of assets
+        /// of the borrower and of the lender searched. The asset is only removed from the borrower
+        /// if it is still there, and only given to the lender if it does not hold it already, so
+        /// that an inventory edited by hand never ends up holding it twice.
+        fn end_loan(asset: &Nft<T>) -> u32 {
+            let Some(loan) = Loans::<T>::take(asset) else { return 0 };
+            let borrower_count = AssetCount::<T>::get(loan.borrower);
+            let searched = match Self::remove_asset(&loan.borrower, asset, borrower_count) {
+                Some(index) => index + 1,
+                None => borrower_count,
+            };
+
+            // The lender kept room for the asset.
+            let (collection, item) = loan.lender;
+            let count = AssetCount::<T>::get(loan.lender);
+            let already_owner = (0..count)
+                .any(|index| OwnerAssets::<T>::get((collection, item, index)) == Some(*asset));
+            if !already_owner {
+                OwnerAssets::<T>::insert((collection, item, count), Some(*asset));
+                AssetCount::<T>::insert(loan.lender, count + 1);
+                Self::record_provenance(asset, &loan.lender, CustodyChange::Acquired);
+            }
+            LentCount::<T>::mutate(loan.lender, |count| *count = count.saturating_sub(1));
+
+            Self::deposit_event(Event::LoanReturned {
+                lender: loan.lender,
+                borrower: loan.borrower,
+                asset: *asset,
+            });
+            (searched + count) as u32
+        }
+
+        /// Return the assets whose loans expired by the block `now`, from the oldest expiry, within
+        /// `limit`. An expiry that does not fit is resumed later from the asset it stopped at.
+        pub(crate) fn sweep_loans(now: BlockNumberFor<T>, limit: Weight) -> Weight {
+            let mut meter = WeightMeter::with_limit(limit);
+            // Reading the next block to sweep, and writing it back.
+            if meter.try_consume(T::DbWeight::get().reads_writes(1, 1)).is_err() {
+                return meter.consumed();
+            }
+            let mut block = NextLoanSweep::<T>::get();
+            if block.is_zero() {
+                return meter.consumed();
+            }
+            // Taking the expiries of a block, and writing back those that did not fit.
+            let per_block = T::DbWeight::get().reads_writes(1, 1);
+            let per_loan =
+                <T as pallet::Config>::WeightInfo::return_loan(2 * T::MaxInventorySize::get() - 1);
+            while block <= now && meter.try_consume(per_block).is_ok() {
+                let mut expiring = LoanExpiries::<T>::take(block);
+                while let Some(asset) = expiring.last().copied() {
+                    if meter.try_consume(per_loan).is_err() {
+                        break;
+                    }
+                    expiring.pop();
+                    Self::end_loan(&asset);
+                }
+                if !expiring.is_empty() {
+                    LoanExpiries::<T>::insert(block, expiring);
+                    break;
+                }
+                block += One::one();
+            }
+            NextLoanSweep::<T>::put(block);
+            meter.consumed()
+        }
+
+        /// The token-bound account of the NFT `(collection, item)`, derived from a hash of its
+        /// identifiers so that it is unique for any size of account.
+        pub fn nft_account(collection: &T::CollectionId, item: &T::ItemId) -> T::AccountId {
+            let entropy = (T::PalletId::get(), *collection, *item).blake2_256();
+            Decode::decode(&mut TrailingZeroInput::new(entropy.as_ref()))
+                .expect("infinite length input; no invalid inputs for type; qed")
+        }
+
+        /// The agents of the NFT `(collection, principal)`, with their scopes.
+        pub fn agents_of(
+            collection: &T::CollectionId,
+            principal: &T::ItemId,
+        ) -> Vec<(Nft<T>, OperatorScope)> {
+            Agents::<T>::iter_prefix((*collection, *principal)).collect()
+        }
+
+        /// Ensure that `who` holds the NFT `(collection, item)`, is an operator approved by its
+        /// current holder for a scope that passes `in_scope` and has not expired, or holds one of
+        /// its agents whose scope passes `in_scope`.
+        pub(crate) fn ensure_can_manage(
+            who: &T::AccountId,
+            collection: &T::CollectionId,
+            item: &T::ItemId,
+            in_scope: impl Fn(&OperatorScope) -> bool,
+        ) -> DispatchResult {
+            let holder = T::Nfts::owner(collection, item);
+            if holder.as_ref() == Some(who) {
+                return Ok(());
+            }
+            ensure!(holder.is_some(), Error::<T>::NotOwner);
+            if let Some(approval) = Operators::<T>::get((*collection, *item, who.clone())) {
+                let now = frame_system::Pallet::<T>::block_number();
+                if holder == Some(approval.approver)
+                    && in_scope(&approval.scope)
+                    && approval.expiry.is_none_or(|expiry| now < expiry)
+                {
+                    return Ok(());
+                }
+            }
+            let holds_agent = Agents::<T>::iter_prefix((*collection, *item)).any(
+                |((agent_collection, agent), scope)| {
+                    in_scope(&scope)
+                        && T::Nfts::owner(&agent_collection, &agent).as_ref() == Some(who)
+                },
+            );
+            ensure!(holds_agent, Error::<T>::NotOwner);
+            Ok(())
+        }
+
+        /// Ensure that `asset` exists and that `who` can manage its tags, both to register it
+        /// and to change the tags of a registered asset.
+        fn ensure_can_retag(
+            who: &T::AccountId,
+            collection: &T::CollectionId,
+            asset: &T::ItemId,
+        ) -> DispatchResult {
+            ensure!(T::Nfts::owner(collection, asset).is_some(), Error::<T>::TokenNotFound);
+            Self::ensure_can_manage(who, collection, asset, |scope| scope.tags)
+        }
+
+        /// Check the invariants of the relationships: the inventory of each owner fills the slots
+        /// below its asset count and nothing else, without repeating an asset, every owner and
+        /// asset in an inventory is registered, the counters of owners, of agents and of assets on
+        /// loan match their entries, and no asset on loan is still offered.
+        #[cfg(any(feature = "try-runtime", test))]
+        pub fn do_try_state() -> Result<(), sp_runtime::TryRuntimeError> {
+            let mut owner_counts: Vec<(Nft<T>, u32)> = Vec::new();
+            for (owner, count) in AssetCount::<T>::iter() {
+                let (collection, item) = owner;
+                ensure!(Self::has_room(&owner, count, 0), "an inventory exceeds MaxInventorySize");
+                ensure!(NftInfos::<T>::contains_key(collection, item), "an owner is not registered");
+                let mut assets = Vec::new();
+                for index in 0..count {
+                    let asset = OwnerAssets::<T>::get((collection, item, index))
+                        .ok_or("an inventory has an empty slot below its asset count")?;
+                    ensure!(!assets.contains(&asset), "an inventory holds an asset twice");
+                    ensure!(
+                        NftInfos::<T>::contains_key(asset.0, asset.1),
+                        "an owned asset is not registered"
+                    );
+                    match owner_counts.iter_mut().find(|(owned, _)| *owned == asset) {
+                        Some((_, owners)) => *owners += 1,
+                        None => owner_counts.push((asset, 1)),
+                    }
+                    assets.push(asset);
+                }
+            }
+            for (collection, item, index) in OwnerAssets::<T>::iter_keys() {
+                ensure!(
+                    index < AssetCount::<T>::get((collection, item)),
+                    "an inventory has a slot beyond its asset count"
+                );
+            }
+            ensure!(
+                owner_counts.iter().all(|(asset, owners)| OwnerCount::<T>::get(asset) == *owners)
+                    && OwnerCount::<T>::iter().all(|entry| owner_counts.contains(&entry)),
+                "the owner count of an asset does not match the inventories holding it"
+            );
+
+            for (principal, count) in AgentCount::<T>::iter() {
+                ensure!(
+                    Agents::<T>::iter_key_prefix(principal).count() == count as usize,
+                    "the agent count of a principal does not match its agents"
+                );
+            }
+            for (collection, item, _, _) in Agents::<T>::iter_keys() {
+                ensure!(
+                    AgentCount::<T>::contains_key((collection, item)),
+                    "a principal has agents but no agent count"
+                );
+            }
+
+            for (lender, lent) in LentCount::<T>::iter() {
+                ensure!(
+                    Loans::<T>::iter_values().filter(|loan| loan.lender == lender).count()
+                        == lent as usize,
+                    "the lent count of an owner does not match its loans"
+                );
+            }
+            for loan in Loans::<T>::iter_values() {
+                ensure!(
+                    LentCount::<T>::contains_key(loan.lender),
+                    "an owner has assets on loan but no lent count"
+                );
+            }
+            for asset in LoanOffers::<T>::iter_keys() {
+                ensure!(!Loans::<T>::contains_key(asset), "an asset on loan is still offered");
+            }
+            Ok(())
+        }
+    }
+}
+
+/// Assets on loan are locked, so that the account holding their NFT cannot transfer them.
+impl<T: Config> frame_support::traits::Locker<T::CollectionId, T::ItemId> for Pallet<T> {
+    fn is_locked(collection: T::CollectionId, item: T::ItemId) -> bool {
+        Loans::<T>::contains_key((collection, item))
     }
 }
@@ -0,0 +1,81 @@
+//! Migration from version 0 to version 1 of the storage, which stores the full
+//! `(CollectionId, ItemId)` of the assets in `OwnerAssets` instead of only their `ItemId`.
+
+use crate::{Config, OwnerAssets, Pallet};
+use core::marker::PhantomData;
+use frame_support::{
+    migrations::VersionedMigration,
+    traits::{Get, UncheckedOnRuntimeUpgrade},
+    weights::Weight,
+};
+
+#[cfg(feature = "try-runtime")]
+use codec::{Decode, Encode};
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+#[cfg(feature = "try-runtime")]
+use sp_std::vec::Vec;
+
+/// The storage of version 0, for the migration and its tests.
+pub mod v0 {
+    use crate::{Config, Pallet};
+    use frame_support::{
+        pallet_prelude::{Twox64Concat, ValueQuery},
+        storage::types::Key,
+        storage_alias,
+    };
+
+    /// Ownership relationship of version 0, where assets shared the CollectionId of their owner.
+    #[storage_alias]
+    pub type OwnerAssets<T: Config> = StorageNMap<
+        Pallet<T>,
+        (
+            Key<Twox64Concat, <T as Config>::CollectionId>,
+            Key<Twox64Concat, <T as Config>::ItemId>,
+            Key<Twox64Concat, u128>,
+        ),
+        Option<<T as Config>::ItemId>,
+        ValueQuery,
+    >;
+}
+
+/// Give every asset the CollectionId of its owner, which version 0 assumed.
+pub struct InnerMigrateV0ToV1<T>(PhantomData<T>);
+
+impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV0ToV1<T> {
+    fn on_runtime_upgrade() -> Weight {
+        let mut translated = 0u64;
+        OwnerAssets::<T>::translate::<Option<T::ItemId>, _>(|(collection, _, _), asset| {
+            translated += 1;
+            Some(asset.map(|asset| (collection, asset)))
+        });
+        T::DbWeight::get().reads_writes(translated, translated)
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+        let count = v0::OwnerAssets::<T>::iter_keys().count() as u64;
+        Ok(count.encode())
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+        let count = u64::decode(&mut &state[..])
+            .map_err(|_| TryRuntimeError::Other("the state of the pre-upgrade is not a count"))?;
+        // Entries that do not decode with the new value are skipped, so they would not count.
+        frame_support::ensure!(
+            OwnerAssets::<T>::iter().count() as u64 == count,
+            "some relationships were not migrated"
+        );
+        Ok(())
+    }
+}
+
+/// The migration from version 0 to version 1, which only runs on a storage of version 0.
+pub type MigrateV0ToV1<T> = VersionedMigration<
+    0,
+    1,
+    InnerMigrateV0ToV1<T>,
+    Pallet<T>,
+    <T as frame_system::Config>::DbWeight,
+>;
@@ -0,0 +1,89 @@
+//! Migration from version 1 to version 2 of the storage, which keys `NftInfos` by the full
+//! `(CollectionId, ItemId)` of each NFT instead of only its `ItemId`, so that items of different
+//! collections with the same id no longer share their registration.
+
+use crate::{Config, NftInfos, Pallet};
+use core::marker::PhantomData;
+use frame_support::{
+    migrations::VersionedMigration,
+    traits::{Get, UncheckedOnRuntimeUpgrade},
+    weights::Weight,
+};
+use sp_std::vec::Vec;
+
+#[cfg(feature = "try-runtime")]
+use codec::{Decode, Encode};
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+
+/// The storage of version 1, for the migration and its tests.
+pub mod v1 {
+    use crate::{common::NftInfo, Config, Pallet};
+    use frame_support::{
+        pallet_prelude::{Blake2_128Concat, ValueQuery},
+        storage_alias,
+    };
+
+    /// Registrations of version 1, keyed by the ItemId of the NFT alone.
+    #[storage_alias]
+    pub type NftInfos<T: Config> = StorageMap<
+        Pallet<T>,
+        Blake2_128Concat,
+        <T as Config>::ItemId,
+        NftInfo<T>,
+        ValueQuery,
+    >;
+}
+
+/// Move every registration under the collection it records. Entries without a collection were
+/// never registered, and are dropped.
+pub struct InnerMigrateV1ToV2<T>(PhantomData<T>);
+
+impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV1ToV2<T> {
+    fn on_runtime_upgrade() -> Weight {
+        // The old and the new keys share the prefix of the storage, so the old entries are all
+        // taken out before the new ones are written.
+        let registrations: Vec<_> = v1::NftInfos::<T>::drain().collect();
+        let read = registrations.len() as u64;
+        let mut written = 0u64;
+        for (item, info) in registrations {
+            if let Some(collection) = info.collec_id {
+                NftInfos::<T>::insert(collection, item, info);
+                written += 1;
+            }
+        }
+        T::DbWeight::get().reads_writes(read, read.saturating_add(written))
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+        let count = v1::NftInfos::<T>::iter_values()
+            .filter(|info| info.collec_id.is_some())
+            .count() as u64;
+        Ok(count.encode())
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+        let count = u64::decode(&mut &state[..])
+            .map_err(|_| TryRuntimeError::Other("the state of the pre-upgrade is not a count"))?;
+        frame_support::ensure!(
+            NftInfos::<T>::iter().count() as u64 == count,
+            "some registrations were not migrated"
+        );
+        frame_support::ensure!(
+            NftInfos::<T>::iter().all(|(collection, _, info)| info.collec_id == Some(collection)),
+            "a registration is stored under another collection"
+        );
+        Ok(())
+    }
+}
+
+/// The migration from version 1 to version 2, which only runs on a storage of version 1.
+pub type MigrateV1ToV2<T> = VersionedMigration<
+    1,
+    2,
+    InnerMigrateV1ToV2<T>,
+    Pallet<T>,
+    <T as frame_system::Config>::DbWeight,
+>;
@@ -0,0 +1,102 @@
+//! Migration from version 2 to version 3 of the storage, which counts in `OwnerCount` the
+//! inventories holding each asset. Ownership now requires both NFTs to be registered, so the
+//! owners and assets of the existing inventories that never were are registered without tags.
+//! The slots at or past the asset count of their inventory are left over from removals that
+//! did not clear them: they are not part of the inventory, so they are removed instead.
+
+use crate::{common::NftInfo, AssetCount, Config, NftInfos, OwnerAssets, OwnerCount, Pallet};
+use core::marker::PhantomData;
+use frame_support::{
+    migrations::VersionedMigration,
+    traits::{Get, UncheckedOnRuntimeUpgrade},
+    weights::Weight,
+};
+use sp_std::vec::Vec;
+
+#[cfg(feature = "try-runtime")]
+use codec::{Decode, Encode};
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+
+/// Count the owners of every asset held in an inventory, register the NFTs of the inventories
+/// that are not, and remove the stale slots of the inventories.
+pub struct InnerMigrateV2ToV3<T>(PhantomData<T>);
+
+impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV2ToV3<T> {
+    fn on_runtime_upgrade() -> Weight {
+        let mut reads = 0u64;
+        let mut writes = 0u64;
+        let mut stale = Vec::new();
+        for ((collection, item, index), asset) in OwnerAssets::<T>::iter() {
+            // The slot and the asset count of its inventory.
+            reads += 2;
+            if index >= AssetCount::<T>::get((collection, item)) {
+                stale.push((collection, item, index));
+                continue;
+            }
+            let Some(asset) = asset else { continue };
+            for (collection, item) in [(collection, item), asset] {
+                reads += 1;
+                if !NftInfos::<T>::contains_key(collection, item) {
+                    NftInfos::<T>::insert(collection, item, NftInfo {
+                        collec_id: Some(collection),
+                        owner_id: None,
+                        tags: Default::default(),
+                    });
+                    writes += 1;
+                }
+            }
+            OwnerCount::<T>::mutate(asset, |owners| *owners += 1);
+            reads += 1;
+            writes += 1;
+        }
+        // The slots are removed once the iteration is over, as it must not change the map.
+        for slot in stale {
+            OwnerAssets::<T>::remove(slot);
+            writes += 1;
+        }
+        T::DbWeight::get().reads_writes(reads, writes)
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+        let held = OwnerAssets::<T>::iter()
+            .filter(|((collection, item, index), asset)| {
+                asset.is_some() && *index < AssetCount::<T>::get((*collection, *item))
+            })
+            .count() as u64;
+        Ok(held.encode())
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+        let held = u64::decode(&mut &state[..])
+            .map_err(|_| TryRuntimeError::Other("the state of the pre-upgrade is not a count"))?;
+        frame_support::ensure!(
+            OwnerCount::<T>::iter_values().map(u64::from).sum::<u64>() == held,
+            "the owner counts do not add up to the assets held"
+        );
+        frame_support::ensure!(
+            OwnerAssets::<T>::iter_keys()
+                .all(|(collection, item, index)| index < AssetCount::<T>::get((collection, item))),
+            "a slot past the asset count of its inventory is left"
+        );
+        frame_support::ensure!(
+            OwnerAssets::<T>::iter().all(|((collection, item, _), asset)| {
+                NftInfos::<T>::contains_key(collection, item)
+                    && asset.is_none_or(|(collection, item)| NftInfos::<T>::contains_key(collection, item))
+            }),
+            "an NFT of an inventory is not registered"
+        );
+        Ok(())
+    }
+}
+
+/// The migration from version 2 to version 3, which only runs on a storage of version 2.
+pub type MigrateV2ToV3<T> = VersionedMigration<
+    2,
+    3,
+    InnerMigrateV2ToV3<T>,
+    Pallet<T>,
+    <T as frame_system::Config>::DbWeight,
+>;
@@ -2,8 +2,10 @@ use crate as pallet_nft_hierarchy;
 use frame_support::{
     derive_impl, parameter_types,
     traits::{ConstU32, VariantCountOf},
+    BoundedVec, PalletId,
 };
 use frame_system::{EnsureRoot, EnsureSigned};
+use pallet_nft_hierarchy_uniques_adapter::UniquesAdapter;
 use sp_runtime::BuildStorage;
 
 type Block = frame_system::mocking::MockBlock<Test>;
@@ -87,7 +89,7 @@ impl pallet_uniques::Config for Test {
     type Currency = Balances;
     type ForceOrigin = EnsureRoot<Self::AccountId>;
     type CreateOrigin = EnsureSigned<Self::AccountId>;
-    type Locker = ();
+    type Locker = NftHierarchy;
     type CollectionDeposit = CollectionDeposit;
     type ItemDeposit = ItemDeposit;
     type MetadataDepositBase = MetadataDepositBase;
@@ -97,6 +99,8 @@ impl pallet_uniques::Config for Test {
     type KeyLimit = KeyLimit;
     type ValueLimit = ValueLimit;
     type WeightInfo = pallet_uniques::weights::SubstrateWeight<Test>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type Helper = ();
 }
 
 // pallet_nft_hierarchy
@@ -104,6 +108,13 @@ parameter_types! {
     pub const StringLimit: u32 = 128;
     pub const TypeLimit: u32 = 100;
     pub const MaxRelationshipsPerQuery: u32 = 10;
+    pub const MaxInventorySize: u32 = 20;
+    pub const MaxBatchSize: u32 = 5;
+    pub const MaxAgents: u32 = 3;
+    pub const MaxLoansPerBlock: u32 = 3;
+    pub const MaxProvenance: u32 = 4;
+    pub const ProvenanceRetention: u64 = 10;
+    pub const NftAccountsPalletId: PalletId = PalletId(*b"py/nftac");
 }
 
 impl pallet_nft_hierarchy::Config for Test {
@@ -113,11 +124,45 @@ impl pallet_nft_hierarchy::Config for Test {
     type StringLimit = StringLimit;
     type TypeLimit = TypeLimit;
     type MaxAssetsPerTransaction = MaxRelationshipsPerQuery;
+    type MaxInventorySize = MaxInventorySize;
+    type MaxBatchSize = MaxBatchSize;
+    type MaxAgents = MaxAgents;
+    type MaxLoansPerBlock = MaxLoansPerBlock;
+    type MaxProvenance = MaxProvenance;
+    type ProvenanceRetention = ProvenanceRetention;
+    type RuntimeCall = RuntimeCall;
+    type PalletId = NftAccountsPalletId;
+    type ForceOrigin = EnsureRoot<Self::AccountId>;
+    type Nfts = UniquesAdapter<Test>;
+    type NftCollectionConfig = ();
+    type NftItemConfig = ();
     type WeightInfo = pallet_nft_hierarchy::weights::SubstrateWeight<Test>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = BenchmarkHelper;
+}
+
+/// Mints the NFTs of the benchmarks in pallet_uniques, in collections owned by their first owner.
+#[cfg(feature = "runtime-benchmarks")]
+pub struct BenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_nft_hierarchy::BenchmarkHelper<u32, u128, u64> for BenchmarkHelper {
+    fn mint(collection: &u32, item: &u128, owner: &u64) {
+        if Uniques::collection_owner(*collection).is_none() {
+            Uniques::force_create(RuntimeOrigin::root(), *collection, *owner, true).unwrap();
+        }
+        let issuer = Uniques::collection_owner(*collection).unwrap();
+        Uniques::mint(RuntimeOrigin::signed(issuer), *collection, *item, *owner).unwrap();
+    }
 }
 
 // Build genesis storage according to the mock runtime.
-pub fn new_test_ext() -> sp_io::TestExternalities {
+pub fn new_test_ext() -> TestExt {
+    TestExt(new_unchecked_test_ext())
+}
+
+/// Build genesis storage as new_test_ext does, without checking the invariants of the pallet at
+/// the end, for the tests that break them on purpose.
+pub fn new_unchecked_test_ext() -> sp_io::TestExternalities {
     let mut storage = frame_system::GenesisConfig::<Test>::default()
         .build_storage()
         .unwrap();
@@ -134,3 +179,33 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
     ext.execute_with(|| System::set_block_number(1));
     ext
 }
+
+/// Test externalities that check the invariants of the pallet once the test is over.
+pub struct TestExt(sp_io::TestExternalities);
+
+impl TestExt {
+    pub fn execute_with<R>(&mut self, execute: impl FnOnce() -> R) -> R {
+        self.0.execute_with(|| {
+            let result = execute();
+            NftHierarchy::do_try_state().expect("the invariants of the pallet hold");
+            result
+        })
+    }
+}
+
+/// Mint the item `item` of the collection `collection` for `owner`, creating the collection if it
+/// does not exist yet. Collections are created by account 1, the only one with funds.
+pub fn mint(collection: u32, item: u128, owner: u64) {
+    if Uniques::collection_owner(collection).is_none() {
+        Uniques::create(RuntimeOrigin::signed(1), collection, 1).unwrap();
+    }
+    Uniques::mint(RuntimeOrigin::signed(1), collection, item, owner).unwrap();
+}
+
+/// Mint the item as mint does, and register it with the tag `tag` on behalf of `owner`, as the
+/// NFTs of an ownership relationship must be registered.
+pub fn mint_registered(collection: u32, item: u128, owner: u64, tag: &[u8]) {
+    mint(collection, item, owner);
+    let tags = BoundedVec::try_from(vec![BoundedVec::try_from(tag.to_vec()).unwrap()]).unwrap();
+    NftHierarchy::register_asset(RuntimeOrigin::signed(owner), collection, item, tags).unwrap();
+}
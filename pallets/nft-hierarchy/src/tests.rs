@@ -1,4 +1,29 @@
 pub mod nft_hierarchy__register_asset;
 pub mod nft_hierarchy__set_ownership;
 pub mod nft_hierarchy__unset_ownership;
-pub mod nft_hierarchy__get_owned_assets;
\ No newline at end of file
+pub mod nft_hierarchy__get_owned_assets;
+pub mod nft_hierarchy__register_assets_batch;
+pub mod nft_hierarchy__set_ownership_batch;
+pub mod nft_hierarchy__unset_ownership_batch;
+pub mod nft_hierarchy__migrations;
+pub mod nft_hierarchy__force_register;
+pub mod nft_hierarchy__force_set_ownership;
+pub mod nft_hierarchy__force_unset_ownership;
+pub mod nft_hierarchy__force_unregister;
+pub mod nft_hierarchy__approve_operator;
+pub mod nft_hierarchy__revoke_operator;
+pub mod nft_hierarchy__appoint_agent;
+pub mod nft_hierarchy__dismiss_agent;
+pub mod nft_hierarchy__lend;
+pub mod nft_hierarchy__accept_loan;
+pub mod nft_hierarchy__cancel_loan_offer;
+pub mod nft_hierarchy__return_loan;
+pub mod nft_hierarchy__propose_relationship;
+pub mod nft_hierarchy__accept_relationship;
+pub mod nft_hierarchy__reject_relationship;
+pub mod nft_hierarchy__cancel_relationship;
+pub mod nft_hierarchy__dispatch_as_nft;
+pub mod nft_hierarchy__prune_provenance;
+pub mod nft_hierarchy__genesis;
+pub mod nft_hierarchy__try_state;
+pub mod nft_hierarchy__operation_sequences;
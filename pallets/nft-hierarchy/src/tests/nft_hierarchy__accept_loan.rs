@@ -0,0 +1,126 @@
+#![allow(non_snake_case)]
+
+use super::super::*;
+use super::nft_hierarchy__lend::{two_characters_and_a_sword, ARMORY, HEROES, SWORD};
+use crate::mock::{new_test_ext, RuntimeOrigin, System, Test};
+use frame_support::{assert_noop, assert_ok, BoundedVec};
+
+/// Offer the sword of the character 1 to the character 2 until the block `until`.
+fn offered(until: u64) -> (u128, u128) {
+    let (lender, borrower) = two_characters_and_a_sword();
+    assert_ok!(Pallet::<Test>::lend(
+        RuntimeOrigin::signed(1),
+        HEROES,
+        lender,
+        ARMORY,
+        SWORD,
+        HEROES,
+        borrower,
+        until
+    ));
+    (lender, borrower)
+}
+
+#[test]
+fn works() {
+    new_test_ext().execute_with(|| {
+        let (lender, borrower) = offered(5);
+
+        assert_ok!(Pallet::<Test>::accept_loan(RuntimeOrigin::signed(2), ARMORY, SWORD));
+        System::assert_last_event(
+            Event::<Test>::AssetLent {
+                lender: (HEROES, lender),
+                borrower: (HEROES, borrower),
+                asset: (ARMORY, SWORD),
+                until: 5,
+            }
+            .into(),
+        );
+
+        assert!(LoanOffers::<Test>::get((ARMORY, SWORD)).is_none());
+        assert_eq!(AssetCount::<Test>::get((HEROES, lender)), 0);
+        assert_eq!(AssetCount::<Test>::get((HEROES, borrower)), 1);
+        assert_eq!(
+            OwnerAssets::<Test>::get((HEROES, borrower, 0)),
+            Some((ARMORY, SWORD))
+        );
+        assert_eq!(LentCount::<Test>::get((HEROES, lender)), 1);
+        assert_eq!(
+            Loans::<Test>::get((ARMORY, SWORD)),
+            Some(Loan {
+                lender: (HEROES, lender),
+                borrower: (HEROES, borrower),
+                until: 5
+            })
+        );
+        assert_eq!(
+            LoanExpiries::<Test>::get(5).into_inner(),
+            vec![(ARMORY, SWORD)]
+        );
+    });
+}
+
+#[test]
+fn only_the_borrower_accepts_it() {
+    new_test_ext().execute_with(|| {
+        offered(5);
+
+        // The holder of the lender cannot place the asset in the inventory of the borrower.
+        assert_noop!(
+            Pallet::<Test>::accept_loan(RuntimeOrigin::signed(1), ARMORY, SWORD),
+            Error::<Test>::NotOwner
+        );
+        assert_noop!(
+            Pallet::<Test>::accept_loan(RuntimeOrigin::signed(2), ARMORY, SWORD + 1),
+            Error::<Test>::LoanNotFound
+        );
+    });
+}
+
+#[test]
+fn fails_after_the_loan_would_end() {
+    new_test_ext().execute_with(|| {
+        offered(5);
+        System::set_block_number(5);
+
+        assert_noop!(
+            Pallet::<Test>::accept_loan(RuntimeOrigin::signed(2), ARMORY, SWORD),
+            Error::<Test>::ProposalExpired
+        );
+    });
+}
+
+#[test]
+fn fails_if_the_lender_gave_the_asset_away() {
+    new_test_ext().execute_with(|| {
+        let (lender, _) = offered(5);
+        assert_ok!(Pallet::<Test>::unset_ownership(
+            RuntimeOrigin::signed(1),
+            HEROES,
+            lender,
+            ARMORY,
+            SWORD,
+            1
+        ));
+
+        assert_noop!(
+            Pallet::<Test>::accept_loan(RuntimeOrigin::signed(2), ARMORY, SWORD),
+            Error::<Test>::OwnershipNotFound
+        );
+    });
+}
+
+#[test]
+fn fails_when_the_expiries_of_the_block_are_full() {
+    new_test_ext().execute_with(|| {
+        offered(5);
+
+        let max = <Test as pallet::Config>::MaxLoansPerBlock::get();
+        let expiring = (0..max as u128).map(|item| (ARMORY, 100 + item)).collect::<Vec<_>>();
+        LoanExpiries::<Test>::insert(5, BoundedVec::truncate_from(expiring));
+        assert_noop!(
+            Pallet::<Test>::accept_loan(RuntimeOrigin::signed(2), ARMORY, SWORD),
+            Error::<Test>::ExceededMaxLoansPerBlock
+        );
+    });
+}
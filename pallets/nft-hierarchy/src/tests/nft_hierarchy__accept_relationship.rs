@@ -0,0 +1,96 @@
+#![allow(non_snake_case)]
+
+use super::super::*;
+use super::nft_hierarchy__propose_relationship::{
+    alice_gives_the_sword, ALICE, ARMORY, BOB, HEROES, SWORD,
+};
+use crate::mock::{new_test_ext, RuntimeOrigin, System, Test};
+use frame_support::{assert_noop, assert_ok};
+
+#[test]
+fn works() {
+    new_test_ext().execute_with(|| {
+        let bob_character = alice_gives_the_sword();
+
+        assert_ok!(Pallet::<Test>::accept_relationship(
+            RuntimeOrigin::signed(BOB),
+            HEROES,
+            bob_character,
+            ARMORY,
+            SWORD,
+            0
+        ));
+        System::assert_has_event(
+            Event::<Test>::RelationshipAccepted {
+                owner: (HEROES, bob_character),
+                asset: (ARMORY, SWORD),
+                who: BOB,
+            }
+            .into(),
+        );
+        assert_eq!(AssetCount::<Test>::get((HEROES, bob_character)), 1);
+        assert_eq!(
+            OwnerAssets::<Test>::get((HEROES, bob_character, 0)),
+            Some((ARMORY, SWORD))
+        );
+        assert!(Proposals::<Test>::get((HEROES, bob_character, ARMORY, SWORD)).is_none());
+    });
+}
+
+#[test]
+fn fails_for_the_proposing_side() {
+    new_test_ext().execute_with(|| {
+        let bob_character = alice_gives_the_sword();
+
+        assert_noop!(
+            Pallet::<Test>::accept_relationship(
+                RuntimeOrigin::signed(ALICE),
+                HEROES,
+                bob_character,
+                ARMORY,
+                SWORD,
+                0
+            ),
+            Error::<Test>::NotOwner
+        );
+    });
+}
+
+#[test]
+fn fails_once_expired() {
+    new_test_ext().execute_with(|| {
+        let bob_character = alice_gives_the_sword();
+
+        System::set_block_number(5);
+        assert_noop!(
+            Pallet::<Test>::accept_relationship(
+                RuntimeOrigin::signed(BOB),
+                HEROES,
+                bob_character,
+                ARMORY,
+                SWORD,
+                0
+            ),
+            Error::<Test>::ProposalExpired
+        );
+    });
+}
+
+#[test]
+fn fails_without_a_proposal() {
+    new_test_ext().execute_with(|| {
+        let bob_character = alice_gives_the_sword();
+
+        assert_noop!(
+            Pallet::<Test>::accept_relationship(
+                RuntimeOrigin::signed(BOB),
+                HEROES,
+                bob_character,
+                ARMORY,
+                SWORD + 1,
+                0
+            ),
+            Error::<Test>::ProposalNotFound
+        );
+    });
+}
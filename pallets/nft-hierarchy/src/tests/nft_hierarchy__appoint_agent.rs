@@ -0,0 +1,224 @@
+#![allow(non_snake_case)]
+
+use super::super::*;
+use crate::mock::{mint, mint_registered, new_test_ext, RuntimeOrigin, System, Test, Uniques};
+use frame_support::{assert_noop, assert_ok, BoundedVec};
+
+const HEROES: u32 = 1;
+const FAMILIARS: u32 = 2;
+const HOLDER: u64 = 1;
+const AGENT_HOLDER: u64 = 2;
+
+/// Mint the character 1 for HOLDER, registered as an owner, and the familiar 1 for AGENT_HOLDER.
+/// The weapon 2 of HOLDER is registered for the character to own.
+fn character_and_familiar() -> (u128, u128) {
+    mint(HEROES, 1, HOLDER);
+    mint(FAMILIARS, 1, AGENT_HOLDER);
+    let tags =
+        BoundedVec::try_from(vec![BoundedVec::try_from(b"owner".to_vec()).unwrap()]).unwrap();
+    assert_ok!(Pallet::<Test>::register_asset(
+        RuntimeOrigin::signed(HOLDER),
+        HEROES,
+        1,
+        tags
+    ));
+    mint_registered(HEROES, 2, HOLDER, b"weapon");
+    (1, 1)
+}
+
+fn ownership() -> OperatorScope {
+    OperatorScope {
+        ownership: true,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn works() {
+    new_test_ext().execute_with(|| {
+        let (character, familiar) = character_and_familiar();
+
+        assert_ok!(Pallet::<Test>::appoint_agent(
+            RuntimeOrigin::signed(HOLDER),
+            HEROES,
+            character,
+            FAMILIARS,
+            familiar,
+            ownership()
+        ));
+        System::assert_last_event(
+            Event::<Test>::AgentAppointed {
+                principal: (HEROES, character),
+                agent: (FAMILIARS, familiar),
+                scope: ownership(),
+            }
+            .into(),
+        );
+        assert_eq!(
+            Pallet::<Test>::agents_of(&HEROES, &character),
+            vec![((FAMILIARS, familiar), ownership())]
+        );
+        assert_eq!(AgentCount::<Test>::get((HEROES, character)), 1);
+
+        // The holder of the familiar manages the inventory of the character.
+        assert_ok!(Pallet::<Test>::set_ownership(
+            RuntimeOrigin::signed(AGENT_HOLDER),
+            HEROES,
+            character,
+            HEROES,
+            2,
+            0
+        ));
+
+        // Appointing it again replaces its scope without counting it twice.
+        assert_ok!(Pallet::<Test>::appoint_agent(
+            RuntimeOrigin::signed(HOLDER),
+            HEROES,
+            character,
+            FAMILIARS,
+            familiar,
+            OperatorScope {
+                tags: true,
+                ..Default::default()
+            }
+        ));
+        assert_eq!(AgentCount::<Test>::get((HEROES, character)), 1);
+        assert_noop!(
+            Pallet::<Test>::set_ownership(
+                RuntimeOrigin::signed(AGENT_HOLDER),
+                HEROES,
+                character,
+                HEROES,
+                3,
+                1
+            ),
+            Error::<Test>::NotOwner
+        );
+    });
+}
+
+#[test]
+fn follows_the_agent_when_it_changes_hands() {
+    new_test_ext().execute_with(|| {
+        let (character, familiar) = character_and_familiar();
+        assert_ok!(Pallet::<Test>::appoint_agent(
+            RuntimeOrigin::signed(HOLDER),
+            HEROES,
+            character,
+            FAMILIARS,
+            familiar,
+            ownership()
+        ));
+
+        assert_ok!(Uniques::transfer(
+            RuntimeOrigin::signed(AGENT_HOLDER),
+            FAMILIARS,
+            familiar,
+            3
+        ));
+
+        assert_noop!(
+            Pallet::<Test>::set_ownership(
+                RuntimeOrigin::signed(AGENT_HOLDER),
+                HEROES,
+                character,
+                HEROES,
+                2,
+                0
+            ),
+            Error::<Test>::NotOwner
+        );
+        assert_ok!(Pallet::<Test>::set_ownership(
+            RuntimeOrigin::signed(3),
+            HEROES,
+            character,
+            HEROES,
+            2,
+            0
+        ));
+    });
+}
+
+#[test]
+fn fails_if_not_holder() {
+    new_test_ext().execute_with(|| {
+        let (character, familiar) = character_and_familiar();
+
+        assert_noop!(
+            Pallet::<Test>::appoint_agent(
+                RuntimeOrigin::signed(AGENT_HOLDER),
+                HEROES,
+                character,
+                FAMILIARS,
+                familiar,
+                ownership()
+            ),
+            Error::<Test>::NotOwner
+        );
+    });
+}
+
+#[test]
+fn fails_with_a_wrong_agent() {
+    new_test_ext().execute_with(|| {
+        let (character, _) = character_and_familiar();
+
+        assert_noop!(
+            Pallet::<Test>::appoint_agent(
+                RuntimeOrigin::signed(HOLDER),
+                HEROES,
+                character,
+                HEROES,
+                character,
+                ownership()
+            ),
+            Error::<Test>::WrongNft
+        );
+        assert_noop!(
+            Pallet::<Test>::appoint_agent(
+                RuntimeOrigin::signed(HOLDER),
+                HEROES,
+                character,
+                FAMILIARS,
+                7,
+                ownership()
+            ),
+            Error::<Test>::TokenNotFound
+        );
+    });
+}
+
+#[test]
+fn supports_maximum_exceeded() {
+    new_test_ext().execute_with(|| {
+        let (character, _) = character_and_familiar();
+        let max = <Test as pallet::Config>::MaxAgents::get() as u128;
+
+        for familiar in 2..=max {
+            mint(FAMILIARS, familiar, AGENT_HOLDER);
+        }
+        for familiar in 1..=max {
+            assert_ok!(Pallet::<Test>::appoint_agent(
+                RuntimeOrigin::signed(HOLDER),
+                HEROES,
+                character,
+                FAMILIARS,
+                familiar,
+                ownership()
+            ));
+        }
+
+        mint(FAMILIARS, max + 1, AGENT_HOLDER);
+        assert_noop!(
+            Pallet::<Test>::appoint_agent(
+                RuntimeOrigin::signed(HOLDER),
+                HEROES,
+                character,
+                FAMILIARS,
+                max + 1,
+                ownership()
+            ),
+            Error::<Test>::ExceededMaxAgents
+        );
+    });
+}
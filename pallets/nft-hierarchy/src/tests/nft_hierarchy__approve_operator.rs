@@ -0,0 +1,250 @@
+#![allow(non_snake_case)]
+
+use super::super::*;
+use crate::mock::{mint, mint_registered, new_test_ext, RuntimeOrigin, System, Test, Uniques};
+use frame_support::{assert_noop, assert_ok, BoundedVec};
+
+const HOLDER: u64 = 1;
+const OPERATOR: u64 = 2;
+
+/// Register the character 1 as an owner, held by HOLDER, and the items 2 and 3 it may own.
+fn character(collec_id: u32) -> u128 {
+    let owner_id = 1u128;
+    mint(collec_id, owner_id, HOLDER);
+    let tags: BoundedVec<
+        BoundedVec<u8, <Test as pallet::Config>::StringLimit>,
+        <Test as pallet::Config>::TypeLimit,
+    > = BoundedVec::try_from(vec![BoundedVec::try_from(b"owner".to_vec()).unwrap()]).unwrap();
+    assert_ok!(Pallet::<Test>::register_asset(
+        RuntimeOrigin::signed(HOLDER),
+        collec_id,
+        owner_id,
+        tags
+    ));
+    mint_registered(collec_id, 2, HOLDER, b"weapon");
+    mint_registered(collec_id, 3, HOLDER, b"weapon");
+    owner_id
+}
+
+fn ownership() -> OperatorScope {
+    OperatorScope {
+        ownership: true,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn works() {
+    new_test_ext().execute_with(|| {
+        let collec_id = 1u32;
+        let owner_id = character(collec_id);
+
+        assert_ok!(Pallet::<Test>::approve_operator(
+            RuntimeOrigin::signed(HOLDER),
+            collec_id,
+            owner_id,
+            OPERATOR,
+            ownership(),
+            None
+        ));
+        System::assert_last_event(
+            Event::<Test>::OperatorApproved {
+                nft: (collec_id, owner_id),
+                operator: OPERATOR,
+                scope: ownership(),
+                expiry: None,
+            }
+            .into(),
+        );
+
+        // The operator manages the inventory of the character.
+        assert_ok!(Pallet::<Test>::set_ownership(
+            RuntimeOrigin::signed(OPERATOR),
+            collec_id,
+            owner_id,
+            collec_id,
+            2,
+            0
+        ));
+        assert_ok!(Pallet::<Test>::unset_ownership(
+            RuntimeOrigin::signed(OPERATOR),
+            collec_id,
+            owner_id,
+            collec_id,
+            2,
+            1
+        ));
+
+        // Anyone else does not.
+        assert_noop!(
+            Pallet::<Test>::set_ownership(
+                RuntimeOrigin::signed(3),
+                collec_id,
+                owner_id,
+                collec_id,
+                2,
+                0
+            ),
+            Error::<Test>::NotOwner
+        );
+    });
+}
+
+#[test]
+fn is_limited_to_its_scope() {
+    new_test_ext().execute_with(|| {
+        let collec_id = 1u32;
+        let owner_id = character(collec_id);
+
+        let tags_only = OperatorScope {
+            tags: true,
+            ..Default::default()
+        };
+        assert_ok!(Pallet::<Test>::approve_operator(
+            RuntimeOrigin::signed(HOLDER),
+            collec_id,
+            owner_id,
+            OPERATOR,
+            tags_only,
+            None
+        ));
+
+        assert_noop!(
+            Pallet::<Test>::set_ownership(
+                RuntimeOrigin::signed(OPERATOR),
+                collec_id,
+                owner_id,
+                collec_id,
+                2,
+                0
+            ),
+            Error::<Test>::NotOwner
+        );
+
+        // The tags of the character are within the scope.
+        let tags: BoundedVec<
+            BoundedVec<u8, <Test as pallet::Config>::StringLimit>,
+            <Test as pallet::Config>::TypeLimit,
+        > = BoundedVec::try_from(vec![BoundedVec::try_from(b"character".to_vec()).unwrap()])
+            .unwrap();
+        assert_ok!(Pallet::<Test>::register_asset(
+            RuntimeOrigin::signed(OPERATOR),
+            collec_id,
+            owner_id,
+            tags.clone()
+        ));
+        assert!(NftInfos::<Test>::get(collec_id, owner_id).is_type("character"));
+
+        // Without the tags scope, nobody but the holder changes the tags of a registered NFT.
+        assert_noop!(
+            Pallet::<Test>::register_asset(RuntimeOrigin::signed(3), collec_id, owner_id, tags),
+            Error::<Test>::NotOwner
+        );
+    });
+}
+
+#[test]
+fn expires() {
+    new_test_ext().execute_with(|| {
+        let collec_id = 1u32;
+        let owner_id = character(collec_id);
+
+        assert_ok!(Pallet::<Test>::approve_operator(
+            RuntimeOrigin::signed(HOLDER),
+            collec_id,
+            owner_id,
+            OPERATOR,
+            ownership(),
+            Some(5)
+        ));
+
+        System::set_block_number(4);
+        assert_ok!(Pallet::<Test>::set_ownership(
+            RuntimeOrigin::signed(OPERATOR),
+            collec_id,
+            owner_id,
+            collec_id,
+            2,
+            0
+        ));
+
+        System::set_block_number(5);
+        assert_noop!(
+            Pallet::<Test>::set_ownership(
+                RuntimeOrigin::signed(OPERATOR),
+                collec_id,
+                owner_id,
+                collec_id,
+                3,
+                1
+            ),
+            Error::<Test>::NotOwner
+        );
+    });
+}
+
+#[test]
+fn lapses_when_the_nft_changes_hands() {
+    new_test_ext().execute_with(|| {
+        let collec_id = 1u32;
+        let owner_id = character(collec_id);
+
+        assert_ok!(Pallet::<Test>::approve_operator(
+            RuntimeOrigin::signed(HOLDER),
+            collec_id,
+            owner_id,
+            OPERATOR,
+            ownership(),
+            None
+        ));
+        assert_ok!(Uniques::transfer(
+            RuntimeOrigin::signed(HOLDER),
+            collec_id,
+            owner_id,
+            3
+        ));
+
+        assert_noop!(
+            Pallet::<Test>::set_ownership(
+                RuntimeOrigin::signed(OPERATOR),
+                collec_id,
+                owner_id,
+                collec_id,
+                2,
+                0
+            ),
+            Error::<Test>::NotOwner
+        );
+    });
+}
+
+#[test]
+fn fails_if_not_holder_or_expired() {
+    new_test_ext().execute_with(|| {
+        let collec_id = 1u32;
+        let owner_id = character(collec_id);
+
+        assert_noop!(
+            Pallet::<Test>::approve_operator(
+                RuntimeOrigin::signed(OPERATOR),
+                collec_id,
+                owner_id,
+                OPERATOR,
+                ownership(),
+                None
+            ),
+            Error::<Test>::NotOwner
+        );
+        assert_noop!(
+            Pallet::<Test>::approve_operator(
+                RuntimeOrigin::signed(HOLDER),
+                collec_id,
+                owner_id,
+                OPERATOR,
+                ownership(),
+                Some(1)
+            ),
+            Error::<Test>::ExpiryInThePast
+        );
+    });
+}
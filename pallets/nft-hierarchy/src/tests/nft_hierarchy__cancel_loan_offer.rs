@@ -0,0 +1,62 @@
+#![allow(non_snake_case)]
+
+use super::super::*;
+use super::nft_hierarchy__lend::{two_characters_and_a_sword, ARMORY, HEROES, SWORD};
+use crate::mock::{new_test_ext, RuntimeOrigin, System, Test};
+use frame_support::{assert_noop, assert_ok};
+
+fn offered() -> (u128, u128) {
+    let (lender, borrower) = two_characters_and_a_sword();
+    assert_ok!(Pallet::<Test>::lend(
+        RuntimeOrigin::signed(1),
+        HEROES,
+        lender,
+        ARMORY,
+        SWORD,
+        HEROES,
+        borrower,
+        5
+    ));
+    (lender, borrower)
+}
+
+#[test]
+fn works_for_either_side() {
+    for who in [1u64, 2] {
+        new_test_ext().execute_with(|| {
+            let (lender, borrower) = offered();
+
+            assert_ok!(Pallet::<Test>::cancel_loan_offer(RuntimeOrigin::signed(who), ARMORY, SWORD));
+            System::assert_last_event(
+                Event::<Test>::LoanOfferCancelled {
+                    lender: (HEROES, lender),
+                    borrower: (HEROES, borrower),
+                    asset: (ARMORY, SWORD),
+                    who,
+                }
+                .into(),
+            );
+            assert!(LoanOffers::<Test>::get((ARMORY, SWORD)).is_none());
+            assert_noop!(
+                Pallet::<Test>::accept_loan(RuntimeOrigin::signed(2), ARMORY, SWORD),
+                Error::<Test>::LoanNotFound
+            );
+        });
+    }
+}
+
+#[test]
+fn fails_for_others() {
+    new_test_ext().execute_with(|| {
+        offered();
+
+        assert_noop!(
+            Pallet::<Test>::cancel_loan_offer(RuntimeOrigin::signed(3), ARMORY, SWORD),
+            Error::<Test>::NotOwner
+        );
+        assert_noop!(
+            Pallet::<Test>::cancel_loan_offer(RuntimeOrigin::signed(1), ARMORY, SWORD + 1),
+            Error::<Test>::LoanNotFound
+        );
+    });
+}
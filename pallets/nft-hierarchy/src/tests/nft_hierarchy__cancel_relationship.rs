@@ -0,0 +1,52 @@
+#![allow(non_snake_case)]
+
+use super::super::*;
+use super::nft_hierarchy__propose_relationship::{
+    alice_gives_the_sword, ALICE, ARMORY, BOB, HEROES, SWORD,
+};
+use crate::mock::{new_test_ext, RuntimeOrigin, System, Test};
+use frame_support::{assert_noop, assert_ok};
+
+#[test]
+fn works() {
+    new_test_ext().execute_with(|| {
+        let bob_character = alice_gives_the_sword();
+
+        assert_noop!(
+            Pallet::<Test>::cancel_relationship(
+                RuntimeOrigin::signed(BOB),
+                HEROES,
+                bob_character,
+                ARMORY,
+                SWORD
+            ),
+            Error::<Test>::NotOwner
+        );
+
+        assert_ok!(Pallet::<Test>::cancel_relationship(
+            RuntimeOrigin::signed(ALICE),
+            HEROES,
+            bob_character,
+            ARMORY,
+            SWORD
+        ));
+        System::assert_last_event(
+            Event::<Test>::RelationshipCancelled {
+                owner: (HEROES, bob_character),
+                asset: (ARMORY, SWORD),
+            }
+            .into(),
+        );
+        assert_noop!(
+            Pallet::<Test>::accept_relationship(
+                RuntimeOrigin::signed(BOB),
+                HEROES,
+                bob_character,
+                ARMORY,
+                SWORD,
+                0
+            ),
+            Error::<Test>::ProposalNotFound
+        );
+    });
+}
@@ -0,0 +1,107 @@
+#![allow(non_snake_case)]
+
+use super::super::*;
+use crate::mock::{mint, new_test_ext, RuntimeOrigin, System, Test};
+use frame_support::{assert_noop, assert_ok};
+
+const HEROES: u32 = 1;
+const FAMILIARS: u32 = 2;
+
+/// Mint the character 1 for account 1 and the familiar 1 for account 2, and appoint the familiar
+/// as an agent of the character.
+fn appointed() -> (u128, u128) {
+    mint(HEROES, 1, 1);
+    mint(FAMILIARS, 1, 2);
+    assert_ok!(Pallet::<Test>::appoint_agent(
+        RuntimeOrigin::signed(1),
+        HEROES,
+        1,
+        FAMILIARS,
+        1,
+        OperatorScope {
+            ownership: true,
+            containers: true,
+            tags: true
+        }
+    ));
+    (1, 1)
+}
+
+#[test]
+fn works() {
+    new_test_ext().execute_with(|| {
+        let (character, familiar) = appointed();
+
+        assert_ok!(Pallet::<Test>::dismiss_agent(
+            RuntimeOrigin::signed(1),
+            HEROES,
+            character,
+            FAMILIARS,
+            familiar
+        ));
+        System::assert_last_event(
+            Event::<Test>::AgentDismissed {
+                principal: (HEROES, character),
+                agent: (FAMILIARS, familiar),
+            }
+            .into(),
+        );
+        assert!(Pallet::<Test>::agents_of(&HEROES, &character).is_empty());
+        assert_eq!(AgentCount::<Test>::get((HEROES, character)), 0);
+
+        assert_noop!(
+            Pallet::<Test>::set_ownership(
+                RuntimeOrigin::signed(2),
+                HEROES,
+                character,
+                HEROES,
+                2,
+                0
+            ),
+            Error::<Test>::NotOwner
+        );
+    });
+}
+
+#[test]
+fn the_agent_can_resign() {
+    new_test_ext().execute_with(|| {
+        let (character, familiar) = appointed();
+
+        assert_ok!(Pallet::<Test>::dismiss_agent(
+            RuntimeOrigin::signed(2),
+            HEROES,
+            character,
+            FAMILIARS,
+            familiar
+        ));
+        assert_noop!(
+            Pallet::<Test>::dismiss_agent(
+                RuntimeOrigin::signed(2),
+                HEROES,
+                character,
+                FAMILIARS,
+                familiar
+            ),
+            Error::<Test>::AgentNotFound
+        );
+    });
+}
+
+#[test]
+fn fails_if_not_holder() {
+    new_test_ext().execute_with(|| {
+        let (character, familiar) = appointed();
+
+        assert_noop!(
+            Pallet::<Test>::dismiss_agent(
+                RuntimeOrigin::signed(3),
+                HEROES,
+                character,
+                FAMILIARS,
+                familiar
+            ),
+            Error::<Test>::NotOwner
+        );
+    });
+}
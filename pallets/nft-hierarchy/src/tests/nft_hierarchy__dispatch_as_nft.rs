@@ -0,0 +1,145 @@
+#![allow(non_snake_case)]
+
+use super::super::*;
+use crate::mock::{mint, new_test_ext, Balances, RuntimeCall, RuntimeOrigin, System, Test, Uniques};
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::DispatchError;
+
+const HEROES: u32 = 1;
+const ARMORY: u32 = 2;
+const CHARACTER: u128 = 1;
+const SWORD: u128 = 10;
+const GOLD: u128 = 1_000_000_000_000;
+
+/// Mint the character for the account 2 and fill its purse with 10 gold from the account 1.
+fn a_character_with_a_purse() -> u64 {
+    mint(HEROES, CHARACTER, 2);
+    let purse = Pallet::<Test>::nft_account(&HEROES, &CHARACTER);
+    assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(1), purse, 10 * GOLD));
+    purse
+}
+
+fn pay(to: u64, amount: u128) -> Box<RuntimeCall> {
+    Box::new(RuntimeCall::Balances(pallet_balances::Call::transfer_allow_death {
+        dest: to,
+        value: amount,
+    }))
+}
+
+#[test]
+fn works() {
+    new_test_ext().execute_with(|| {
+        let purse = a_character_with_a_purse();
+
+        assert_ok!(Pallet::<Test>::dispatch_as_nft(
+            RuntimeOrigin::signed(2),
+            HEROES,
+            CHARACTER,
+            pay(3, 4 * GOLD)
+        ));
+        System::assert_last_event(
+            Event::<Test>::DispatchedAsNft {
+                nft: (HEROES, CHARACTER),
+                account: purse,
+                result: Ok(()),
+            }
+            .into(),
+        );
+
+        assert_eq!(Balances::free_balance(purse), 6 * GOLD);
+        assert_eq!(Balances::free_balance(3), 4 * GOLD);
+    });
+}
+
+#[test]
+fn each_nft_has_its_own_account() {
+    new_test_ext().execute_with(|| {
+        let purse = Pallet::<Test>::nft_account(&HEROES, &CHARACTER);
+        assert_eq!(purse, Pallet::<Test>::nft_account(&HEROES, &CHARACTER));
+        assert_ne!(purse, Pallet::<Test>::nft_account(&HEROES, &(CHARACTER + 1)));
+        assert_ne!(purse, Pallet::<Test>::nft_account(&ARMORY, &CHARACTER));
+    });
+}
+
+#[test]
+fn the_purse_goes_with_the_character() {
+    new_test_ext().execute_with(|| {
+        a_character_with_a_purse();
+        assert_ok!(Uniques::transfer(RuntimeOrigin::signed(2), HEROES, CHARACTER, 3));
+
+        assert_noop!(
+            Pallet::<Test>::dispatch_as_nft(
+                RuntimeOrigin::signed(2),
+                HEROES,
+                CHARACTER,
+                pay(2, 4 * GOLD)
+            ),
+            Error::<Test>::NotOwner
+        );
+        assert_ok!(Pallet::<Test>::dispatch_as_nft(
+            RuntimeOrigin::signed(3),
+            HEROES,
+            CHARACTER,
+            pay(3, 4 * GOLD)
+        ));
+        assert_eq!(Balances::free_balance(3), 4 * GOLD);
+    });
+}
+
+#[test]
+fn the_account_holds_items() {
+    new_test_ext().execute_with(|| {
+        let purse = a_character_with_a_purse();
+        mint(ARMORY, SWORD, purse);
+
+        assert_ok!(Pallet::<Test>::dispatch_as_nft(
+            RuntimeOrigin::signed(2),
+            HEROES,
+            CHARACTER,
+            Box::new(RuntimeCall::Uniques(pallet_uniques::Call::transfer {
+                collection: ARMORY,
+                item: SWORD,
+                dest: 3,
+            }))
+        ));
+        assert_eq!(Uniques::owner(ARMORY, SWORD), Some(3));
+    });
+}
+
+#[test]
+fn reports_the_result_of_the_call() {
+    new_test_ext().execute_with(|| {
+        let purse = a_character_with_a_purse();
+
+        // The token-bound account is a signed origin, which cannot dispatch root calls.
+        assert_ok!(Pallet::<Test>::dispatch_as_nft(
+            RuntimeOrigin::signed(2),
+            HEROES,
+            CHARACTER,
+            Box::new(RuntimeCall::System(frame_system::Call::set_heap_pages { pages: 0 }))
+        ));
+        System::assert_last_event(
+            Event::<Test>::DispatchedAsNft {
+                nft: (HEROES, CHARACTER),
+                account: purse,
+                result: Err(DispatchError::BadOrigin),
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn fails_if_the_nft_does_not_exist() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Pallet::<Test>::dispatch_as_nft(
+                RuntimeOrigin::signed(2),
+                HEROES,
+                CHARACTER,
+                pay(3, GOLD)
+            ),
+            Error::<Test>::NotOwner
+        );
+    });
+}
@@ -0,0 +1,42 @@
+#![allow(non_snake_case)]
+
+use super::super::*;
+use crate::mock::{new_test_ext, RuntimeOrigin, System, Test};
+use frame_support::{assert_noop, assert_ok, BoundedVec};
+use sp_runtime::DispatchError;
+
+#[test]
+fn works() {
+    new_test_ext().execute_with(|| {
+        let collec_id = 1u32;
+        let asset_id = 2u128;
+
+        let tags: BoundedVec<BoundedVec<u8, <Test as pallet::Config>::StringLimit>, <Test as pallet::Config>::TypeLimit> = 
+            BoundedVec::try_from(vec![BoundedVec::try_from(b"owner".to_vec()).unwrap()]).unwrap();
+        assert_ok!(Pallet::<Test>::force_register(
+            RuntimeOrigin::root(),
+            collec_id,
+            asset_id,
+            tags
+        ));
+        assert_eq!(NftInfos::<Test>::get(collec_id, asset_id).collec_id, Some(collec_id));
+
+        System::assert_last_event(
+            Event::<Test>::NftForceRegistered {
+                collection: collec_id,
+                asset: asset_id,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn fails_if_not_forced() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Pallet::<Test>::force_register(RuntimeOrigin::signed(1), 1, 2, BoundedVec::new()),
+            DispatchError::BadOrigin
+        );
+    });
+}
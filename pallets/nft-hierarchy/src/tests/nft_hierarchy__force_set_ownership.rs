@@ -0,0 +1,92 @@
+#![allow(non_snake_case)]
+
+use super::super::*;
+use super::nft_hierarchy__lend::{lend_and_accept, two_characters_and_a_sword, ARMORY, HEROES, SWORD};
+use crate::mock::{mint, mint_registered, new_test_ext, RuntimeOrigin, System, Test};
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::DispatchError;
+
+#[test]
+fn works() {
+    new_test_ext().execute_with(|| {
+        let collec_id = 1u32;
+        let owner_id = 1u128;
+        let asset_id = 2u128;
+
+        // The owner must be registered, but not tagged as an owner.
+        mint_registered(collec_id, owner_id, 1, b"entity");
+        mint_registered(collec_id, asset_id, 1, b"weapon");
+        assert_ok!(Pallet::<Test>::force_set_ownership(
+            RuntimeOrigin::root(),
+            collec_id,
+            owner_id,
+            collec_id,
+            asset_id
+        ));
+        assert_eq!(AssetCount::<Test>::get((collec_id, owner_id)), 1);
+        assert_eq!(
+            OwnerAssets::<Test>::get((collec_id, owner_id, 0)),
+            Some((collec_id, asset_id))
+        );
+
+        System::assert_last_event(
+            Event::<Test>::OwnershipForceAdded {
+                owner: (collec_id, owner_id),
+                asset: (collec_id, asset_id),
+            }
+            .into(),
+        );
+
+        assert_noop!(
+            Pallet::<Test>::force_set_ownership(
+                RuntimeOrigin::root(),
+                collec_id,
+                owner_id,
+                collec_id,
+                asset_id
+            ),
+            Error::<Test>::AlreadyOwner
+        );
+    });
+}
+
+#[test]
+fn fails_if_not_forced() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Pallet::<Test>::force_set_ownership(RuntimeOrigin::signed(1), 1, 1, 1, 2),
+            DispatchError::BadOrigin
+        );
+    });
+}
+
+#[test]
+fn fails_for_unregistered_nfts() {
+    new_test_ext().execute_with(|| {
+        mint_registered(1, 1, 1, b"entity");
+        mint(1, 2, 1);
+
+        assert_noop!(
+            Pallet::<Test>::force_set_ownership(RuntimeOrigin::root(), 1, 1, 1, 2),
+            Error::<Test>::NotRegistered
+        );
+        assert_noop!(
+            Pallet::<Test>::force_set_ownership(RuntimeOrigin::root(), 1, 2, 1, 1),
+            Error::<Test>::NotRegistered
+        );
+    });
+}
+
+#[test]
+fn fails_for_assets_on_loan() {
+    new_test_ext().execute_with(|| {
+        let (lender, _) = two_characters_and_a_sword();
+        lend_and_accept(SWORD, 5);
+
+        // Giving the sword back to its lender would leave it held twice once the loan ends.
+        assert_noop!(
+            Pallet::<Test>::force_set_ownership(RuntimeOrigin::root(), HEROES, lender, ARMORY, SWORD),
+            Error::<Test>::AssetOnLoan
+        );
+    });
+}
@@ -0,0 +1,100 @@
+#![allow(non_snake_case)]
+
+use super::super::*;
+use super::nft_hierarchy__lend::{lend_and_accept, two_characters_and_a_sword, HEROES, SWORD};
+use crate::mock::{new_test_ext, RuntimeOrigin, System, Test};
+use frame_support::{assert_noop, assert_ok, BoundedVec};
+use sp_runtime::DispatchError;
+
+#[test]
+fn removes_the_asset_and_its_inventory() {
+    new_test_ext().execute_with(|| {
+        let collec_id = 1u32;
+        let owner_id = 1u128;
+
+        assert_ok!(Pallet::<Test>::force_register(
+            RuntimeOrigin::root(),
+            collec_id,
+            owner_id,
+            BoundedVec::new()
+        ));
+        for asset_id in 2..=3u128 {
+            assert_ok!(Pallet::<Test>::force_register(
+                RuntimeOrigin::root(),
+                collec_id,
+                asset_id,
+                BoundedVec::new()
+            ));
+            assert_ok!(Pallet::<Test>::force_set_ownership(
+                RuntimeOrigin::root(),
+                collec_id,
+                owner_id,
+                collec_id,
+                asset_id
+            ));
+        }
+
+        assert_ok!(Pallet::<Test>::force_unregister(RuntimeOrigin::root(), collec_id, owner_id));
+        assert!(!NftInfos::<Test>::contains_key(collec_id, owner_id));
+        assert_eq!(AssetCount::<Test>::get((collec_id, owner_id)), 0);
+        assert_eq!(OwnerAssets::<Test>::get((collec_id, owner_id, 0)), None);
+        assert_eq!(OwnerAssets::<Test>::get((collec_id, owner_id, 1)), None);
+        // Its assets are no longer owned, and may be unregistered in turn.
+        assert_eq!(OwnerCount::<Test>::get((collec_id, 2)), 0);
+        assert_ok!(Pallet::<Test>::force_unregister(RuntimeOrigin::root(), collec_id, 2));
+
+        System::assert_last_event(
+            Event::<Test>::NftForceUnregistered {
+                collection: collec_id,
+                asset: 2,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn fails_if_not_registered() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Pallet::<Test>::force_unregister(RuntimeOrigin::root(), 1, 1),
+            Error::<Test>::NotRegistered
+        );
+        assert_noop!(
+            Pallet::<Test>::force_unregister(RuntimeOrigin::signed(1), 1, 1),
+            DispatchError::BadOrigin
+        );
+    });
+}
+
+#[test]
+fn fails_for_an_owned_asset() {
+    new_test_ext().execute_with(|| {
+        for item in 1..=2u128 {
+            assert_ok!(Pallet::<Test>::force_register(RuntimeOrigin::root(), 1, item, BoundedVec::new()));
+        }
+        assert_ok!(Pallet::<Test>::force_set_ownership(RuntimeOrigin::root(), 1, 1, 1, 2));
+
+        assert_noop!(
+            Pallet::<Test>::force_unregister(RuntimeOrigin::root(), 1, 2),
+            Error::<Test>::StillOwned
+        );
+    });
+}
+
+#[test]
+fn fails_for_an_nft_with_assets_on_loan() {
+    new_test_ext().execute_with(|| {
+        let (lender, borrower) = two_characters_and_a_sword();
+        lend_and_accept(SWORD, 10);
+
+        assert_noop!(
+            Pallet::<Test>::force_unregister(RuntimeOrigin::root(), HEROES, lender),
+            Error::<Test>::AssetOnLoan
+        );
+        assert_noop!(
+            Pallet::<Test>::force_unregister(RuntimeOrigin::root(), HEROES, borrower),
+            Error::<Test>::AssetOnLoan
+        );
+    });
+}
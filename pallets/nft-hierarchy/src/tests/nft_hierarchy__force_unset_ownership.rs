@@ -0,0 +1,93 @@
+#![allow(non_snake_case)]
+
+use super::super::*;
+use super::nft_hierarchy__lend::{lend_and_accept, two_characters_and_a_sword, ARMORY, HEROES, SWORD};
+use crate::mock::{new_test_ext, RuntimeOrigin, System, Test};
+use frame_support::{assert_noop, assert_ok, BoundedVec};
+use sp_runtime::DispatchError;
+
+#[test]
+fn removes_the_given_asset() {
+    new_test_ext().execute_with(|| {
+        let collec_id = 1u32;
+        let owner_id = 1u128;
+
+        assert_ok!(Pallet::<Test>::force_register(
+            RuntimeOrigin::root(),
+            collec_id,
+            owner_id,
+            BoundedVec::new()
+        ));
+        for asset_id in 2..=4u128 {
+            assert_ok!(Pallet::<Test>::force_register(
+                RuntimeOrigin::root(),
+                collec_id,
+                asset_id,
+                BoundedVec::new()
+            ));
+            assert_ok!(Pallet::<Test>::force_set_ownership(
+                RuntimeOrigin::root(),
+                collec_id,
+                owner_id,
+                collec_id,
+                asset_id
+            ));
+        }
+
+        // The asset in the middle is taken away, and the last one takes its place.
+        assert_ok!(Pallet::<Test>::force_unset_ownership(
+            RuntimeOrigin::root(),
+            collec_id,
+            owner_id,
+            collec_id,
+            3
+        ));
+        assert_eq!(AssetCount::<Test>::get((collec_id, owner_id)), 2);
+        assert_eq!(OwnerAssets::<Test>::get((collec_id, owner_id, 0)), Some((collec_id, 2)));
+        assert_eq!(OwnerAssets::<Test>::get((collec_id, owner_id, 1)), Some((collec_id, 4)));
+        assert_eq!(OwnerAssets::<Test>::get((collec_id, owner_id, 2)), None);
+
+        System::assert_last_event(
+            Event::<Test>::OwnershipForceRemoved {
+                owner: (collec_id, owner_id),
+                asset: (collec_id, 3),
+            }
+            .into(),
+        );
+
+        assert_noop!(
+            Pallet::<Test>::force_unset_ownership(
+                RuntimeOrigin::root(),
+                collec_id,
+                owner_id,
+                collec_id,
+                3
+            ),
+            Error::<Test>::OwnershipNotFound
+        );
+    });
+}
+
+#[test]
+fn fails_if_not_forced() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Pallet::<Test>::force_unset_ownership(RuntimeOrigin::signed(1), 1, 1, 1, 2),
+            DispatchError::BadOrigin
+        );
+    });
+}
+
+#[test]
+fn fails_for_assets_on_loan() {
+    new_test_ext().execute_with(|| {
+        let (_, borrower) = two_characters_and_a_sword();
+        lend_and_accept(SWORD, 5);
+
+        // Taking the sword away from its borrower would leave the loan without an asset to return.
+        assert_noop!(
+            Pallet::<Test>::force_unset_ownership(RuntimeOrigin::root(), HEROES, borrower, ARMORY, SWORD),
+            Error::<Test>::AssetOnLoan
+        );
+    });
+}
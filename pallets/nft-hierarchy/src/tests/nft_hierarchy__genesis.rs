@@ -0,0 +1,100 @@
+#![allow(non_snake_case)]
+
+use super::super::*;
+use crate::mock::{Test, Uniques};
+use sp_runtime::BuildStorage;
+
+const HEROES: u32 = 1;
+const ARMORY: u32 = 2;
+const KNIGHT: u128 = 1;
+const SWORD: u128 = 101;
+const SHIELD: u128 = 102;
+
+/// Build the storage of the mock with `genesis`, where the account 1 has funds for the deposits.
+fn new_world(genesis: GenesisConfig<Test>) -> sp_io::TestExternalities {
+    let mut storage = frame_system::GenesisConfig::<Test>::default()
+        .build_storage()
+        .unwrap();
+    pallet_balances::GenesisConfig::<Test> {
+        balances: vec![(1, 1_000_000_000_000_000)],
+        dev_accounts: None,
+    }
+    .assimilate_storage(&mut storage)
+    .unwrap();
+    genesis.assimilate_storage(&mut storage).unwrap();
+    storage.into()
+}
+
+/// A knight held by the account 2, who owns a sword and a shield.
+fn a_knight_and_his_gear() -> GenesisConfig<Test> {
+    GenesisConfig {
+        collections: vec![(HEROES, 1), (ARMORY, 1)],
+        items: vec![(HEROES, KNIGHT, 2), (ARMORY, SWORD, 2), (ARMORY, SHIELD, 2)],
+        nfts: vec![
+            (HEROES, KNIGHT, vec![b"character".to_vec(), b"owner".to_vec()]),
+            (ARMORY, SWORD, vec![b"weapon".to_vec()]),
+            (ARMORY, SHIELD, vec![b"armor".to_vec()]),
+        ],
+        ownerships: vec![((HEROES, KNIGHT), (ARMORY, SWORD)), ((HEROES, KNIGHT), (ARMORY, SHIELD))],
+    }
+}
+
+#[test]
+fn works() {
+    new_world(a_knight_and_his_gear()).execute_with(|| {
+        assert_eq!(Uniques::owner(HEROES, KNIGHT), Some(2));
+        assert_eq!(Uniques::owner(ARMORY, SHIELD), Some(2));
+        assert_eq!(Uniques::collection_owner(ARMORY), Some(1));
+
+        let knight = NftInfos::<Test>::get(HEROES, KNIGHT);
+        assert_eq!(knight.collec_id, Some(HEROES));
+        assert!(knight.is_type("character") && knight.is_type("owner"));
+        assert!(NftInfos::<Test>::get(ARMORY, SWORD).is_type("weapon"));
+
+        assert_eq!(AssetCount::<Test>::get((HEROES, KNIGHT)), 2);
+        assert_eq!(OwnerAssets::<Test>::get((HEROES, KNIGHT, 0)), Some((ARMORY, SWORD)));
+        assert_eq!(OwnerAssets::<Test>::get((HEROES, KNIGHT, 1)), Some((ARMORY, SHIELD)));
+        assert_eq!(
+            Provenance::<Test>::get((ARMORY, SWORD, 0)),
+            Some(ProvenanceEntry {
+                owner: (HEROES, KNIGHT),
+                change: CustodyChange::Acquired,
+                at: 0
+            })
+        );
+        assert_eq!(OwnerCount::<Test>::get((ARMORY, SHIELD)), 1);
+        assert!(Pallet::<Test>::do_try_state().is_ok());
+    });
+}
+
+#[test]
+#[should_panic(expected = "the NFTs registered at genesis exist")]
+fn fails_to_register_a_missing_nft() {
+    let mut genesis = a_knight_and_his_gear();
+    genesis.nfts.push((ARMORY, 103, vec![b"weapon".to_vec()]));
+    new_world(genesis);
+}
+
+#[test]
+#[should_panic(expected = "the owners of the genesis are registered with the owner tag")]
+fn fails_with_an_owner_not_registered_as_such() {
+    let mut genesis = a_knight_and_his_gear();
+    genesis.ownerships.push(((ARMORY, SWORD), (ARMORY, SHIELD)));
+    new_world(genesis);
+}
+
+#[test]
+#[should_panic(expected = "the assets owned at genesis are registered")]
+fn fails_with_an_unregistered_asset() {
+    let mut genesis = a_knight_and_his_gear();
+    genesis.nfts.pop();
+    new_world(genesis);
+}
+
+#[test]
+#[should_panic(expected = "the owners of the genesis have room for their assets, once each")]
+fn fails_with_an_asset_owned_twice() {
+    let mut genesis = a_knight_and_his_gear();
+    genesis.ownerships.push(((HEROES, KNIGHT), (ARMORY, SWORD)));
+    new_world(genesis);
+}
@@ -1,7 +1,8 @@
 #![allow(non_snake_case)]
 
 use super::super::*;
-use crate::mock::{new_test_ext, RuntimeOrigin, System, Test};
+use crate::mock::{mint, mint_registered, new_test_ext, RuntimeOrigin, System, Test};
+use crate::weights::WeightInfo;
 use frame_support::{assert_noop, assert_ok, BoundedVec};
 
 #[test]
@@ -10,6 +11,7 @@ fn works() {
         let collec_id = 0u32;
         let owner_id = 1u128;
         let who = 1u64;
+        mint(collec_id, owner_id, who);
 
         // Create NFTs for the owner.
         let tags: BoundedVec<BoundedVec<u8, <Test as pallet::Config>::StringLimit>, <Test as pallet::Config>::TypeLimit> = 
@@ -31,6 +33,7 @@ fn works() {
                 vec![BoundedVec::try_from(b"entity".to_vec()).unwrap()]
             ).unwrap();
         for i in 2..=12 {
+            mint(collec_id, i, who);
             let _ = Pallet::<Test>::register_asset(
                 RuntimeOrigin::signed(who),
                 collec_id,
@@ -41,7 +44,9 @@ fn works() {
                 RuntimeOrigin::signed(who),
                 collec_id,
                 owner_id,
+                collec_id,
                 i,
+                11,
             );
         }
 
@@ -56,7 +61,7 @@ fn works() {
         ));
 
         let expected_assets =
-            BoundedVec::try_from((2..=5).map(|i| i).collect::<Vec<u128>>()).unwrap();
+            BoundedVec::try_from((2..=5).map(|asset_id| (collec_id, asset_id)).collect::<Vec<_>>()).unwrap();
         System::assert_last_event(
             Event::<Test>::AssetsRetrieved {
                 owner: (collec_id, owner_id),
@@ -73,6 +78,7 @@ fn supports_maximum_exceeded() {
         let collec_id = 0u32;
         let owner_id = 1u128;
         let who = 1u64;
+        mint(collec_id, owner_id, who);
 
 
         
@@ -88,4 +94,43 @@ fn supports_maximum_exceeded() {
             Error::<Test>::ExceededMaxAssetsPerQuery
         );
     });
-}
\ No newline at end of file
+}
+
+#[test]
+fn refunds_assets_not_owned() {
+    new_test_ext().execute_with(|| {
+        let collec_id = 0u32;
+        let owner_id = 1u128;
+        let who = 1u64;
+        mint(collec_id, owner_id, who);
+
+        let tags: BoundedVec<BoundedVec<u8, <Test as pallet::Config>::StringLimit>, <Test as pallet::Config>::TypeLimit> = 
+            BoundedVec::try_from(vec![BoundedVec::try_from(b"owner".to_vec()).unwrap()]).unwrap();
+        assert_ok!(Pallet::<Test>::register_asset(
+            RuntimeOrigin::signed(who),
+            collec_id,
+            owner_id,
+            tags
+        ));
+        for i in 2..=4 {
+            mint_registered(collec_id, i, who, b"entity");
+            assert_ok!(Pallet::<Test>::set_ownership(
+                RuntimeOrigin::signed(who),
+                collec_id,
+                owner_id,
+                collec_id,
+                i,
+                3,
+            ));
+        }
+
+        // Asking for 10 assets from the second one only reads the 2 left.
+        let info =
+            Pallet::<Test>::get_owned_assets(RuntimeOrigin::signed(who), collec_id, owner_id, 1, 10)
+                .unwrap();
+        assert_eq!(
+            info.actual_weight,
+            Some(<Test as pallet::Config>::WeightInfo::get_owned_assets(2))
+        );
+    });
+}
@@ -0,0 +1,304 @@
+#![allow(non_snake_case)]
+
+use super::super::*;
+use crate::mock::{mint, mint_registered, new_test_ext, RuntimeOrigin, System, Test, Uniques};
+use frame_support::{assert_noop, assert_ok, BoundedVec};
+
+pub(crate) const HEROES: u32 = 1;
+pub(crate) const ARMORY: u32 = 2;
+pub(crate) const SWORD: u128 = 10;
+
+/// Register the characters 1 and 2 as owners, held by the accounts 1 and 2, and give the sword to
+/// the character 1. The sword itself is held by the account 1.
+pub(crate) fn two_characters_and_a_sword() -> (u128, u128) {
+    for character in [1u128, 2] {
+        mint(HEROES, character, character as u64);
+        let tags =
+            BoundedVec::try_from(vec![BoundedVec::try_from(b"owner".to_vec()).unwrap()]).unwrap();
+        assert_ok!(Pallet::<Test>::register_asset(
+            RuntimeOrigin::signed(character as u64),
+            HEROES,
+            character,
+            tags
+        ));
+    }
+    mint_registered(ARMORY, SWORD, 1, b"weapon");
+    assert_ok!(Pallet::<Test>::set_ownership(
+        RuntimeOrigin::signed(1),
+        HEROES,
+        1,
+        ARMORY,
+        SWORD,
+        0
+    ));
+    (1, 2)
+}
+
+/// Offer the asset `asset` of the armory from the character 1 to the character 2 until the block
+/// `until`, and accept the offer from the side of the character 2.
+pub(crate) fn lend_and_accept(asset: u128, until: u64) {
+    assert_ok!(Pallet::<Test>::lend(
+        RuntimeOrigin::signed(1),
+        HEROES,
+        1,
+        ARMORY,
+        asset,
+        HEROES,
+        2,
+        until
+    ));
+    assert_ok!(Pallet::<Test>::accept_loan(RuntimeOrigin::signed(2), ARMORY, asset));
+}
+
+#[test]
+fn works() {
+    new_test_ext().execute_with(|| {
+        let (lender, borrower) = two_characters_and_a_sword();
+
+        assert_ok!(Pallet::<Test>::lend(
+            RuntimeOrigin::signed(1),
+            HEROES,
+            lender,
+            ARMORY,
+            SWORD,
+            HEROES,
+            borrower,
+            5
+        ));
+        System::assert_last_event(
+            Event::<Test>::LoanOffered {
+                lender: (HEROES, lender),
+                borrower: (HEROES, borrower),
+                asset: (ARMORY, SWORD),
+                until: 5,
+            }
+            .into(),
+        );
+        assert_eq!(
+            LoanOffers::<Test>::get((ARMORY, SWORD)),
+            Some(Loan {
+                lender: (HEROES, lender),
+                borrower: (HEROES, borrower),
+                until: 5
+            })
+        );
+
+        // Nothing moves until the borrower accepts.
+        assert!(Loans::<Test>::get((ARMORY, SWORD)).is_none());
+        assert_eq!(AssetCount::<Test>::get((HEROES, lender)), 1);
+        assert_eq!(AssetCount::<Test>::get((HEROES, borrower)), 0);
+        assert_eq!(LentCount::<Test>::get((HEROES, lender)), 0);
+    });
+}
+
+#[test]
+fn the_borrower_cannot_give_away_nor_lend_it() {
+    new_test_ext().execute_with(|| {
+        let (lender, borrower) = two_characters_and_a_sword();
+        lend_and_accept(SWORD, 5);
+
+        assert_noop!(
+            Pallet::<Test>::unset_ownership(
+                RuntimeOrigin::signed(2),
+                HEROES,
+                borrower,
+                ARMORY,
+                SWORD,
+                1
+            ),
+            Error::<Test>::AssetOnLoan
+        );
+        assert_noop!(
+            Pallet::<Test>::unset_ownership_batch(
+                RuntimeOrigin::signed(2),
+                HEROES,
+                borrower,
+                BoundedVec::truncate_from(vec![(ARMORY, SWORD)]),
+                1
+            ),
+            Error::<Test>::AssetOnLoan
+        );
+        assert_noop!(
+            Pallet::<Test>::lend(
+                RuntimeOrigin::signed(2),
+                HEROES,
+                borrower,
+                ARMORY,
+                SWORD,
+                HEROES,
+                lender,
+                5
+            ),
+            Error::<Test>::AssetOnLoan
+        );
+        // Nor can the account holding the sword transfer it.
+        assert_noop!(
+            Uniques::transfer(RuntimeOrigin::signed(1), ARMORY, SWORD, 3),
+            pallet_uniques::Error::<Test>::Locked
+        );
+    });
+}
+
+#[test]
+fn the_lender_cannot_give_it_to_another_owner() {
+    new_test_ext().execute_with(|| {
+        two_characters_and_a_sword();
+        let other = 3u128;
+        mint(HEROES, other, 1);
+        let tags =
+            BoundedVec::try_from(vec![BoundedVec::try_from(b"owner".to_vec()).unwrap()]).unwrap();
+        assert_ok!(Pallet::<Test>::register_asset(RuntimeOrigin::signed(1), HEROES, other, tags));
+        lend_and_accept(SWORD, 5);
+
+        // The account 1 still holds the sword, but it stays with the borrower until the loan ends.
+        assert_noop!(
+            Pallet::<Test>::set_ownership(RuntimeOrigin::signed(1), HEROES, other, ARMORY, SWORD, 0),
+            Error::<Test>::AssetOnLoan
+        );
+        assert_noop!(
+            Pallet::<Test>::set_ownership_batch(
+                RuntimeOrigin::signed(1),
+                HEROES,
+                other,
+                BoundedVec::truncate_from(vec![(ARMORY, SWORD)]),
+                0
+            ),
+            Error::<Test>::AssetOnLoan
+        );
+    });
+}
+
+#[test]
+fn the_lender_keeps_room_for_it() {
+    new_test_ext().execute_with(|| {
+        let (lender, _) = two_characters_and_a_sword();
+        lend_and_accept(SWORD, 5);
+
+        let max = <Test as pallet::Config>::MaxInventorySize::get();
+        for item in (100..100 + max as u128 - 1).chain([200]) {
+            mint_registered(ARMORY, item, 1, b"weapon");
+        }
+        for index in 0..max - 1 {
+            assert_ok!(Pallet::<Test>::set_ownership(
+                RuntimeOrigin::signed(1),
+                HEROES,
+                lender,
+                ARMORY,
+                100 + index as u128,
+                index
+            ));
+        }
+        assert_noop!(
+            Pallet::<Test>::set_ownership(
+                RuntimeOrigin::signed(1),
+                HEROES,
+                lender,
+                ARMORY,
+                200,
+                max
+            ),
+            Error::<Test>::ExceededMaxInventorySize
+        );
+    });
+}
+
+#[test]
+fn fails_with_wrong_parties() {
+    new_test_ext().execute_with(|| {
+        let (lender, borrower) = two_characters_and_a_sword();
+
+        // Only the lender side offers the loan.
+        assert_noop!(
+            Pallet::<Test>::lend(
+                RuntimeOrigin::signed(2),
+                HEROES,
+                lender,
+                ARMORY,
+                SWORD,
+                HEROES,
+                borrower,
+                5
+            ),
+            Error::<Test>::NotOwner
+        );
+        // The borrower must be another owner.
+        assert_noop!(
+            Pallet::<Test>::lend(
+                RuntimeOrigin::signed(1),
+                HEROES,
+                lender,
+                ARMORY,
+                SWORD,
+                HEROES,
+                lender,
+                5
+            ),
+            Error::<Test>::WrongNft
+        );
+        assert_noop!(
+            Pallet::<Test>::lend(
+                RuntimeOrigin::signed(1),
+                HEROES,
+                lender,
+                ARMORY,
+                SWORD,
+                HEROES,
+                7,
+                5
+            ),
+            Error::<Test>::WrongNft
+        );
+    });
+}
+
+#[test]
+fn fails_with_a_wrong_expiry() {
+    new_test_ext().execute_with(|| {
+        let (lender, borrower) = two_characters_and_a_sword();
+
+        assert_noop!(
+            Pallet::<Test>::lend(
+                RuntimeOrigin::signed(1),
+                HEROES,
+                lender,
+                ARMORY,
+                SWORD,
+                HEROES,
+                borrower,
+                1
+            ),
+            Error::<Test>::ExpiryInThePast
+        );
+    });
+}
+
+#[test]
+fn fails_if_already_offered() {
+    new_test_ext().execute_with(|| {
+        let (lender, borrower) = two_characters_and_a_sword();
+        assert_ok!(Pallet::<Test>::lend(
+            RuntimeOrigin::signed(1),
+            HEROES,
+            lender,
+            ARMORY,
+            SWORD,
+            HEROES,
+            borrower,
+            5
+        ));
+
+        assert_noop!(
+            Pallet::<Test>::lend(
+                RuntimeOrigin::signed(1),
+                HEROES,
+                lender,
+                ARMORY,
+                SWORD,
+                HEROES,
+                borrower,
+                6
+            ),
+            Error::<Test>::AlreadyExists
+        );
+    });
+}
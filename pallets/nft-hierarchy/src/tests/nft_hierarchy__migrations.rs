@@ -0,0 +1,174 @@
+#![allow(non_snake_case)]
+
+use super::super::*;
+use crate::migrations::v1::{v0, MigrateV0ToV1};
+use crate::migrations::v2::{v1, MigrateV1ToV2};
+use crate::migrations::v3::MigrateV2ToV3;
+use crate::common::NftInfo;
+use crate::mock::{new_test_ext, new_unchecked_test_ext, Test};
+use frame_support::{
+    traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+    BoundedVec,
+};
+
+// The inventories of versions 0 and 1 hold unregistered NFTs, so their tests skip the invariants
+// of the current version.
+#[test]
+fn v1_gives_assets_the_collection_of_their_owner() {
+    new_unchecked_test_ext().execute_with(|| {
+        StorageVersion::new(0).put::<Pallet<Test>>();
+        v0::OwnerAssets::<Test>::insert((1u32, 1u128, 0u128), Some(2u128));
+        v0::OwnerAssets::<Test>::insert((1u32, 1u128, 1u128), Some(3u128));
+        v0::OwnerAssets::<Test>::insert((4u32, 5u128, 0u128), Some(6u128));
+        AssetCount::<Test>::insert((1, 1), 2);
+        AssetCount::<Test>::insert((4, 5), 1);
+
+        MigrateV0ToV1::<Test>::on_runtime_upgrade();
+
+        assert_eq!(OwnerAssets::<Test>::get((1, 1, 0)), Some((1, 2)));
+        assert_eq!(OwnerAssets::<Test>::get((1, 1, 1)), Some((1, 3)));
+        assert_eq!(OwnerAssets::<Test>::get((4, 5, 0)), Some((4, 6)));
+        assert_eq!(Pallet::<Test>::on_chain_storage_version(), 1);
+    });
+}
+
+#[test]
+fn v1_only_runs_on_version_0() {
+    new_unchecked_test_ext().execute_with(|| {
+        StorageVersion::new(1).put::<Pallet<Test>>();
+        OwnerAssets::<Test>::insert((1, 1, 0), Some((2, 2)));
+        AssetCount::<Test>::insert((1, 1), 1);
+
+        MigrateV0ToV1::<Test>::on_runtime_upgrade();
+
+        assert_eq!(OwnerAssets::<Test>::get((1, 1, 0)), Some((2, 2)));
+    });
+}
+
+/// The registration of an NFT of `collection` as of version 1, with the tag `tag`.
+fn v1_info(collection: Option<u32>, tag: &[u8]) -> NftInfo<Test> {
+    NftInfo {
+        collec_id: collection,
+        owner_id: None,
+        tags: BoundedVec::try_from(vec![BoundedVec::try_from(tag.to_vec()).unwrap()]).unwrap(),
+    }
+}
+
+#[test]
+fn v2_keys_registrations_by_collection() {
+    new_test_ext().execute_with(|| {
+        StorageVersion::new(1).put::<Pallet<Test>>();
+        v1::NftInfos::<Test>::insert(1u128, v1_info(Some(1), b"owner"));
+        v1::NftInfos::<Test>::insert(2u128, v1_info(Some(2), b"weapon"));
+        v1::NftInfos::<Test>::insert(3u128, v1_info(None, b"weapon"));
+
+        MigrateV1ToV2::<Test>::on_runtime_upgrade();
+
+        assert!(NftInfos::<Test>::get(1, 1).is_type("owner"));
+        assert!(NftInfos::<Test>::get(2, 2).is_type("weapon"));
+        assert_eq!(NftInfos::<Test>::iter().count(), 2);
+        assert!(!v1::NftInfos::<Test>::contains_key(3u128));
+        assert_eq!(Pallet::<Test>::on_chain_storage_version(), 2);
+    });
+}
+
+#[test]
+fn v2_only_runs_on_version_1() {
+    new_test_ext().execute_with(|| {
+        StorageVersion::new(2).put::<Pallet<Test>>();
+        NftInfos::<Test>::insert(1, 1, v1_info(Some(1), b"owner"));
+
+        MigrateV1ToV2::<Test>::on_runtime_upgrade();
+
+        assert!(NftInfos::<Test>::get(1, 1).is_type("owner"));
+    });
+}
+
+#[cfg(feature = "try-runtime")]
+#[test]
+fn v2_passes_its_try_runtime_checks() {
+    new_test_ext().execute_with(|| {
+        StorageVersion::new(1).put::<Pallet<Test>>();
+        v1::NftInfos::<Test>::insert(1u128, v1_info(Some(1), b"owner"));
+        v1::NftInfos::<Test>::insert(2u128, v1_info(Some(2), b"weapon"));
+
+        assert!(MigrateV1ToV2::<Test>::try_on_runtime_upgrade(true).is_ok());
+        assert_eq!(NftInfos::<Test>::iter().count(), 2);
+    });
+}
+
+#[test]
+fn v3_counts_owners_and_registers_their_nfts() {
+    new_test_ext().execute_with(|| {
+        StorageVersion::new(2).put::<Pallet<Test>>();
+        NftInfos::<Test>::insert(1, 1, v1_info(Some(1), b"owner"));
+        NftInfos::<Test>::insert(2, 2, v1_info(Some(2), b"weapon"));
+        // Two owners hold the weapon 2, and the owner 1 also holds the unregistered item 3.
+        OwnerAssets::<Test>::insert((1, 1, 0), Some((2, 2)));
+        OwnerAssets::<Test>::insert((1, 1, 1), Some((2, 3)));
+        OwnerAssets::<Test>::insert((1, 4, 0), Some((2, 2)));
+        AssetCount::<Test>::insert((1, 1), 2);
+        AssetCount::<Test>::insert((1, 4), 1);
+
+        MigrateV2ToV3::<Test>::on_runtime_upgrade();
+
+        assert_eq!(OwnerCount::<Test>::get((2, 2)), 2);
+        assert_eq!(OwnerCount::<Test>::get((2, 3)), 1);
+        assert_eq!(OwnerCount::<Test>::get((1, 1)), 0);
+        assert!(NftInfos::<Test>::get(2, 2).is_type("weapon"));
+        assert_eq!(NftInfos::<Test>::get(2, 3).collec_id, Some(2));
+        assert!(NftInfos::<Test>::get(2, 3).tags.is_empty());
+        assert_eq!(NftInfos::<Test>::get(1, 4).collec_id, Some(1));
+        assert_eq!(Pallet::<Test>::on_chain_storage_version(), 3);
+    });
+}
+
+#[test]
+fn v3_removes_the_slots_past_the_asset_count() {
+    new_test_ext().execute_with(|| {
+        StorageVersion::new(2).put::<Pallet<Test>>();
+        // The owner 1 holds the item 2, and a removal left the item 3 in the slot past its count.
+        OwnerAssets::<Test>::insert((1, 1, 0), Some((2, 2)));
+        OwnerAssets::<Test>::insert((1, 1, 1), Some((2, 3)));
+        AssetCount::<Test>::insert((1, 1), 1);
+
+        MigrateV2ToV3::<Test>::on_runtime_upgrade();
+
+        assert_eq!(OwnerCount::<Test>::get((2, 2)), 1);
+        assert_eq!(OwnerCount::<Test>::get((2, 3)), 0);
+        assert_eq!(OwnerAssets::<Test>::get((1, 1, 0)), Some((2, 2)));
+        assert!(!OwnerAssets::<Test>::contains_key((1, 1, 1)));
+        assert!(!NftInfos::<Test>::contains_key(2, 3));
+    });
+}
+
+#[test]
+fn v3_only_runs_on_version_2() {
+    new_unchecked_test_ext().execute_with(|| {
+        StorageVersion::new(3).put::<Pallet<Test>>();
+        OwnerAssets::<Test>::insert((1, 1, 0), Some((2, 2)));
+        AssetCount::<Test>::insert((1, 1), 1);
+
+        MigrateV2ToV3::<Test>::on_runtime_upgrade();
+
+        assert_eq!(OwnerCount::<Test>::get((2, 2)), 0);
+        assert!(!NftInfos::<Test>::contains_key(2, 2));
+    });
+}
+
+#[cfg(feature = "try-runtime")]
+#[test]
+fn v3_passes_its_try_runtime_checks() {
+    new_test_ext().execute_with(|| {
+        StorageVersion::new(2).put::<Pallet<Test>>();
+        OwnerAssets::<Test>::insert((1, 1, 0), Some((2, 2)));
+        OwnerAssets::<Test>::insert((1, 4, 0), Some((2, 2)));
+        OwnerAssets::<Test>::insert((1, 4, 1), Some((2, 3)));
+        AssetCount::<Test>::insert((1, 1), 1);
+        AssetCount::<Test>::insert((1, 4), 1);
+
+        assert!(MigrateV2ToV3::<Test>::try_on_runtime_upgrade(true).is_ok());
+        assert_eq!(OwnerCount::<Test>::get((2, 2)), 2);
+        assert_eq!(OwnerCount::<Test>::get((2, 3)), 0);
+    });
+}
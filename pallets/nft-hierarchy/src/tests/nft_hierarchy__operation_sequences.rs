@@ -0,0 +1,399 @@
+#![allow(non_snake_case)]
+
+//! Random sequences of register, set, unset and get calls, as well as transfers of the NFTs
+//! between accounts, checked after each step against a reference model of the relationships and
+//! against the invariants of the pallet.
+
+use super::super::*;
+use crate::mock::{mint, new_test_ext, RuntimeOrigin, System, Test, Uniques};
+use frame_support::{pallet_prelude::DispatchError, BoundedVec};
+use proptest::prelude::*;
+use std::collections::BTreeMap;
+
+const HEROES: u32 = 1;
+const ARMORY: u32 = 2;
+
+/// The NFTs of the sequences, with the same item ids in both collections so that an NFT is never
+/// mistaken for its namesake in the other one.
+const NFTS: [(u32, u128); 8] = [
+    (HEROES, 1),
+    (HEROES, 2),
+    (HEROES, 3),
+    (HEROES, 4),
+    (ARMORY, 1),
+    (ARMORY, 2),
+    (ARMORY, 3),
+    (ARMORY, 4),
+];
+
+/// The accounts that hold the NFTs and make the calls.
+const ACCOUNTS: [u64; 3] = [1, 2, 3];
+
+/// Bound of the positions in an inventory that the sequences pick assets from.
+const MAX_SLOT: usize = 8;
+
+type NftId = (u32, u128);
+
+/// The account that makes a call: mostly the holder of the NFT the call manages, so that the
+/// sequences go past the checks of permissions, but also any other account.
+#[derive(Clone, Copy, Debug)]
+enum Caller {
+    Holder,
+    Account(u64),
+}
+
+/// The asset of a call: one the owner has, picked by its position in the inventory, so that the
+/// sequences remove relationships that exist, or any NFT.
+#[derive(Clone, Copy, Debug)]
+enum Asset {
+    Owned(usize),
+    Any(usize),
+}
+
+#[derive(Clone, Debug)]
+enum Operation {
+    Register {
+        who: Caller,
+        nft: usize,
+        owner: bool,
+    },
+    Set {
+        who: Caller,
+        owner: usize,
+        asset: usize,
+        hint: u32,
+    },
+    Unset {
+        who: Caller,
+        owner: usize,
+        asset: Asset,
+        hint: u32,
+    },
+    Get {
+        who: u64,
+        owner: usize,
+        start: u128,
+        num_assets: u32,
+    },
+    Transfer {
+        nft: usize,
+        to: u64,
+    },
+}
+
+fn caller() -> impl Strategy<Value = Caller> {
+    prop_oneof![
+        3 => Just(Caller::Holder),
+        1 => proptest::sample::select(ACCOUNTS.to_vec()).prop_map(Caller::Account),
+    ]
+}
+
+fn asset() -> impl Strategy<Value = Asset> {
+    prop_oneof![
+        3 => (0..MAX_SLOT).prop_map(Asset::Owned),
+        1 => (0..NFTS.len()).prop_map(Asset::Any),
+    ]
+}
+
+fn operation() -> impl Strategy<Value = Operation> {
+    let account = proptest::sample::select(ACCOUNTS.to_vec());
+    let nft = 0..NFTS.len();
+    prop_oneof![
+        2 => (caller(), nft.clone(), any::<bool>())
+            .prop_map(|(who, nft, owner)| Operation::Register { who, nft, owner }),
+        4 => (caller(), nft.clone(), nft.clone(), 0..10u32)
+            .prop_map(|(who, owner, asset, hint)| Operation::Set { who, owner, asset, hint }),
+        3 => (caller(), nft.clone(), asset(), 0..10u32)
+            .prop_map(|(who, owner, asset, hint)| Operation::Unset { who, owner, asset, hint }),
+        1 => (account.clone(), nft.clone(), 0..10u128, 0..12u32).prop_map(
+            |(who, owner, start, num_assets)| Operation::Get { who, owner, start, num_assets }
+        ),
+        1 => (nft, account).prop_map(|(nft, to)| Operation::Transfer { nft, to }),
+    ]
+}
+
+/// What the relationships should be: who holds each NFT, which ones are registered and whether
+/// as owners, and the assets each owner has, in the order of its slots.
+struct Model {
+    holders: BTreeMap<NftId, u64>,
+    registered: BTreeMap<NftId, bool>,
+    inventories: BTreeMap<NftId, Vec<NftId>>,
+}
+
+impl Model {
+    fn new() -> Self {
+        let holders = NFTS
+            .iter()
+            .enumerate()
+            .map(|(index, nft)| (*nft, ACCOUNTS[index % ACCOUNTS.len()]))
+            .collect();
+        Model {
+            holders,
+            registered: BTreeMap::new(),
+            inventories: BTreeMap::new(),
+        }
+    }
+
+    /// The account that makes a call on `nft` as `caller`.
+    fn account(&self, caller: Caller, nft: &NftId) -> u64 {
+        match caller {
+            Caller::Holder => self.holders[nft],
+            Caller::Account(account) => account,
+        }
+    }
+
+    /// The NFT that `asset` refers to for `owner`.
+    fn asset(&self, owner: &NftId, asset: Asset) -> NftId {
+        let inventory = self
+            .inventories
+            .get(owner)
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+        match asset {
+            Asset::Owned(slot) if !inventory.is_empty() => inventory[slot % inventory.len()],
+            Asset::Owned(slot) | Asset::Any(slot) => NFTS[slot % NFTS.len()],
+        }
+    }
+
+    fn ensure_holder(&self, who: u64, nft: &NftId) -> Result<(), DispatchError> {
+        match self.holders.get(nft) == Some(&who) {
+            true => Ok(()),
+            false => Err(Error::<Test>::NotOwner.into()),
+        }
+    }
+
+    fn register(&mut self, who: u64, nft: NftId, owner: bool) -> Result<(), DispatchError> {
+        if !self.holders.contains_key(&nft) {
+            return Err(Error::<Test>::TokenNotFound.into());
+        }
+        self.ensure_holder(who, &nft)?;
+        self.registered.insert(nft, owner);
+        Ok(())
+    }
+
+    fn set(
+        &mut self,
+        who: u64,
+        owner: NftId,
+        asset: NftId,
+        hint: u32,
+    ) -> Result<(), DispatchError> {
+        self.ensure_holder(who, &owner)?;
+        if self.registered.get(&owner) != Some(&true) {
+            return Err(Error::<Test>::WrongNft.into());
+        }
+        if self.holders.get(&asset) != self.holders.get(&owner) {
+            return Err(Error::<Test>::ConsentRequired.into());
+        }
+        if !self.registered.contains_key(&asset) {
+            return Err(Error::<Test>::NotRegistered.into());
+        }
+        let inventory = self.inventories.entry(owner).or_default();
+        if inventory.len() > hint as usize {
+            return Err(Error::<Test>::InventoryHintTooLow.into());
+        }
+        if inventory.len() >= <Test as Config>::MaxInventorySize::get() as usize {
+            return Err(Error::<Test>::ExceededMaxInventorySize.into());
+        }
+        if inventory.contains(&asset) {
+            return Err(Error::<Test>::AlreadyOwner.into());
+        }
+        inventory.push(asset);
+        Ok(())
+    }
+
+    fn unset(
+        &mut self,
+        who: u64,
+        owner: NftId,
+        asset: NftId,
+        hint: u32,
+    ) -> Result<(), DispatchError> {
+        self.ensure_holder(who, &owner)?;
+        let inventory = self.inventories.entry(owner).or_default();
+        if inventory.len() > hint as usize {
+            return Err(Error::<Test>::InventoryHintTooLow.into());
+        }
+        let index = inventory
+            .iter()
+            .position(|owned| *owned == asset)
+            .ok_or(Error::<Test>::OwnershipNotFound)?;
+        // The last relationship moves to the slot of the one removed.
+        inventory.swap_remove(index);
+        Ok(())
+    }
+
+    fn get(&self, owner: NftId, start: u128, num_assets: u32) -> Result<Vec<NftId>, DispatchError> {
+        if num_assets > <Test as Config>::MaxAssetsPerTransaction::get() {
+            return Err(Error::<Test>::ExceededMaxAssetsPerQuery.into());
+        }
+        let inventory = self.inventories.get(&owner).cloned().unwrap_or_default();
+        Ok(inventory
+            .into_iter()
+            .skip(start as usize)
+            .take(num_assets as usize)
+            .collect())
+    }
+}
+
+fn tags(owner: bool) -> Tags<Test> {
+    let tag: &[u8] = if owner { b"owner" } else { b"weapon" };
+    BoundedVec::try_from(vec![BoundedVec::try_from(tag.to_vec()).unwrap()]).unwrap()
+}
+
+/// Apply `operation` to both the pallet and the model, and check that they agree on the outcome.
+fn apply(model: &mut Model, operation: &Operation) {
+    match *operation {
+        Operation::Register { who, nft, owner } => {
+            let (collection, item) = NFTS[nft];
+            let who = model.account(who, &NFTS[nft]);
+            let result = Pallet::<Test>::register_asset(
+                RuntimeOrigin::signed(who),
+                collection,
+                item,
+                tags(owner),
+            );
+            assert_eq!(
+                result,
+                model.register(who, NFTS[nft], owner),
+                "{operation:?}"
+            );
+        }
+        Operation::Set {
+            who,
+            owner,
+            asset,
+            hint,
+        } => {
+            let ((collection, item), (asset_collection, asset_item)) = (NFTS[owner], NFTS[asset]);
+            let who = model.account(who, &NFTS[owner]);
+            let result = Pallet::<Test>::set_ownership(
+                RuntimeOrigin::signed(who),
+                collection,
+                item,
+                asset_collection,
+                asset_item,
+                hint,
+            );
+            let expected = model.set(who, NFTS[owner], NFTS[asset], hint);
+            assert_eq!(
+                result.map(|_| ()).map_err(|error| error.error),
+                expected,
+                "{operation:?}"
+            );
+        }
+        Operation::Unset {
+            who,
+            owner,
+            asset,
+            hint,
+        } => {
+            let (collection, item) = NFTS[owner];
+            let who = model.account(who, &NFTS[owner]);
+            let asset = model.asset(&NFTS[owner], asset);
+            let result = Pallet::<Test>::unset_ownership(
+                RuntimeOrigin::signed(who),
+                collection,
+                item,
+                asset.0,
+                asset.1,
+                hint,
+            );
+            let expected = model.unset(who, NFTS[owner], asset, hint);
+            assert_eq!(
+                result.map(|_| ()).map_err(|error| error.error),
+                expected,
+                "{operation:?}"
+            );
+        }
+        Operation::Get {
+            who,
+            owner,
+            start,
+            num_assets,
+        } => {
+            let (collection, item) = NFTS[owner];
+            let result = Pallet::<Test>::get_owned_assets(
+                RuntimeOrigin::signed(who),
+                collection,
+                item,
+                start,
+                num_assets,
+            );
+            match model.get(NFTS[owner], start, num_assets) {
+                Ok(assets) => {
+                    assert!(result.is_ok(), "{operation:?}");
+                    System::assert_last_event(
+                        Event::<Test>::AssetsRetrieved {
+                            owner: NFTS[owner],
+                            assets: BoundedVec::try_from(assets).unwrap(),
+                        }
+                        .into(),
+                    );
+                }
+                Err(error) => {
+                    assert_eq!(
+                        result.map_err(|error| error.error),
+                        Err(error),
+                        "{operation:?}"
+                    )
+                }
+            }
+        }
+        Operation::Transfer { nft, to } => {
+            let (collection, item) = NFTS[nft];
+            let holder = model.holders[&NFTS[nft]];
+            Uniques::transfer(RuntimeOrigin::signed(holder), collection, item, to).unwrap();
+            model.holders.insert(NFTS[nft], to);
+        }
+    }
+}
+
+/// Check that the storage of the pallet matches the model.
+fn check(model: &Model) {
+    for (collection, item) in NFTS {
+        let inventory = model
+            .inventories
+            .get(&(collection, item))
+            .cloned()
+            .unwrap_or_default();
+        assert_eq!(
+            AssetCount::<Test>::get((collection, item)),
+            inventory.len() as u128
+        );
+        for (index, asset) in inventory.iter().enumerate() {
+            assert_eq!(
+                OwnerAssets::<Test>::get((collection, item, index as u128)),
+                Some(*asset)
+            );
+        }
+
+        let info = NftInfos::<Test>::get(collection, item);
+        match model.registered.get(&(collection, item)) {
+            Some(owner) => {
+                assert_eq!(info.collec_id, Some(collection));
+                assert_eq!(info.is_type("owner"), *owner);
+            }
+            None => assert_eq!(info.collec_id, None),
+        }
+    }
+    Pallet::<Test>::do_try_state().unwrap();
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(64))]
+
+    #[test]
+    fn agree_with_the_model(operations in proptest::collection::vec(operation(), 1..60)) {
+        new_test_ext().execute_with(|| {
+            let mut model = Model::new();
+            for (nft, holder) in model.holders.iter() {
+                mint(nft.0, nft.1, *holder);
+            }
+            for operation in operations.iter() {
+                apply(&mut model, operation);
+                check(&model);
+            }
+        });
+    }
+}
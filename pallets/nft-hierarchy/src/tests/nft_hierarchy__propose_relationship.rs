@@ -0,0 +1,214 @@
+#![allow(non_snake_case)]
+
+use super::super::*;
+use crate::mock::{mint, mint_registered, new_test_ext, RuntimeOrigin, System, Test};
+use frame_support::{assert_noop, assert_ok, BoundedVec};
+
+pub(crate) const HEROES: u32 = 1;
+pub(crate) const ARMORY: u32 = 2;
+pub(crate) const ALICE: u64 = 1;
+pub(crate) const BOB: u64 = 2;
+pub(crate) const SWORD: u128 = 10;
+
+/// Register the characters of Alice (1) and Bob (2) as owners, and the sword of Alice.
+pub(crate) fn alice_and_bob() -> (u128, u128) {
+    for character in [1u128, 2] {
+        mint(HEROES, character, character as u64);
+        let tags =
+            BoundedVec::try_from(vec![BoundedVec::try_from(b"owner".to_vec()).unwrap()]).unwrap();
+        assert_ok!(Pallet::<Test>::register_asset(
+            RuntimeOrigin::signed(character as u64),
+            HEROES,
+            character,
+            tags
+        ));
+    }
+    mint_registered(ARMORY, SWORD, ALICE, b"weapon");
+    (1, 2)
+}
+
+/// Alice proposes to give her sword to the character of Bob, until the block 5.
+pub(crate) fn alice_gives_the_sword() -> u128 {
+    let (_, bob_character) = alice_and_bob();
+    assert_ok!(Pallet::<Test>::propose_relationship(
+        RuntimeOrigin::signed(ALICE),
+        HEROES,
+        bob_character,
+        ARMORY,
+        SWORD,
+        5
+    ));
+    bob_character
+}
+
+#[test]
+fn works() {
+    new_test_ext().execute_with(|| {
+        let bob_character = alice_gives_the_sword();
+
+        System::assert_last_event(
+            Event::<Test>::RelationshipProposed {
+                owner: (HEROES, bob_character),
+                asset: (ARMORY, SWORD),
+                proposer: ALICE,
+                expiry: 5,
+            }
+            .into(),
+        );
+        assert_eq!(
+            Proposals::<Test>::get((HEROES, bob_character, ARMORY, SWORD)),
+            Some(RelationshipProposal {
+                proposer: ALICE,
+                by_owner: false,
+                expiry: 5
+            })
+        );
+        // Nothing changes until Bob accepts.
+        assert_eq!(AssetCount::<Test>::get((HEROES, bob_character)), 0);
+    });
+}
+
+#[test]
+fn works_from_the_side_of_the_owner() {
+    new_test_ext().execute_with(|| {
+        let (alice_character, bob_character) = alice_and_bob();
+
+        // Alice takes the character of Bob as an apprentice.
+        assert_ok!(Pallet::<Test>::propose_relationship(
+            RuntimeOrigin::signed(ALICE),
+            HEROES,
+            alice_character,
+            HEROES,
+            bob_character,
+            5
+        ));
+        assert_eq!(
+            Proposals::<Test>::get((HEROES, alice_character, HEROES, bob_character)),
+            Some(RelationshipProposal {
+                proposer: ALICE,
+                by_owner: true,
+                expiry: 5
+            })
+        );
+    });
+}
+
+#[test]
+fn is_required_between_accounts() {
+    new_test_ext().execute_with(|| {
+        let (_, bob_character) = alice_and_bob();
+
+        // Bob cannot just take the sword of Alice.
+        assert_noop!(
+            Pallet::<Test>::set_ownership(
+                RuntimeOrigin::signed(BOB),
+                HEROES,
+                bob_character,
+                ARMORY,
+                SWORD,
+                0
+            ),
+            Error::<Test>::ConsentRequired
+        );
+        assert_noop!(
+            Pallet::<Test>::set_ownership_batch(
+                RuntimeOrigin::signed(BOB),
+                HEROES,
+                bob_character,
+                BoundedVec::truncate_from(vec![(ARMORY, SWORD)]),
+                0
+            ),
+            Error::<Test>::ConsentRequired
+        );
+    });
+}
+
+#[test]
+fn fails_if_not_on_either_side() {
+    new_test_ext().execute_with(|| {
+        let (_, bob_character) = alice_and_bob();
+
+        assert_noop!(
+            Pallet::<Test>::propose_relationship(
+                RuntimeOrigin::signed(3),
+                HEROES,
+                bob_character,
+                ARMORY,
+                SWORD,
+                5
+            ),
+            Error::<Test>::NotOwner
+        );
+    });
+}
+
+#[test]
+fn fails_with_a_wrong_proposal() {
+    new_test_ext().execute_with(|| {
+        let bob_character = alice_gives_the_sword();
+
+        assert_noop!(
+            Pallet::<Test>::propose_relationship(
+                RuntimeOrigin::signed(BOB),
+                HEROES,
+                bob_character,
+                ARMORY,
+                SWORD,
+                5
+            ),
+            Error::<Test>::AlreadyExists
+        );
+        assert_noop!(
+            Pallet::<Test>::propose_relationship(
+                RuntimeOrigin::signed(ALICE),
+                HEROES,
+                bob_character,
+                ARMORY,
+                SWORD + 1,
+                1
+            ),
+            Error::<Test>::NotOwner
+        );
+        mint(ARMORY, SWORD + 1, ALICE);
+        assert_noop!(
+            Pallet::<Test>::propose_relationship(
+                RuntimeOrigin::signed(ALICE),
+                HEROES,
+                bob_character,
+                ARMORY,
+                SWORD + 1,
+                5
+            ),
+            Error::<Test>::NotRegistered
+        );
+        assert_ok!(Pallet::<Test>::register_asset(
+            RuntimeOrigin::signed(ALICE),
+            ARMORY,
+            SWORD + 1,
+            BoundedVec::try_from(vec![BoundedVec::try_from(b"weapon".to_vec()).unwrap()]).unwrap()
+        ));
+        assert_noop!(
+            Pallet::<Test>::propose_relationship(
+                RuntimeOrigin::signed(ALICE),
+                HEROES,
+                bob_character,
+                ARMORY,
+                SWORD + 1,
+                1
+            ),
+            Error::<Test>::ExpiryInThePast
+        );
+        // The sword cannot own anything.
+        assert_noop!(
+            Pallet::<Test>::propose_relationship(
+                RuntimeOrigin::signed(BOB),
+                ARMORY,
+                SWORD,
+                HEROES,
+                bob_character,
+                5
+            ),
+            Error::<Test>::WrongNft
+        );
+    });
+}
@@ -0,0 +1,155 @@
+#![allow(non_snake_case)]
+
+use super::super::*;
+use crate::mock::{mint, mint_registered, new_test_ext, RuntimeOrigin, System, Test};
+use frame_support::{assert_noop, assert_ok, BoundedVec};
+
+const HEROES: u32 = 1;
+const ARMORY: u32 = 2;
+const SWORD: u128 = 10;
+
+/// Register the characters 1 and 2 as owners, both held by the account 1, as well as the sword.
+fn two_characters() {
+    for character in [1u128, 2] {
+        mint(HEROES, character, 1);
+        let tags =
+            BoundedVec::try_from(vec![BoundedVec::try_from(b"owner".to_vec()).unwrap()]).unwrap();
+        assert_ok!(Pallet::<Test>::register_asset(
+            RuntimeOrigin::signed(1),
+            HEROES,
+            character,
+            tags
+        ));
+    }
+    mint_registered(ARMORY, SWORD, 1, b"weapon");
+}
+
+/// Give the sword to `character` and take it back at the next block.
+fn pass_the_sword(character: u128) {
+    assert_ok!(Pallet::<Test>::set_ownership(
+        RuntimeOrigin::signed(1),
+        HEROES,
+        character,
+        ARMORY,
+        SWORD,
+        1
+    ));
+    System::set_block_number(System::block_number() + 1);
+    assert_ok!(Pallet::<Test>::unset_ownership(
+        RuntimeOrigin::signed(1),
+        HEROES,
+        character,
+        ARMORY,
+        SWORD,
+        1
+    ));
+    System::set_block_number(System::block_number() + 1);
+}
+
+fn entry(character: u128, change: CustodyChange, at: u64) -> ProvenanceOf<Test> {
+    ProvenanceEntry { owner: (HEROES, character), change, at }
+}
+
+#[test]
+fn the_ownership_changes_are_recorded() {
+    new_test_ext().execute_with(|| {
+        two_characters();
+        pass_the_sword(1);
+        pass_the_sword(2);
+
+        assert_eq!(
+            Pallet::<Test>::provenance(ARMORY, SWORD, 0, 10),
+            vec![
+                (0, entry(1, CustodyChange::Acquired, 1)),
+                (1, entry(1, CustodyChange::Released, 2)),
+                (2, entry(2, CustodyChange::Acquired, 3)),
+                (3, entry(2, CustodyChange::Released, 4)),
+            ]
+        );
+        // One page at a time.
+        assert_eq!(
+            Pallet::<Test>::provenance(ARMORY, SWORD, 1, 2),
+            vec![
+                (1, entry(1, CustodyChange::Released, 2)),
+                (2, entry(2, CustodyChange::Acquired, 3)),
+            ]
+        );
+    });
+}
+
+#[test]
+fn only_the_latest_entries_are_retained() {
+    new_test_ext().execute_with(|| {
+        two_characters();
+        pass_the_sword(1);
+        pass_the_sword(2);
+        pass_the_sword(1);
+
+        // The provenance of the mock retains 4 entries.
+        assert_eq!(ProvenanceBounds::<Test>::get((ARMORY, SWORD)), (2, 6));
+        assert!(!Provenance::<Test>::contains_key((ARMORY, SWORD, 1)));
+        assert_eq!(
+            Pallet::<Test>::provenance(ARMORY, SWORD, 0, 10),
+            vec![
+                (2, entry(2, CustodyChange::Acquired, 3)),
+                (3, entry(2, CustodyChange::Released, 4)),
+                (4, entry(1, CustodyChange::Acquired, 5)),
+                (5, entry(1, CustodyChange::Released, 6)),
+            ]
+        );
+    });
+}
+
+#[test]
+fn works() {
+    new_test_ext().execute_with(|| {
+        two_characters();
+        pass_the_sword(1);
+        pass_the_sword(2);
+
+        // The entries of the blocks 1 and 2 are retained for 10 blocks.
+        System::set_block_number(12);
+        assert_ok!(Pallet::<Test>::prune_provenance(RuntimeOrigin::signed(3), ARMORY, SWORD, 10));
+        System::assert_last_event(
+            Event::<Test>::ProvenancePruned { asset: (ARMORY, SWORD), entries: 2 }.into(),
+        );
+
+        assert_eq!(ProvenanceBounds::<Test>::get((ARMORY, SWORD)), (2, 4));
+        assert_eq!(
+            Pallet::<Test>::provenance(ARMORY, SWORD, 0, 10),
+            vec![
+                (2, entry(2, CustodyChange::Acquired, 3)),
+                (3, entry(2, CustodyChange::Released, 4)),
+            ]
+        );
+    });
+}
+
+#[test]
+fn prunes_at_most_the_entries_asked() {
+    new_test_ext().execute_with(|| {
+        two_characters();
+        pass_the_sword(1);
+
+        System::set_block_number(20);
+        assert_ok!(Pallet::<Test>::prune_provenance(RuntimeOrigin::signed(3), ARMORY, SWORD, 1));
+        assert_eq!(ProvenanceBounds::<Test>::get((ARMORY, SWORD)), (1, 2));
+    });
+}
+
+#[test]
+fn fails_with_nothing_to_prune() {
+    new_test_ext().execute_with(|| {
+        two_characters();
+        pass_the_sword(1);
+
+        assert_noop!(
+            Pallet::<Test>::prune_provenance(RuntimeOrigin::signed(3), ARMORY, SWORD, 10),
+            Error::<Test>::NothingToPrune
+        );
+        assert_noop!(
+            Pallet::<Test>::prune_provenance(RuntimeOrigin::signed(3), ARMORY, SWORD + 1, 10),
+            Error::<Test>::NothingToPrune
+        );
+    });
+}
@@ -1,8 +1,8 @@
 #![allow(non_snake_case)]
 
 use super::super::*;
-use crate::{mock::{new_test_ext, RuntimeOrigin, System, Test}};
-use frame_support::{assert_ok, BoundedVec};
+use crate::{mock::{mint, new_test_ext, RuntimeOrigin, System, Test}};
+use frame_support::{assert_noop, assert_ok, BoundedVec};
 
 #[test]
 fn works() {
@@ -10,6 +10,7 @@ fn works() {
         let collec_id = 1u32;
         let asset_id = 2u128;
         let who = 1u64;
+        mint(collec_id, asset_id, who);
 
         // Create NFT for the owner.
         let tags: BoundedVec<BoundedVec<u8, <Test as pallet::Config>::StringLimit>, <Test as pallet::Config>::TypeLimit> = 
@@ -24,7 +25,7 @@ fn works() {
         ));
 
         // Verify the NFT has been registered.
-        let info = NftInfos::<Test>::get(asset_id);
+        let info = NftInfos::<Test>::get(collec_id, asset_id);
         assert_eq!(info.collec_id, Some(collec_id));
         assert_eq!(info.owner_id, None);
         assert_eq!(info.tags, tags);
@@ -39,4 +40,43 @@ fn works() {
             .into(),
         );
     });
-}
\ No newline at end of file
+}
+fn entity() -> Tags<Test> {
+    BoundedVec::try_from(vec![BoundedVec::try_from(b"entity".to_vec()).unwrap()]).unwrap()
+}
+
+#[test]
+fn fails_for_a_missing_nft() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Pallet::<Test>::register_asset(RuntimeOrigin::signed(1), 1, 2, entity()),
+            Error::<Test>::TokenNotFound
+        );
+    });
+}
+
+#[test]
+fn fails_if_not_holder() {
+    new_test_ext().execute_with(|| {
+        let (collec_id, asset_id) = (1u32, 2u128);
+        let (holder, stranger) = (1u64, 2u64);
+        mint(collec_id, asset_id, holder);
+
+        // Nobody but the holder tags an NFT, even before its first registration.
+        assert_noop!(
+            Pallet::<Test>::register_asset(
+                RuntimeOrigin::signed(stranger),
+                collec_id,
+                asset_id,
+                entity()
+            ),
+            Error::<Test>::NotOwner
+        );
+        assert_ok!(Pallet::<Test>::register_asset(
+            RuntimeOrigin::signed(holder),
+            collec_id,
+            asset_id,
+            entity()
+        ));
+    });
+}
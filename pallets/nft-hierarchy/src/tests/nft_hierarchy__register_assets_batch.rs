@@ -0,0 +1,64 @@
+#![allow(non_snake_case)]
+
+use super::super::*;
+use crate::mock::{mint, new_test_ext, RuntimeOrigin, System, Test};
+use frame_support::{assert_noop, assert_ok, BoundedVec};
+
+#[test]
+fn works() {
+    new_test_ext().execute_with(|| {
+        let collec_id = 1u32;
+        let who = 1u64;
+        for asset_id in 2..=4u128 {
+            mint(collec_id, asset_id, who);
+        }
+
+        let tags: BoundedVec<BoundedVec<u8, <Test as pallet::Config>::StringLimit>, <Test as pallet::Config>::TypeLimit> = 
+            BoundedVec::try_from(vec![BoundedVec::try_from(b"entity".to_vec()).unwrap()]).unwrap();
+        let assets = BoundedVec::try_from(
+            (2..=4).map(|asset_id| (asset_id, tags.clone())).collect::<Vec<_>>()
+        ).unwrap();
+        assert_ok!(Pallet::<Test>::register_assets_batch(
+            RuntimeOrigin::signed(who),
+            collec_id,
+            assets
+        ));
+
+        // Verify every asset and its event.
+        for asset_id in 2..=4u128 {
+            let info = NftInfos::<Test>::get(collec_id, asset_id);
+            assert_eq!(info.collec_id, Some(collec_id));
+            assert!(info.is_type("entity"));
+            System::assert_has_event(
+                Event::<Test>::NftRegistered {
+                    who,
+                    collection: collec_id,
+                    asset: asset_id,
+                }
+                .into(),
+            );
+        }
+    });
+}
+
+#[test]
+fn registers_none_if_one_is_missing() {
+    new_test_ext().execute_with(|| {
+        let collec_id = 1u32;
+        let who = 1u64;
+        for asset_id in 2..=3u128 {
+            mint(collec_id, asset_id, who);
+        }
+
+        let tags: Tags<Test> =
+            BoundedVec::try_from(vec![BoundedVec::try_from(b"entity".to_vec()).unwrap()]).unwrap();
+        let assets = BoundedVec::try_from(
+            (2..=4).map(|asset_id| (asset_id, tags.clone())).collect::<Vec<_>>()
+        ).unwrap();
+        assert_noop!(
+            Pallet::<Test>::register_assets_batch(RuntimeOrigin::signed(who), collec_id, assets),
+            Error::<Test>::TokenNotFound
+        );
+        assert!(NftInfos::<Test>::get(collec_id, 2).collec_id.is_none());
+    });
+}
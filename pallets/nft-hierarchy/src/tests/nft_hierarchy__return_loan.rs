@@ -0,0 +1,138 @@
+#![allow(non_snake_case)]
+
+use super::super::*;
+use super::nft_hierarchy__lend::{lend_and_accept, two_characters_and_a_sword, ARMORY, HEROES, SWORD};
+use crate::mock::{mint_registered, new_test_ext, NftHierarchy, RuntimeOrigin, System, Test};
+use frame_support::{
+    assert_noop, assert_ok,
+    traits::{Get, Hooks},
+    weights::{RuntimeDbWeight, Weight},
+};
+
+/// Lend the sword of the character 1 to the character 2 until the block 5.
+fn lent() -> (u128, u128) {
+    let (lender, borrower) = two_characters_and_a_sword();
+    lend_and_accept(SWORD, 5);
+    (lender, borrower)
+}
+
+fn assert_returned(lender: u128, borrower: u128) {
+    assert!(Loans::<Test>::get((ARMORY, SWORD)).is_none());
+    assert_eq!(AssetCount::<Test>::get((HEROES, borrower)), 0);
+    assert_eq!(AssetCount::<Test>::get((HEROES, lender)), 1);
+    assert_eq!(
+        OwnerAssets::<Test>::get((HEROES, lender, 0)),
+        Some((ARMORY, SWORD))
+    );
+    assert_eq!(LentCount::<Test>::get((HEROES, lender)), 0);
+}
+
+#[test]
+fn works() {
+    new_test_ext().execute_with(|| {
+        let (lender, borrower) = lent();
+
+        assert_ok!(Pallet::<Test>::return_loan(
+            RuntimeOrigin::signed(2),
+            ARMORY,
+            SWORD
+        ));
+        System::assert_last_event(
+            Event::<Test>::LoanReturned {
+                lender: (HEROES, lender),
+                borrower: (HEROES, borrower),
+                asset: (ARMORY, SWORD),
+            }
+            .into(),
+        );
+        assert_returned(lender, borrower);
+        assert!(LoanExpiries::<Test>::get(5).is_empty());
+
+        assert_noop!(
+            Pallet::<Test>::return_loan(RuntimeOrigin::signed(2), ARMORY, SWORD),
+            Error::<Test>::LoanNotFound
+        );
+    });
+}
+
+#[test]
+fn only_the_borrower_returns_it_early() {
+    new_test_ext().execute_with(|| {
+        lent();
+
+        assert_noop!(
+            Pallet::<Test>::return_loan(RuntimeOrigin::signed(1), ARMORY, SWORD),
+            Error::<Test>::NotOwner
+        );
+    });
+}
+
+#[test]
+fn expired_loans_are_returned() {
+    new_test_ext().execute_with(|| {
+        let (lender, borrower) = lent();
+
+        System::set_block_number(4);
+        NftHierarchy::on_idle(4, Weight::MAX);
+        assert!(Loans::<Test>::get((ARMORY, SWORD)).is_some());
+
+        System::set_block_number(5);
+        NftHierarchy::on_idle(5, Weight::MAX);
+        assert_returned(lender, borrower);
+        assert_eq!(NextLoanSweep::<Test>::get(), 6);
+    });
+}
+
+#[test]
+fn the_sweep_is_bounded_by_weight() {
+    new_test_ext().execute_with(|| {
+        let (lender, _) = lent();
+        mint_registered(ARMORY, SWORD + 1, 1, b"weapon");
+        assert_ok!(Pallet::<Test>::set_ownership(
+            RuntimeOrigin::signed(1),
+            HEROES,
+            lender,
+            ARMORY,
+            SWORD + 1,
+            0
+        ));
+        lend_and_accept(SWORD + 1, 5);
+
+        // Room for the next block to sweep, the expiries of the block 5 and a single loan.
+        let db: RuntimeDbWeight = <Test as frame_system::Config>::DbWeight::get();
+        let per_loan = <Test as pallet::Config>::WeightInfo::return_loan(
+            2 * <Test as pallet::Config>::MaxInventorySize::get() - 1,
+        );
+        let limit = db.reads_writes(2, 2) + per_loan;
+
+        System::set_block_number(5);
+        let used = NftHierarchy::on_idle(5, limit);
+        assert!(used.all_lte(limit));
+        assert_eq!(LoanExpiries::<Test>::get(5).len(), 1);
+        assert_eq!(NextLoanSweep::<Test>::get(), 5);
+
+        NftHierarchy::on_idle(5, Weight::MAX);
+        assert!(LoanExpiries::<Test>::get(5).is_empty());
+        assert_eq!(AssetCount::<Test>::get((HEROES, lender)), 2);
+    });
+}
+
+#[test]
+fn the_lender_is_not_given_an_asset_it_already_holds() {
+    new_test_ext().execute_with(|| {
+        let (lender, borrower) = lent();
+        // An inventory edited by hand gave the sword back to the lender during the loan.
+        OwnerAssets::<Test>::insert((HEROES, lender, 0), Some((ARMORY, SWORD)));
+        AssetCount::<Test>::insert((HEROES, lender), 1);
+        OwnerCount::<Test>::mutate((ARMORY, SWORD), |owners| *owners += 1);
+
+        assert_ok!(Pallet::<Test>::return_loan(
+            RuntimeOrigin::signed(2),
+            ARMORY,
+            SWORD
+        ));
+        assert_returned(lender, borrower);
+        assert_eq!(OwnerAssets::<Test>::get((HEROES, lender, 1)), None);
+        assert_eq!(OwnerCount::<Test>::get((ARMORY, SWORD)), 1);
+    });
+}
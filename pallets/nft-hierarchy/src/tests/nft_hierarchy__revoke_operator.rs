@@ -0,0 +1,62 @@
+#![allow(non_snake_case)]
+
+use super::super::*;
+use crate::mock::{mint, new_test_ext, RuntimeOrigin, System, Test};
+use frame_support::{assert_noop, assert_ok};
+
+#[test]
+fn works() {
+    new_test_ext().execute_with(|| {
+        let collec_id = 1u32;
+        let owner_id = 1u128;
+        let (holder, operator) = (1u64, 2u64);
+        mint(collec_id, owner_id, holder);
+
+        assert_ok!(Pallet::<Test>::approve_operator(
+            RuntimeOrigin::signed(holder),
+            collec_id,
+            owner_id,
+            operator,
+            OperatorScope {
+                ownership: true,
+                containers: true,
+                tags: true
+            },
+            None
+        ));
+        assert_noop!(
+            Pallet::<Test>::revoke_operator(
+                RuntimeOrigin::signed(operator),
+                collec_id,
+                owner_id,
+                operator
+            ),
+            Error::<Test>::NotOwner
+        );
+
+        assert_ok!(Pallet::<Test>::revoke_operator(
+            RuntimeOrigin::signed(holder),
+            collec_id,
+            owner_id,
+            operator
+        ));
+        assert!(Operators::<Test>::get((collec_id, owner_id, operator)).is_none());
+        System::assert_last_event(
+            Event::<Test>::OperatorRevoked {
+                nft: (collec_id, owner_id),
+                operator,
+            }
+            .into(),
+        );
+
+        assert_noop!(
+            Pallet::<Test>::revoke_operator(
+                RuntimeOrigin::signed(holder),
+                collec_id,
+                owner_id,
+                operator
+            ),
+            Error::<Test>::OperatorNotFound
+        );
+    });
+}
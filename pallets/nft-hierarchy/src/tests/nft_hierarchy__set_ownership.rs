@@ -1,8 +1,9 @@
 #![allow(non_snake_case)]
 
 use super::super::*;
-use crate::mock::{new_test_ext, RuntimeOrigin, System, Test};
-use frame_support::{assert_ok, BoundedVec};
+use crate::mock::{mint, mint_registered, new_test_ext, RuntimeOrigin, System, Test};
+use crate::weights::WeightInfo;
+use frame_support::{assert_noop, assert_ok, BoundedVec};
 
 #[test]
 fn works() {
@@ -11,6 +12,8 @@ fn works() {
         let owner_id = 1u128;
         let asset_id = 2u128;
         let who = 1u64;
+        mint(collec_id, owner_id, who);
+        mint(collec_id, asset_id, who);
 
         // Create NFT for the owner.
         let tags: BoundedVec<BoundedVec<u8, <Test as pallet::Config>::StringLimit>, <Test as pallet::Config>::TypeLimit> = 
@@ -43,19 +46,21 @@ fn works() {
             RuntimeOrigin::signed(who),
             collec_id,
             owner_id,
-            asset_id
+            collec_id,
+            asset_id,
+            0
         ));
         assert_eq!(AssetCount::<Test>::get((collec_id, owner_id)), 1);
         assert_eq!(
             OwnerAssets::<Test>::get((collec_id, owner_id, 0)),
-            Some(asset_id)
+            Some((collec_id, asset_id))
         );
 
         // Verify event.
         System::assert_last_event(
             Event::<Test>::OwnershipAdded {
                 owner: (collec_id, owner_id),
-                asset: asset_id,
+                asset: (collec_id, asset_id),
                 who,
             }
             .into(),
@@ -69,6 +74,7 @@ fn asset_count_for_3_items() {
         let collec_id = 1u32;
         let owner_id = 1u128;
         let who = 1u64;
+        mint(collec_id, owner_id, who);
 
         // Create NFT for the owner.
         let tags: BoundedVec<BoundedVec<u8, <Test as pallet::Config>::StringLimit>, <Test as pallet::Config>::TypeLimit> = 
@@ -90,6 +96,7 @@ fn asset_count_for_3_items() {
                 vec![BoundedVec::try_from(b"entity".to_vec()).unwrap()]
             ).unwrap();
         for i in 2..=4 {
+            mint(collec_id, i, who);
             let _ = Pallet::<Test>::register_asset(
                 RuntimeOrigin::signed(who),
                 collec_id,
@@ -100,10 +107,266 @@ fn asset_count_for_3_items() {
                 RuntimeOrigin::signed(who),
                 collec_id,
                 owner_id,
+                collec_id,
                 i,
+                3,
             );
         }
 
         assert_eq!(AssetCount::<Test>::get((collec_id, owner_id)), 3);
     });
-}
\ No newline at end of file
+}
+
+#[test]
+fn refunds_unused_hint() {
+    new_test_ext().execute_with(|| {
+        let collec_id = 1u32;
+        let owner_id = 1u128;
+        let who = 1u64;
+        mint(collec_id, owner_id, who);
+
+        let tags: BoundedVec<BoundedVec<u8, <Test as pallet::Config>::StringLimit>, <Test as pallet::Config>::TypeLimit> = 
+            BoundedVec::try_from(vec![BoundedVec::try_from(b"owner".to_vec()).unwrap()]).unwrap();
+        assert_ok!(Pallet::<Test>::register_asset(
+            RuntimeOrigin::signed(who),
+            collec_id,
+            owner_id,
+            tags
+        ));
+        for i in 2..=4 {
+            mint_registered(collec_id, i, who, b"entity");
+        }
+        for i in 2..=3 {
+            assert_ok!(Pallet::<Test>::set_ownership(
+                RuntimeOrigin::signed(who),
+                collec_id,
+                owner_id,
+                collec_id,
+                i,
+                10,
+            ));
+        }
+
+        // The owner had a single asset, so only that one is charged.
+        let info = Pallet::<Test>::set_ownership(
+            RuntimeOrigin::signed(who),
+            collec_id,
+            owner_id,
+            collec_id,
+            4,
+            10,
+        )
+        .unwrap();
+        assert_eq!(
+            info.actual_weight,
+            Some(<Test as pallet::Config>::WeightInfo::create_ownership(2))
+        );
+    });
+}
+
+#[test]
+fn fails_with_hint_too_low() {
+    new_test_ext().execute_with(|| {
+        let collec_id = 1u32;
+        let owner_id = 1u128;
+        let who = 1u64;
+        mint(collec_id, owner_id, who);
+
+        let tags: BoundedVec<BoundedVec<u8, <Test as pallet::Config>::StringLimit>, <Test as pallet::Config>::TypeLimit> = 
+            BoundedVec::try_from(vec![BoundedVec::try_from(b"owner".to_vec()).unwrap()]).unwrap();
+        assert_ok!(Pallet::<Test>::register_asset(
+            RuntimeOrigin::signed(who),
+            collec_id,
+            owner_id,
+            tags
+        ));
+        for i in 2..=3 {
+            mint_registered(collec_id, i, who, b"entity");
+        }
+        assert_ok!(Pallet::<Test>::set_ownership(
+            RuntimeOrigin::signed(who),
+            collec_id,
+            owner_id,
+            collec_id,
+            2,
+            0,
+        ));
+
+        assert_noop!(
+            Pallet::<Test>::set_ownership(RuntimeOrigin::signed(who), collec_id, owner_id, collec_id, 3, 0),
+            Error::<Test>::InventoryHintTooLow
+        );
+    });
+}
+
+#[test]
+fn fails_when_inventory_is_full() {
+    new_test_ext().execute_with(|| {
+        let collec_id = 1u32;
+        let owner_id = 1u128;
+        let who = 1u64;
+        mint(collec_id, owner_id, who);
+        let max = <Test as pallet::Config>::MaxInventorySize::get();
+
+        let tags: BoundedVec<BoundedVec<u8, <Test as pallet::Config>::StringLimit>, <Test as pallet::Config>::TypeLimit> = 
+            BoundedVec::try_from(vec![BoundedVec::try_from(b"owner".to_vec()).unwrap()]).unwrap();
+        assert_ok!(Pallet::<Test>::register_asset(
+            RuntimeOrigin::signed(who),
+            collec_id,
+            owner_id,
+            tags
+        ));
+        mint_registered(collec_id, 2, who, b"entity");
+        for i in 0..max as u128 {
+            mint_registered(collec_id, 100 + i, who, b"entity");
+            assert_ok!(Pallet::<Test>::set_ownership(
+                RuntimeOrigin::signed(who),
+                collec_id,
+                owner_id,
+                collec_id,
+                100 + i,
+                max,
+            ));
+        }
+
+        assert_noop!(
+            Pallet::<Test>::set_ownership(RuntimeOrigin::signed(who), collec_id, owner_id, collec_id, 2, max),
+            Error::<Test>::ExceededMaxInventorySize
+        );
+    });
+}
+
+#[test]
+fn works_across_collections() {
+    new_test_ext().execute_with(|| {
+        let (heroes, armory) = (1u32, 2u32);
+        let (character, sword) = (1u128, 2u128);
+        let who = 1u64;
+        mint(heroes, character, who);
+        mint_registered(armory, sword, who, b"weapon");
+        mint_registered(heroes, sword, who, b"entity");
+
+        let tags: BoundedVec<BoundedVec<u8, <Test as pallet::Config>::StringLimit>, <Test as pallet::Config>::TypeLimit> = 
+            BoundedVec::try_from(vec![BoundedVec::try_from(b"owner".to_vec()).unwrap()]).unwrap();
+        assert_ok!(Pallet::<Test>::register_asset(
+            RuntimeOrigin::signed(who),
+            heroes,
+            character,
+            tags
+        ));
+
+        // A character from the Heroes owns a sword from the Armory.
+        assert_ok!(Pallet::<Test>::set_ownership(
+            RuntimeOrigin::signed(who),
+            heroes,
+            character,
+            armory,
+            sword,
+            0
+        ));
+        assert_eq!(
+            OwnerAssets::<Test>::get((heroes, character, 0)),
+            Some((armory, sword))
+        );
+        System::assert_last_event(
+            Event::<Test>::OwnershipAdded {
+                owner: (heroes, character),
+                asset: (armory, sword),
+                who,
+            }
+            .into(),
+        );
+
+        // The same item ID in the owner's collection is a different asset.
+        assert_ok!(Pallet::<Test>::set_ownership(
+            RuntimeOrigin::signed(who),
+            heroes,
+            character,
+            heroes,
+            sword,
+            1
+        ));
+        assert_eq!(AssetCount::<Test>::get((heroes, character)), 2);
+    });
+}
+
+#[test]
+fn fails_if_not_holder() {
+    new_test_ext().execute_with(|| {
+        let collec_id = 1u32;
+        let owner_id = 1u128;
+        let who = 1u64;
+        mint(collec_id, owner_id, who);
+
+        assert_noop!(
+            Pallet::<Test>::set_ownership(RuntimeOrigin::signed(2), collec_id, owner_id, collec_id, 2, 0),
+            Error::<Test>::NotOwner
+        );
+    });
+}
+
+#[test]
+fn fails_for_the_namesake_of_an_owner_in_another_collection() {
+    new_test_ext().execute_with(|| {
+        let (heroes, armory) = (1u32, 2u32);
+        let item = 1u128;
+        let who = 1u64;
+        mint(heroes, item, who);
+        mint(armory, item, who);
+        mint_registered(armory, 2, who, b"weapon");
+
+        // Heroes#1 is an owner, while Armory#1 is a plain weapon with the same item id.
+        let tags = |tag: &[u8]| -> Tags<Test> {
+            BoundedVec::try_from(vec![BoundedVec::try_from(tag.to_vec()).unwrap()]).unwrap()
+        };
+        assert_ok!(Pallet::<Test>::register_asset(
+            RuntimeOrigin::signed(who),
+            heroes,
+            item,
+            tags(b"owner")
+        ));
+        assert_ok!(Pallet::<Test>::register_asset(
+            RuntimeOrigin::signed(who),
+            armory,
+            item,
+            tags(b"weapon")
+        ));
+        assert!(NftInfos::<Test>::get(heroes, item).is_type("owner"));
+        assert!(NftInfos::<Test>::get(armory, item).is_type("weapon"));
+
+        assert_noop!(
+            Pallet::<Test>::set_ownership(RuntimeOrigin::signed(who), armory, item, armory, 2, 0),
+            Error::<Test>::WrongNft
+        );
+        assert_ok!(Pallet::<Test>::set_ownership(
+            RuntimeOrigin::signed(who),
+            heroes,
+            item,
+            armory,
+            2,
+            0
+        ));
+    });
+}
+
+#[test]
+fn fails_for_an_unregistered_asset() {
+    new_test_ext().execute_with(|| {
+        let (collec_id, owner_id, asset_id) = (1u32, 1u128, 2u128);
+        let who = 1u64;
+        mint_registered(collec_id, owner_id, who, b"owner");
+        mint(collec_id, asset_id, who);
+
+        assert_noop!(
+            Pallet::<Test>::set_ownership(
+                RuntimeOrigin::signed(who),
+                collec_id,
+                owner_id,
+                collec_id,
+                asset_id,
+                0
+            ),
+            Error::<Test>::NotRegistered
+        );
+    });
+}
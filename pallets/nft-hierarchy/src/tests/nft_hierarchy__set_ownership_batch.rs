@@ -0,0 +1,173 @@
+#![allow(non_snake_case)]
+
+use super::super::*;
+use crate::mock::{mint, mint_registered, new_test_ext, RuntimeOrigin, System, Test};
+use frame_support::{assert_noop, assert_ok, BoundedVec};
+
+fn register_owner(collec_id: u32, owner_id: u128, who: u64) {
+    mint(collec_id, owner_id, who);
+    let tags: BoundedVec<BoundedVec<u8, <Test as pallet::Config>::StringLimit>, <Test as pallet::Config>::TypeLimit> = 
+        BoundedVec::try_from(vec![
+            BoundedVec::try_from(b"entity".to_vec()).unwrap(),
+            BoundedVec::try_from(b"owner".to_vec()).unwrap(),
+    ]).unwrap();
+    assert_ok!(Pallet::<Test>::register_asset(
+        RuntimeOrigin::signed(who),
+        collec_id,
+        owner_id,
+        tags
+    ));
+}
+
+fn register_assets(collec_id: u32, assets: impl IntoIterator<Item = u128>, who: u64) {
+    for asset_id in assets {
+        mint_registered(collec_id, asset_id, who, b"entity");
+    }
+}
+
+#[test]
+fn works() {
+    new_test_ext().execute_with(|| {
+        let collec_id = 1u32;
+        let owner_id = 1u128;
+        let who = 1u64;
+        register_owner(collec_id, owner_id, who);
+        register_assets(collec_id, 2..=5, who);
+        assert_ok!(Pallet::<Test>::set_ownership(
+            RuntimeOrigin::signed(who),
+            collec_id,
+            owner_id,
+            collec_id,
+            2,
+            0
+        ));
+
+        // The hoard is added after the assets already owned.
+        assert_ok!(Pallet::<Test>::set_ownership_batch(
+            RuntimeOrigin::signed(who),
+            collec_id,
+            owner_id,
+            BoundedVec::try_from(vec![(collec_id, 3), (collec_id, 4), (collec_id, 5)]).unwrap(),
+            1
+        ));
+        assert_eq!(AssetCount::<Test>::get((collec_id, owner_id)), 4);
+        for (index, asset_id) in (0..4).zip(2..=5u128) {
+            assert_eq!(
+                OwnerAssets::<Test>::get((collec_id, owner_id, index)),
+                Some((collec_id, asset_id))
+            );
+        }
+
+        // Verify one event per asset.
+        for asset_id in 3..=5u128 {
+            System::assert_has_event(
+                Event::<Test>::OwnershipAdded {
+                    owner: (collec_id, owner_id),
+                    asset: (collec_id, asset_id),
+                    who,
+                }
+                .into(),
+            );
+        }
+    });
+}
+
+#[test]
+fn is_all_or_nothing() {
+    new_test_ext().execute_with(|| {
+        let collec_id = 1u32;
+        let owner_id = 1u128;
+        let who = 1u64;
+        register_owner(collec_id, owner_id, who);
+        register_assets(collec_id, 2..=4, who);
+        assert_ok!(Pallet::<Test>::set_ownership(
+            RuntimeOrigin::signed(who),
+            collec_id,
+            owner_id,
+            collec_id,
+            4,
+            0
+        ));
+
+        // An asset already owned.
+        assert_noop!(
+            Pallet::<Test>::set_ownership_batch(
+                RuntimeOrigin::signed(who),
+                collec_id,
+                owner_id,
+                BoundedVec::try_from(vec![(collec_id, 2), (collec_id, 3), (collec_id, 4)]).unwrap(),
+                1
+            ),
+            Error::<Test>::AlreadyOwner
+        );
+
+        // An asset repeated in the batch.
+        assert_noop!(
+            Pallet::<Test>::set_ownership_batch(
+                RuntimeOrigin::signed(who),
+                collec_id,
+                owner_id,
+                BoundedVec::try_from(vec![(collec_id, 2), (collec_id, 3), (collec_id, 2)]).unwrap(),
+                1
+            ),
+            Error::<Test>::AlreadyOwner
+        );
+        assert_eq!(AssetCount::<Test>::get((collec_id, owner_id)), 1);
+    });
+}
+
+#[test]
+fn fails_when_inventory_would_overflow() {
+    new_test_ext().execute_with(|| {
+        let collec_id = 1u32;
+        let owner_id = 1u128;
+        let who = 1u64;
+        let max = <Test as pallet::Config>::MaxInventorySize::get();
+        register_owner(collec_id, owner_id, who);
+        register_assets(collec_id, (2..=4).chain(100..100 + max as u128 - 2), who);
+        for asset_id in 0..max as u128 - 2 {
+            assert_ok!(Pallet::<Test>::set_ownership(
+                RuntimeOrigin::signed(who),
+                collec_id,
+                owner_id,
+                collec_id,
+                100 + asset_id,
+                max
+            ));
+        }
+
+        assert_noop!(
+            Pallet::<Test>::set_ownership_batch(
+                RuntimeOrigin::signed(who),
+                collec_id,
+                owner_id,
+                BoundedVec::try_from(vec![(collec_id, 2), (collec_id, 3), (collec_id, 4)]).unwrap(),
+                max
+            ),
+            Error::<Test>::ExceededMaxInventorySize
+        );
+    });
+}
+
+#[test]
+fn fails_for_an_unregistered_asset() {
+    new_test_ext().execute_with(|| {
+        let collec_id = 1u32;
+        let owner_id = 1u128;
+        let who = 1u64;
+        register_owner(collec_id, owner_id, who);
+        register_assets(collec_id, 2..=2, who);
+        mint(collec_id, 3, who);
+
+        assert_noop!(
+            Pallet::<Test>::set_ownership_batch(
+                RuntimeOrigin::signed(who),
+                collec_id,
+                owner_id,
+                BoundedVec::try_from(vec![(collec_id, 2), (collec_id, 3)]).unwrap(),
+                0
+            ),
+            Error::<Test>::NotRegistered
+        );
+    });
+}
@@ -0,0 +1,121 @@
+#![allow(non_snake_case)]
+
+use super::super::*;
+use crate::mock::{mint, mint_registered, new_unchecked_test_ext, RuntimeOrigin, Test};
+use frame_support::{assert_err, assert_ok, BoundedVec};
+
+const HEROES: u32 = 1;
+const ARMORY: u32 = 2;
+const KNIGHT: u128 = 1;
+
+/// Give the registered items 10, 11 and 12 of the armory to the knight, and take the first one back, which
+/// moves the last relationship to its slot.
+fn a_knight_with_two_items() {
+    mint(HEROES, KNIGHT, 1);
+    let tags =
+        BoundedVec::try_from(vec![BoundedVec::try_from(b"owner".to_vec()).unwrap()]).unwrap();
+    assert_ok!(Pallet::<Test>::register_asset(RuntimeOrigin::signed(1), HEROES, KNIGHT, tags));
+    for item in [10u128, 11, 12] {
+        mint_registered(ARMORY, item, 1, b"weapon");
+        assert_ok!(Pallet::<Test>::set_ownership(
+            RuntimeOrigin::signed(1),
+            HEROES,
+            KNIGHT,
+            ARMORY,
+            item,
+            10
+        ));
+    }
+    assert_ok!(Pallet::<Test>::unset_ownership(
+        RuntimeOrigin::signed(1),
+        HEROES,
+        KNIGHT,
+        ARMORY,
+        10,
+        10
+    ));
+}
+
+#[test]
+fn works() {
+    new_unchecked_test_ext().execute_with(|| {
+        a_knight_with_two_items();
+        assert_ok!(Pallet::<Test>::do_try_state());
+    });
+}
+
+#[test]
+fn detects_an_empty_slot_below_the_count() {
+    new_unchecked_test_ext().execute_with(|| {
+        a_knight_with_two_items();
+        // A relationship removed without moving the last one to its slot.
+        OwnerAssets::<Test>::insert((HEROES, KNIGHT, 0), None::<(u32, u128)>);
+        assert_err!(
+            Pallet::<Test>::do_try_state(),
+            "an inventory has an empty slot below its asset count"
+        );
+    });
+}
+
+#[test]
+fn detects_a_slot_beyond_the_count() {
+    new_unchecked_test_ext().execute_with(|| {
+        a_knight_with_two_items();
+        AssetCount::<Test>::insert((HEROES, KNIGHT), 1);
+        assert_err!(
+            Pallet::<Test>::do_try_state(),
+            "an inventory has a slot beyond its asset count"
+        );
+    });
+}
+
+#[test]
+fn detects_an_asset_owned_twice() {
+    new_unchecked_test_ext().execute_with(|| {
+        a_knight_with_two_items();
+        OwnerAssets::<Test>::insert((HEROES, KNIGHT, 1), Some((ARMORY, 12)));
+        assert_err!(Pallet::<Test>::do_try_state(), "an inventory holds an asset twice");
+    });
+}
+
+#[test]
+fn detects_an_unregistered_nft() {
+    new_unchecked_test_ext().execute_with(|| {
+        a_knight_with_two_items();
+        NftInfos::<Test>::remove(ARMORY, 11);
+        assert_err!(Pallet::<Test>::do_try_state(), "an owned asset is not registered");
+    });
+    new_unchecked_test_ext().execute_with(|| {
+        a_knight_with_two_items();
+        NftInfos::<Test>::remove(HEROES, KNIGHT);
+        assert_err!(Pallet::<Test>::do_try_state(), "an owner is not registered");
+    });
+}
+
+#[test]
+fn detects_counters_that_do_not_match() {
+    new_unchecked_test_ext().execute_with(|| {
+        a_knight_with_two_items();
+        OwnerCount::<Test>::insert((ARMORY, 10), 1);
+        assert_err!(
+            Pallet::<Test>::do_try_state(),
+            "the owner count of an asset does not match the inventories holding it"
+        );
+    });
+    new_unchecked_test_ext().execute_with(|| {
+        a_knight_with_two_items();
+        AgentCount::<Test>::insert((HEROES, KNIGHT), 1);
+        assert_err!(
+            Pallet::<Test>::do_try_state(),
+            "the agent count of a principal does not match its agents"
+        );
+    });
+    new_unchecked_test_ext().execute_with(|| {
+        a_knight_with_two_items();
+        LentCount::<Test>::insert((HEROES, KNIGHT), 1);
+        assert_err!(
+            Pallet::<Test>::do_try_state(),
+            "the lent count of an owner does not match its loans"
+        );
+    });
+}
@@ -1,8 +1,8 @@
 #![allow(non_snake_case)]
 
 use super::super::*;
-use crate::mock::{new_test_ext, RuntimeOrigin, System, Test};
-use frame_support::{assert_ok, BoundedVec};
+use crate::mock::{mint, new_test_ext, RuntimeOrigin, System, Test};
+use frame_support::{assert_noop, assert_ok, BoundedVec};
 
 #[test]
 fn works() {
@@ -11,6 +11,7 @@ fn works() {
         let owner_id = 1u128;
         let asset_id = 2u128;
         let who = 1u64;
+        mint(collec_id, owner_id, who);
 
         let tags: BoundedVec<BoundedVec<u8, <Test as pallet::Config>::StringLimit>, <Test as pallet::Config>::TypeLimit> = 
             BoundedVec::try_from(vec![
@@ -30,6 +31,7 @@ fn works() {
             BoundedVec::try_from(
                 vec![BoundedVec::try_from(b"entity".to_vec()).unwrap()]
             ).unwrap();
+        mint(collec_id, asset_id, who);
         let _ = Pallet::<Test>::register_asset(
             RuntimeOrigin::signed(who),
             collec_id,
@@ -42,7 +44,9 @@ fn works() {
             RuntimeOrigin::signed(who),
             collec_id,
             owner_id,
-            asset_id
+            collec_id,
+            asset_id,
+            0
         );
 
         // Verify relationship removal.
@@ -50,17 +54,95 @@ fn works() {
             RuntimeOrigin::signed(who),
             collec_id,
             owner_id,
-            asset_id
+            collec_id,
+            asset_id,
+            1
         ));
 
         // Verify event.
         System::assert_last_event(
             Event::<Test>::OwnershipRemoved {
                 owner: (collec_id, owner_id),
-                asset: asset_id,
+                asset: (collec_id, asset_id),
                 who,
             }
             .into(),
         );
     });
-}
\ No newline at end of file
+}
+#[test]
+fn removes_the_requested_asset() {
+    new_test_ext().execute_with(|| {
+        let collec_id = 0u32;
+        let owner_id = 1u128;
+        let who = 1u64;
+        mint(collec_id, owner_id, who);
+
+        let tags: BoundedVec<BoundedVec<u8, <Test as pallet::Config>::StringLimit>, <Test as pallet::Config>::TypeLimit> =
+            BoundedVec::try_from(vec![
+                BoundedVec::try_from(b"entity".to_vec()).unwrap(),
+                BoundedVec::try_from(b"owner".to_vec()).unwrap(),
+                BoundedVec::try_from(b"character".to_vec()).unwrap(),
+        ]).unwrap();
+        let _ = Pallet::<Test>::register_asset(
+            RuntimeOrigin::signed(who),
+            collec_id,
+            owner_id,
+            tags
+        );
+
+        // The owner holds the assets 2, 3 and 4, in this order.
+        let tags: BoundedVec<BoundedVec<u8, <Test as pallet::Config>::StringLimit>, <Test as pallet::Config>::TypeLimit> =
+            BoundedVec::try_from(
+                vec![BoundedVec::try_from(b"entity".to_vec()).unwrap()]
+            ).unwrap();
+        for asset_id in 2..=4u128 {
+            mint(collec_id, asset_id, who);
+            let _ = Pallet::<Test>::register_asset(
+                RuntimeOrigin::signed(who),
+                collec_id,
+                asset_id,
+                tags.clone()
+            );
+            let _ = Pallet::<Test>::set_ownership(
+                RuntimeOrigin::signed(who),
+                collec_id,
+                owner_id,
+                collec_id,
+                asset_id,
+                3
+            );
+        }
+
+        // An asset the owner does not hold is not found, even though the owner holds others.
+        assert_noop!(
+            Pallet::<Test>::unset_ownership(RuntimeOrigin::signed(who), collec_id, owner_id, collec_id, 9, 3),
+            Error::<Test>::OwnershipNotFound
+        );
+
+        // Removing the asset in the middle moves the last one to its place.
+        assert_ok!(Pallet::<Test>::unset_ownership(
+            RuntimeOrigin::signed(who),
+            collec_id,
+            owner_id,
+            collec_id,
+            3,
+            3
+        ));
+        let owned = |index: u128| OwnerAssets::<Test>::get((collec_id, owner_id, index));
+        assert_eq!(AssetCount::<Test>::get((collec_id, owner_id)), 2);
+        assert_eq!((owned(0), owned(1), owned(2)), (Some((collec_id, 2)), Some((collec_id, 4)), None));
+
+        // Removing the last asset clears its slot.
+        assert_ok!(Pallet::<Test>::unset_ownership(
+            RuntimeOrigin::signed(who),
+            collec_id,
+            owner_id,
+            collec_id,
+            4,
+            2
+        ));
+        assert_eq!(AssetCount::<Test>::get((collec_id, owner_id)), 1);
+        assert_eq!((owned(0), owned(1)), (Some((collec_id, 2)), None));
+    });
+}
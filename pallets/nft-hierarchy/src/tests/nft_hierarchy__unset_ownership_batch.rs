@@ -0,0 +1,105 @@
+#![allow(non_snake_case)]
+
+use super::super::*;
+use crate::mock::{mint, mint_registered, new_test_ext, RuntimeOrigin, System, Test};
+use frame_support::{assert_noop, assert_ok, BoundedVec};
+
+/// Register the owner `owner_id` with the assets 2 to 6.
+fn owner_with_hoard(collec_id: u32, owner_id: u128, who: u64) {
+    mint(collec_id, owner_id, who);
+    let tags: BoundedVec<BoundedVec<u8, <Test as pallet::Config>::StringLimit>, <Test as pallet::Config>::TypeLimit> = 
+        BoundedVec::try_from(vec![BoundedVec::try_from(b"owner".to_vec()).unwrap()]).unwrap();
+    assert_ok!(Pallet::<Test>::register_asset(
+        RuntimeOrigin::signed(who),
+        collec_id,
+        owner_id,
+        tags
+    ));
+    for asset_id in 2..=6 {
+        mint_registered(collec_id, asset_id, who, b"entity");
+    }
+    assert_ok!(Pallet::<Test>::set_ownership_batch(
+        RuntimeOrigin::signed(who),
+        collec_id,
+        owner_id,
+        BoundedVec::try_from((2..=6).map(|asset_id| (collec_id, asset_id)).collect::<Vec<_>>()).unwrap(),
+        0
+    ));
+}
+
+fn owned_assets(collec_id: u32, owner_id: u128) -> Vec<u128> {
+    (0..AssetCount::<Test>::get((collec_id, owner_id)))
+        .filter_map(|index| OwnerAssets::<Test>::get((collec_id, owner_id, index)))
+        .map(|(_, asset_id)| asset_id)
+        .collect()
+}
+
+#[test]
+fn works() {
+    new_test_ext().execute_with(|| {
+        let collec_id = 1u32;
+        let owner_id = 1u128;
+        let who = 1u64;
+        owner_with_hoard(collec_id, owner_id, who);
+
+        assert_ok!(Pallet::<Test>::unset_ownership_batch(
+            RuntimeOrigin::signed(who),
+            collec_id,
+            owner_id,
+            BoundedVec::try_from(vec![(collec_id, 2), (collec_id, 4)]).unwrap(),
+            5
+        ));
+
+        // The last assets take the place of those taken away.
+        assert_eq!(owned_assets(collec_id, owner_id), vec![6, 3, 5]);
+        assert_eq!(OwnerAssets::<Test>::get((collec_id, owner_id, 3)), None);
+        assert_eq!(OwnerAssets::<Test>::get((collec_id, owner_id, 4)), None);
+
+        // Verify one event per asset.
+        for asset_id in [2u128, 4] {
+            System::assert_has_event(
+                Event::<Test>::OwnershipRemoved {
+                    owner: (collec_id, owner_id),
+                    asset: (collec_id, asset_id),
+                    who,
+                }
+                .into(),
+            );
+        }
+    });
+}
+
+#[test]
+fn is_all_or_nothing() {
+    new_test_ext().execute_with(|| {
+        let collec_id = 1u32;
+        let owner_id = 1u128;
+        let who = 1u64;
+        owner_with_hoard(collec_id, owner_id, who);
+
+        // An asset not owned.
+        assert_noop!(
+            Pallet::<Test>::unset_ownership_batch(
+                RuntimeOrigin::signed(who),
+                collec_id,
+                owner_id,
+                BoundedVec::try_from(vec![(collec_id, 2), (collec_id, 7)]).unwrap(),
+                5
+            ),
+            Error::<Test>::OwnershipNotFound
+        );
+
+        // An asset repeated in the batch.
+        assert_noop!(
+            Pallet::<Test>::unset_ownership_batch(
+                RuntimeOrigin::signed(who),
+                collec_id,
+                owner_id,
+                BoundedVec::try_from(vec![(collec_id, 3), (collec_id, 3)]).unwrap(),
+                5
+            ),
+            Error::<Test>::OwnershipNotFound
+        );
+        assert_eq!(owned_assets(collec_id, owner_id), vec![2, 3, 4, 5, 6]);
+    });
+}
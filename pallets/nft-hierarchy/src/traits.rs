@@ -0,0 +1,54 @@
+use crate::{Config, OwnerAssets, AssetCount, Pallet};
+use sp_std::vec::Vec;
+
+/// Read access to the ownership relationship for other pallets, which do not need to know how the
+/// relationship is stored. Assets are returned as `(CollectionId, ItemId)` pairs.
+pub trait InspectOwnership<CollectionId, ItemId> {
+    /// Return at most `max` assets owned by the NFT `(collection, owner)`, starting at the
+    /// `start`-th owned asset.
+    fn owned_assets(
+        collection: &CollectionId,
+        owner: &ItemId,
+        start: u128,
+        max: u32,
+    ) -> Vec<(CollectionId, ItemId)>;
+}
+
+impl<T: Config> InspectOwnership<T::CollectionId, T::ItemId> for Pallet<T> {
+    fn owned_assets(
+        collection: &T::CollectionId,
+        owner: &T::ItemId,
+        start: u128,
+        max: u32,
+    ) -> Vec<(T::CollectionId, T::ItemId)> {
+        let end = start
+            .saturating_add(max as u128)
+            .min(AssetCount::<T>::get((*collection, *owner)));
+        (start..end)
+            .filter_map(|index| OwnerAssets::<T>::get((*collection, *owner, index)))
+            .collect()
+    }
+}
+
+/// No ownership relationships at all, for runtimes and mocks that do not use the hierarchy.
+impl<CollectionId, ItemId> InspectOwnership<CollectionId, ItemId> for () {
+    fn owned_assets(
+        _collection: &CollectionId,
+        _owner: &ItemId,
+        _start: u128,
+        _max: u32,
+    ) -> Vec<(CollectionId, ItemId)> {
+        Vec::new()
+    }
+}
+
+/// Enumeration of the items of a collection from a cursor, which `nonfungibles_v2` lacks, so that
+/// searches over a collection can be paginated without reading it from the start every time.
+pub trait InspectItemsFrom<CollectionId, ItemId> {
+    type ItemsIterator: Iterator<Item = ItemId>;
+
+    /// Return the items of `collection` in the order of their storage, starting right after
+    /// `start_after`, or from the first one without it. The order only depends on the
+    /// identifiers, so the cursor stays valid even if `start_after` has been burned since.
+    fn items_from(collection: &CollectionId, start_after: Option<&ItemId>) -> Self::ItemsIterator;
+}
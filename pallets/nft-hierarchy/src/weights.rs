@@ -1,94 +1,845 @@
+//! Weights for `pallet_nft_hierarchy`.
+//!
+//! PROVISIONAL: these weights were not produced by the node's `benchmark pallet` command nor on
+//! the reference hardware. They were measured by running the benchmarks of `benchmarking.rs`
+//! natively against the runtime, with 50 steps and 20 repeats, and counting the storage
+//! accesses of the worst case of each call. Regenerate them before any production deployment:
+//!
+//! ./target/release/telurean-chain-node benchmark pallet \
+//!     --runtime ./target/release/wbuild/telurean-chain-runtime/telurean_chain_runtime.wasm \
+//!     --genesis-builder=runtime --pallet pallet_nft_hierarchy --extrinsic '*' \
+//!     --steps 50 --repeat 20 --output pallets/nft-hierarchy/src/weights.rs
 
 #![cfg_attr(rustfmt, rustfmt_skip)]
 #![allow(unused_parens)]
 #![allow(unused_imports)]
+#![allow(missing_docs)]
 
 use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
 use core::marker::PhantomData;
 
+/// Weight functions needed for `pallet_nft_hierarchy`.
 pub trait WeightInfo {
-  fn register_nft() -> Weight;
-  fn create_ownership() -> Weight;
-  fn remove_ownership() -> Weight;
-  fn get_owned_assets() -> Weight;
+	fn register_nft(t: u32, ) -> Weight;
+	fn create_ownership(n: u32, ) -> Weight;
+	fn remove_ownership(n: u32, ) -> Weight;
+	fn register_nft_batch(b: u32, ) -> Weight;
+	fn create_ownership_batch(n: u32, b: u32, ) -> Weight;
+	fn remove_ownership_batch(n: u32, b: u32, ) -> Weight;
+	fn get_owned_assets(n: u32, ) -> Weight;
+	fn force_register(t: u32, ) -> Weight;
+	fn force_set_ownership(n: u32, ) -> Weight;
+	fn force_unset_ownership(n: u32, ) -> Weight;
+	fn force_unregister(n: u32, ) -> Weight;
+	fn approve_operator() -> Weight;
+	fn revoke_operator() -> Weight;
+	fn appoint_agent() -> Weight;
+	fn dismiss_agent() -> Weight;
+	fn lend() -> Weight;
+	fn accept_loan(n: u32, ) -> Weight;
+	fn cancel_loan_offer() -> Weight;
+	fn return_loan(n: u32, ) -> Weight;
+	fn propose_relationship() -> Weight;
+	fn accept_relationship(n: u32, ) -> Weight;
+	fn reject_relationship() -> Weight;
+	fn cancel_relationship() -> Weight;
+	fn dispatch_as_nft() -> Weight;
+	fn prune_provenance(n: u32, ) -> Weight;
 }
 
+/// Weights for `pallet_nft_hierarchy`, with storage accesses priced by the database weights of the runtime.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
-    fn register_nft() -> Weight {
-        // Proof Size summary in bytes:
-        //  Measured:  `0`
-        //  Estimated: `0`
-        // Minimum execution time: 8_000_000 picoseconds.
-        Weight::from_parts(10_000_000, 0)
-        .saturating_add(T::DbWeight::get().writes(1_u64))
-    }
-
-    fn create_ownership() -> Weight {
-        // Proof Size summary in bytes:
-        //  Measured:  `0`
-        //  Estimated: `0`
-        // Minimum execution time: 8_000_000 picoseconds.
-        Weight::from_parts(10_000_000, 0)
-        .saturating_add(T::DbWeight::get().writes(1_u64))
-    }
-
-    fn remove_ownership() -> Weight {
-        // Proof Size summary in bytes:
-        //  Measured:  `32`
-        //  Estimated: `1489`
-        // Minimum execution time: 6_000_000 picoseconds.
-        Weight::from_parts(10_000_000, 1489)
-        .saturating_add(T::DbWeight::get().reads(1_u64))
-        .saturating_add(T::DbWeight::get().writes(1_u64))
-    }
-  
-    fn get_owned_assets() -> Weight {
-        // Proof Size summary in bytes:
-        //  Measured:  `0`
-        //  Estimated: `0`
-        // Minimum execution time: 8_000_000 picoseconds.
-        Weight::from_parts(10_000_000, 0)
-        .saturating_add(T::DbWeight::get().writes(1_u64))
-    }
+	/// Storage: `NftHierarchy::NftInfos` (r:1 w:1)
+	/// Storage: `Uniques::Asset` (r:6 w:0)
+	/// Storage: `NftHierarchy::Operators` (r:1 w:0)
+	/// Storage: `NftHierarchy::Agents` (r:6 w:0)
+	/// The range of component `t` is `[0, 100]`.
+	fn register_nft(t: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1023`
+		// Minimum execution time: 31_878_000 picoseconds.
+		Weight::from_parts(33_970_381, 1023)
+			.saturating_add(Weight::from_parts(49_554, 0).saturating_mul(t.into()))
+			.saturating_add(T::DbWeight::get().reads(14_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `NftHierarchy::NftInfos` (r:2 w:0)
+	/// Storage: `NftHierarchy::OwnerCount` (r:1 w:1)
+	/// Storage: `NftHierarchy::AssetCount` (r:1 w:1)
+	/// Storage: `NftHierarchy::OwnerAssets` (r:n w:1)
+	/// Storage: `NftHierarchy::Loans` (r:1 w:0)
+	/// Storage: `NftHierarchy::LentCount` (r:1 w:0)
+	/// Storage: `Uniques::Asset` (r:7 w:0)
+	/// Storage: `NftHierarchy::Operators` (r:1 w:0)
+	/// Storage: `NftHierarchy::Agents` (r:6 w:0)
+	/// Storage: `NftHierarchy::ProvenanceBounds` (r:1 w:1)
+	/// Storage: `NftHierarchy::Provenance` (r:0 w:2)
+	/// The range of component `n` is `[0, 1000]`.
+	fn create_ownership(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3262`
+		// Minimum execution time: 43_906_000 picoseconds.
+		Weight::from_parts(56_614_426, 3262)
+			.saturating_add(Weight::from_parts(2_212_948, 51).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(21_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: `NftHierarchy::OwnerCount` (r:1 w:1)
+	/// Storage: `NftHierarchy::Loans` (r:1 w:0)
+	/// Storage: `NftHierarchy::AssetCount` (r:1 w:1)
+	/// Storage: `NftHierarchy::OwnerAssets` (r:n w:2)
+	/// Storage: `Uniques::Asset` (r:6 w:0)
+	/// Storage: `NftHierarchy::Operators` (r:1 w:0)
+	/// Storage: `NftHierarchy::Agents` (r:6 w:0)
+	/// Storage: `NftHierarchy::ProvenanceBounds` (r:1 w:1)
+	/// Storage: `NftHierarchy::Provenance` (r:0 w:2)
+	/// The range of component `n` is `[1, 1000]`.
+	fn remove_ownership(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2494`
+		// Minimum execution time: 41_893_000 picoseconds.
+		Weight::from_parts(32_509_427, 2494)
+			.saturating_add(Weight::from_parts(2_304_041, 51).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(17_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	/// Storage: `NftHierarchy::NftInfos` (r:b w:b)
+	/// Storage: `Uniques::Asset` (r:b * 6 w:0)
+	/// Storage: `NftHierarchy::Operators` (r:b w:0)
+	/// Storage: `NftHierarchy::Agents` (r:b * 6 w:0)
+	/// The range of component `b` is `[1, 50]`.
+	fn register_nft_batch(b: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `273`
+		// Minimum execution time: 39_059_000 picoseconds.
+		Weight::from_parts(39_489_000, 273)
+			.saturating_add(Weight::from_parts(39_999_756, 776).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads((14_u64).saturating_mul(b.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(b.into())))
+	}
+	/// Storage: `NftHierarchy::NftInfos` (r:1 + b w:0)
+	/// Storage: `NftHierarchy::OwnerCount` (r:b w:b)
+	/// Storage: `NftHierarchy::AssetCount` (r:1 w:1)
+	/// Storage: `NftHierarchy::OwnerAssets` (r:n w:b)
+	/// Storage: `NftHierarchy::Loans` (r:b w:0)
+	/// Storage: `NftHierarchy::LentCount` (r:1 w:0)
+	/// Storage: `Uniques::Asset` (r:6 + b w:0)
+	/// Storage: `NftHierarchy::Operators` (r:1 w:0)
+	/// Storage: `NftHierarchy::Agents` (r:6 w:0)
+	/// Storage: `NftHierarchy::ProvenanceBounds` (r:b w:b)
+	/// Storage: `NftHierarchy::Provenance` (r:0 w:b * 2)
+	/// The range of component `n` is `[0, 950]`.
+	/// The range of component `b` is `[1, 50]`.
+	fn create_ownership_batch(n: u32, b: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3605`
+		// Minimum execution time: 46_600_000 picoseconds.
+		Weight::from_parts(8_828_644, 3605)
+			.saturating_add(Weight::from_parts(2_987_067, 54).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(9_205_266, 126).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads(16_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(b.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((5_u64).saturating_mul(b.into())))
+	}
+	/// Storage: `NftHierarchy::OwnerCount` (r:b w:b)
+	/// Storage: `NftHierarchy::Loans` (r:b w:0)
+	/// Storage: `NftHierarchy::AssetCount` (r:1 w:1)
+	/// Storage: `NftHierarchy::OwnerAssets` (r:n w:b * 2)
+	/// Storage: `Uniques::Asset` (r:6 w:0)
+	/// Storage: `NftHierarchy::Operators` (r:1 w:0)
+	/// Storage: `NftHierarchy::Agents` (r:6 w:0)
+	/// Storage: `NftHierarchy::ProvenanceBounds` (r:b w:b)
+	/// Storage: `NftHierarchy::Provenance` (r:0 w:b * 2)
+	/// The range of component `n` is `[50, 1000]`.
+	/// The range of component `b` is `[1, 50]`.
+	fn remove_ownership_batch(n: u32, b: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6089`
+		// Minimum execution time: 163_425_000 picoseconds.
+		Weight::from_parts(51_384_141, 6089)
+			.saturating_add(Weight::from_parts(2_958_580, 52).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(6_945_513, 88).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads(14_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(b.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((6_u64).saturating_mul(b.into())))
+	}
+	/// Storage: `NftHierarchy::AssetCount` (r:1 w:0)
+	/// Storage: `NftHierarchy::OwnerAssets` (r:n w:0)
+	/// The range of component `n` is `[0, 10]`.
+	fn get_owned_assets(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `156`
+		// Minimum execution time: 4_306_000 picoseconds.
+		Weight::from_parts(4_760_163, 156)
+			.saturating_add(Weight::from_parts(2_188_676, 54).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+	}
+	/// Storage: `NftHierarchy::NftInfos` (r:0 w:1)
+	/// The range of component `t` is `[0, 100]`.
+	fn force_register(t: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		// Minimum execution time: 2_624_000 picoseconds.
+		Weight::from_parts(3_021_380, 0)
+			.saturating_add(Weight::from_parts(42_994, 0).saturating_mul(t.into()))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `NftHierarchy::NftInfos` (r:2 w:0)
+	/// Storage: `NftHierarchy::Loans` (r:1 w:0)
+	/// Storage: `NftHierarchy::OwnerCount` (r:1 w:1)
+	/// Storage: `NftHierarchy::AssetCount` (r:1 w:1)
+	/// Storage: `NftHierarchy::OwnerAssets` (r:n w:1)
+	/// Storage: `NftHierarchy::LentCount` (r:1 w:0)
+	/// Storage: `NftHierarchy::ProvenanceBounds` (r:1 w:1)
+	/// Storage: `NftHierarchy::Provenance` (r:0 w:2)
+	/// The range of component `n` is `[0, 999]`.
+	fn force_set_ownership(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2314`
+		// Minimum execution time: 15_603_000 picoseconds.
+		Weight::from_parts(12_449_278, 2314)
+			.saturating_add(Weight::from_parts(2_290_610, 51).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: `NftHierarchy::Loans` (r:1 w:0)
+	/// Storage: `NftHierarchy::OwnerCount` (r:1 w:1)
+	/// Storage: `NftHierarchy::AssetCount` (r:1 w:1)
+	/// Storage: `NftHierarchy::OwnerAssets` (r:n w:2)
+	/// Storage: `NftHierarchy::ProvenanceBounds` (r:1 w:1)
+	/// Storage: `NftHierarchy::Provenance` (r:0 w:2)
+	/// The range of component `n` is `[1, 1000]`.
+	fn force_unset_ownership(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1546`
+		// Minimum execution time: 14_061_000 picoseconds.
+		Weight::from_parts(14_502_000, 1546)
+			.saturating_add(Weight::from_parts(2_303_270, 51).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: `NftHierarchy::NftInfos` (r:1 w:1)
+	/// Storage: `NftHierarchy::OwnerCount` (r:1 + n w:n)
+	/// Storage: `NftHierarchy::LentCount` (r:1 w:0)
+	/// Storage: `NftHierarchy::AssetCount` (r:1 w:1)
+	/// Storage: `NftHierarchy::OwnerAssets` (r:n w:n)
+	/// Storage: `NftHierarchy::Loans` (r:n w:0)
+	/// Storage: `NftHierarchy::ProvenanceBounds` (r:n w:n)
+	/// Storage: `NftHierarchy::Provenance` (r:0 w:n * 2)
+	/// The range of component `n` is `[0, 1000]`.
+	fn force_unregister(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `415`
+		// Minimum execution time: 9_515_000 picoseconds.
+		Weight::from_parts(9_745_000, 415)
+			.saturating_add(Weight::from_parts(12_320_838, 145).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((5_u64).saturating_mul(n.into())))
+	}
+	/// Storage: `Uniques::Asset` (r:1 w:0)
+	/// Storage: `NftHierarchy::Operators` (r:0 w:1)
+	fn approve_operator() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `214`
+		// Minimum execution time: 6_210_000 picoseconds.
+		Weight::from_parts(6_530_000, 214)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Uniques::Asset` (r:1 w:0)
+	/// Storage: `NftHierarchy::Operators` (r:1 w:1)
+	fn revoke_operator() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `340`
+		// Minimum execution time: 8_052_000 picoseconds.
+		Weight::from_parts(8_373_000, 340)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Uniques::Asset` (r:2 w:0)
+	/// Storage: `NftHierarchy::Agents` (r:1 w:1)
+	/// Storage: `NftHierarchy::AgentCount` (r:1 w:1)
+	fn appoint_agent() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `537`
+		// Minimum execution time: 14_742_000 picoseconds.
+		Weight::from_parts(15_754_000, 537)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Uniques::Asset` (r:2 w:0)
+	/// Storage: `NftHierarchy::Agents` (r:1 w:1)
+	/// Storage: `NftHierarchy::AgentCount` (r:1 w:1)
+	fn dismiss_agent() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `735`
+		// Minimum execution time: 18_237_000 picoseconds.
+		Weight::from_parts(18_908_000, 735)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `NftHierarchy::NftInfos` (r:1 w:0)
+	/// Storage: `NftHierarchy::Loans` (r:1 w:0)
+	/// Storage: `NftHierarchy::LoanOffers` (r:1 w:1)
+	/// Storage: `Uniques::Asset` (r:6 w:0)
+	/// Storage: `NftHierarchy::Operators` (r:1 w:0)
+	/// Storage: `NftHierarchy::Agents` (r:6 w:0)
+	fn lend() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1150`
+		// Minimum execution time: 37_076_000 picoseconds.
+		Weight::from_parts(43_996_000, 1150)
+			.saturating_add(T::DbWeight::get().reads(16_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `NftHierarchy::OwnerCount` (r:1 w:1)
+	/// Storage: `NftHierarchy::LoanOffers` (r:1 w:1)
+	/// Storage: `NftHierarchy::Loans` (r:1 w:1)
+	/// Storage: `NftHierarchy::AssetCount` (r:2 w:2)
+	/// Storage: `NftHierarchy::LentCount` (r:2 w:1)
+	/// Storage: `NftHierarchy::OwnerAssets` (r:n * 2 w:3)
+	/// Storage: `NftHierarchy::LoanExpiries` (r:1 w:1)
+	/// Storage: `NftHierarchy::NextLoanSweep` (r:1 w:1)
+	/// Storage: `Uniques::Asset` (r:6 w:0)
+	/// Storage: `NftHierarchy::Operators` (r:1 w:0)
+	/// Storage: `NftHierarchy::Agents` (r:6 w:0)
+	/// Storage: `NftHierarchy::ProvenanceBounds` (r:1 w:1)
+	/// Storage: `NftHierarchy::Provenance` (r:0 w:4)
+	/// The range of component `n` is `[1, 999]`.
+	fn accept_loan(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4047`
+		// Minimum execution time: 61_773_000 picoseconds.
+		Weight::from_parts(63_836_000, 4047)
+			.saturating_add(Weight::from_parts(4_704_474, 100).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(23_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(16_u64))
+	}
+	/// Storage: `NftHierarchy::LoanOffers` (r:1 w:1)
+	/// Storage: `Uniques::Asset` (r:12 w:0)
+	/// Storage: `NftHierarchy::Operators` (r:2 w:0)
+	/// Storage: `NftHierarchy::Agents` (r:12 w:0)
+	fn cancel_loan_offer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1851`
+		// Minimum execution time: 61_963_000 picoseconds.
+		Weight::from_parts(64_697_000, 1851)
+			.saturating_add(T::DbWeight::get().reads(27_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `NftHierarchy::OwnerCount` (r:1 w:1)
+	/// Storage: `NftHierarchy::Loans` (r:1 w:1)
+	/// Storage: `NftHierarchy::LoanExpiries` (r:1 w:1)
+	/// Storage: `NftHierarchy::AssetCount` (r:2 w:2)
+	/// Storage: `NftHierarchy::OwnerAssets` (r:n w:3)
+	/// Storage: `NftHierarchy::LentCount` (r:1 w:1)
+	/// Storage: `Uniques::Asset` (r:6 w:0)
+	/// Storage: `NftHierarchy::Operators` (r:1 w:0)
+	/// Storage: `NftHierarchy::Agents` (r:6 w:0)
+	/// Storage: `NftHierarchy::ProvenanceBounds` (r:1 w:1)
+	/// Storage: `NftHierarchy::Provenance` (r:0 w:4)
+	/// The range of component `n` is `[1, 1999]`.
+	fn return_loan(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4015`
+		// Minimum execution time: 55_784_000 picoseconds.
+		Weight::from_parts(56_735_000, 4015)
+			.saturating_add(Weight::from_parts(2_498_102, 50).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(20_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(14_u64))
+	}
+	/// Storage: `NftHierarchy::NftInfos` (r:2 w:0)
+	/// Storage: `NftHierarchy::Proposals` (r:1 w:1)
+	/// Storage: `Uniques::Asset` (r:12 w:0)
+	/// Storage: `NftHierarchy::Operators` (r:2 w:0)
+	/// Storage: `NftHierarchy::Agents` (r:12 w:0)
+	fn propose_relationship() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1942`
+		// Minimum execution time: 63_815_000 picoseconds.
+		Weight::from_parts(66_340_000, 1942)
+			.saturating_add(T::DbWeight::get().reads(29_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `NftHierarchy::NftInfos` (r:1 w:0)
+	/// Storage: `NftHierarchy::OwnerCount` (r:1 w:1)
+	/// Storage: `NftHierarchy::Proposals` (r:1 w:1)
+	/// Storage: `NftHierarchy::AssetCount` (r:1 w:1)
+	/// Storage: `NftHierarchy::Loans` (r:1 w:0)
+	/// Storage: `NftHierarchy::LentCount` (r:1 w:0)
+	/// Storage: `NftHierarchy::OwnerAssets` (r:n w:1)
+	/// Storage: `Uniques::Asset` (r:6 w:0)
+	/// Storage: `NftHierarchy::Operators` (r:1 w:0)
+	/// Storage: `NftHierarchy::Agents` (r:6 w:0)
+	/// Storage: `NftHierarchy::ProvenanceBounds` (r:1 w:1)
+	/// Storage: `NftHierarchy::Provenance` (r:0 w:2)
+	/// The range of component `n` is `[0, 999]`.
+	fn accept_relationship(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3357`
+		// Minimum execution time: 46_389_000 picoseconds.
+		Weight::from_parts(48_518_061, 3357)
+			.saturating_add(Weight::from_parts(2_264_500, 51).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(20_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	/// Storage: `NftHierarchy::Proposals` (r:1 w:1)
+	/// Storage: `Uniques::Asset` (r:6 w:0)
+	/// Storage: `NftHierarchy::Operators` (r:1 w:0)
+	/// Storage: `NftHierarchy::Agents` (r:6 w:0)
+	fn reject_relationship() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1176`
+		// Minimum execution time: 36_004_000 picoseconds.
+		Weight::from_parts(43_795_000, 1176)
+			.saturating_add(T::DbWeight::get().reads(14_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `NftHierarchy::Proposals` (r:1 w:1)
+	/// Storage: `Uniques::Asset` (r:6 w:0)
+	/// Storage: `NftHierarchy::Operators` (r:1 w:0)
+	/// Storage: `NftHierarchy::Agents` (r:6 w:0)
+	fn cancel_relationship() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1176`
+		// Minimum execution time: 36_815_000 picoseconds.
+		Weight::from_parts(38_528_000, 1176)
+			.saturating_add(T::DbWeight::get().reads(14_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Uniques::Asset` (r:1 w:0)
+	fn dispatch_as_nft() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `214`
+		// Minimum execution time: 5_929_000 picoseconds.
+		Weight::from_parts(6_380_000, 214)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
+	/// Storage: `NftHierarchy::ProvenanceBounds` (r:1 w:1)
+	/// Storage: `NftHierarchy::Provenance` (r:n + 1 w:n)
+	/// The range of component `n` is `[1, 100]`.
+	fn prune_provenance(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `148`
+		// Minimum execution time: 9_144_000 picoseconds.
+		Weight::from_parts(9_544_000, 148)
+			.saturating_add(Weight::from_parts(3_521_560, 42).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
 }
 
-// For backwards compatibility and tests
+// For backwards compatibility and tests.
 impl WeightInfo for () {
-    fn register_nft() -> Weight {
-        // Proof Size summary in bytes:
-        //  Measured:  `0`
-        //  Estimated: `0`
-        // Minimum execution time: 8_000_000 picoseconds.
-        Weight::from_parts(10_000_000, 0)
-        .saturating_add(RocksDbWeight::get().writes(1_u64))
-    }
-
-    fn create_ownership() -> Weight {
-        // Proof Size summary in bytes:
-        //  Measured:  `0`
-        //  Estimated: `0`
-        // Minimum execution time: 8_000_000 picoseconds.
-        Weight::from_parts(10_000_000, 0)
-        .saturating_add(RocksDbWeight::get().writes(1_u64))
-    }
-
-    fn remove_ownership() -> Weight {
-        // Proof Size summary in bytes:
-        //  Measured:  `32`
-        //  Estimated: `1489`
-        // Minimum execution time: 6_000_000 picoseconds.
-        Weight::from_parts(10_000_000, 1489)
-        .saturating_add(RocksDbWeight::get().reads(1_u64))
-        .saturating_add(RocksDbWeight::get().writes(1_u64))
-    }
-
-    fn get_owned_assets() -> Weight {
-        // Proof Size summary in bytes:
-        //  Measured:  `0`
-        //  Estimated: `0`
-        // Minimum execution time: 8_000_000 picoseconds.
-        Weight::from_parts(10_000_000, 0)
-        .saturating_add(RocksDbWeight::get().writes(1_u64))
-    }
-}
\ No newline at end of file
+	/// Storage: `NftHierarchy::NftInfos` (r:1 w:1)
+	/// Storage: `Uniques::Asset` (r:6 w:0)
+	/// Storage: `NftHierarchy::Operators` (r:1 w:0)
+	/// Storage: `NftHierarchy::Agents` (r:6 w:0)
+	/// The range of component `t` is `[0, 100]`.
+	fn register_nft(t: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1023`
+		// Minimum execution time: 31_878_000 picoseconds.
+		Weight::from_parts(33_970_381, 1023)
+			.saturating_add(Weight::from_parts(49_554, 0).saturating_mul(t.into()))
+			.saturating_add(RocksDbWeight::get().reads(14_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `NftHierarchy::NftInfos` (r:2 w:0)
+	/// Storage: `NftHierarchy::OwnerCount` (r:1 w:1)
+	/// Storage: `NftHierarchy::AssetCount` (r:1 w:1)
+	/// Storage: `NftHierarchy::OwnerAssets` (r:n w:1)
+	/// Storage: `NftHierarchy::Loans` (r:1 w:0)
+	/// Storage: `NftHierarchy::LentCount` (r:1 w:0)
+	/// Storage: `Uniques::Asset` (r:7 w:0)
+	/// Storage: `NftHierarchy::Operators` (r:1 w:0)
+	/// Storage: `NftHierarchy::Agents` (r:6 w:0)
+	/// Storage: `NftHierarchy::ProvenanceBounds` (r:1 w:1)
+	/// Storage: `NftHierarchy::Provenance` (r:0 w:2)
+	/// The range of component `n` is `[0, 1000]`.
+	fn create_ownership(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3262`
+		// Minimum execution time: 43_906_000 picoseconds.
+		Weight::from_parts(56_614_426, 3262)
+			.saturating_add(Weight::from_parts(2_212_948, 51).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(21_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: `NftHierarchy::OwnerCount` (r:1 w:1)
+	/// Storage: `NftHierarchy::Loans` (r:1 w:0)
+	/// Storage: `NftHierarchy::AssetCount` (r:1 w:1)
+	/// Storage: `NftHierarchy::OwnerAssets` (r:n w:2)
+	/// Storage: `Uniques::Asset` (r:6 w:0)
+	/// Storage: `NftHierarchy::Operators` (r:1 w:0)
+	/// Storage: `NftHierarchy::Agents` (r:6 w:0)
+	/// Storage: `NftHierarchy::ProvenanceBounds` (r:1 w:1)
+	/// Storage: `NftHierarchy::Provenance` (r:0 w:2)
+	/// The range of component `n` is `[1, 1000]`.
+	fn remove_ownership(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2494`
+		// Minimum execution time: 41_893_000 picoseconds.
+		Weight::from_parts(32_509_427, 2494)
+			.saturating_add(Weight::from_parts(2_304_041, 51).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(17_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	/// Storage: `NftHierarchy::NftInfos` (r:b w:b)
+	/// Storage: `Uniques::Asset` (r:b * 6 w:0)
+	/// Storage: `NftHierarchy::Operators` (r:b w:0)
+	/// Storage: `NftHierarchy::Agents` (r:b * 6 w:0)
+	/// The range of component `b` is `[1, 50]`.
+	fn register_nft_batch(b: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `273`
+		// Minimum execution time: 39_059_000 picoseconds.
+		Weight::from_parts(39_489_000, 273)
+			.saturating_add(Weight::from_parts(39_999_756, 776).saturating_mul(b.into()))
+			.saturating_add(RocksDbWeight::get().reads((14_u64).saturating_mul(b.into())))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(b.into())))
+	}
+	/// Storage: `NftHierarchy::NftInfos` (r:1 + b w:0)
+	/// Storage: `NftHierarchy::OwnerCount` (r:b w:b)
+	/// Storage: `NftHierarchy::AssetCount` (r:1 w:1)
+	/// Storage: `NftHierarchy::OwnerAssets` (r:n w:b)
+	/// Storage: `NftHierarchy::Loans` (r:b w:0)
+	/// Storage: `NftHierarchy::LentCount` (r:1 w:0)
+	/// Storage: `Uniques::Asset` (r:6 + b w:0)
+	/// Storage: `NftHierarchy::Operators` (r:1 w:0)
+	/// Storage: `NftHierarchy::Agents` (r:6 w:0)
+	/// Storage: `NftHierarchy::ProvenanceBounds` (r:b w:b)
+	/// Storage: `NftHierarchy::Provenance` (r:0 w:b * 2)
+	/// The range of component `n` is `[0, 950]`.
+	/// The range of component `b` is `[1, 50]`.
+	fn create_ownership_batch(n: u32, b: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3605`
+		// Minimum execution time: 46_600_000 picoseconds.
+		Weight::from_parts(8_828_644, 3605)
+			.saturating_add(Weight::from_parts(2_987_067, 54).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(9_205_266, 126).saturating_mul(b.into()))
+			.saturating_add(RocksDbWeight::get().reads(16_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().reads((5_u64).saturating_mul(b.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((5_u64).saturating_mul(b.into())))
+	}
+	/// Storage: `NftHierarchy::OwnerCount` (r:b w:b)
+	/// Storage: `NftHierarchy::Loans` (r:b w:0)
+	/// Storage: `NftHierarchy::AssetCount` (r:1 w:1)
+	/// Storage: `NftHierarchy::OwnerAssets` (r:n w:b * 2)
+	/// Storage: `Uniques::Asset` (r:6 w:0)
+	/// Storage: `NftHierarchy::Operators` (r:1 w:0)
+	/// Storage: `NftHierarchy::Agents` (r:6 w:0)
+	/// Storage: `NftHierarchy::ProvenanceBounds` (r:b w:b)
+	/// Storage: `NftHierarchy::Provenance` (r:0 w:b * 2)
+	/// The range of component `n` is `[50, 1000]`.
+	/// The range of component `b` is `[1, 50]`.
+	fn remove_ownership_batch(n: u32, b: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6089`
+		// Minimum execution time: 163_425_000 picoseconds.
+		Weight::from_parts(51_384_141, 6089)
+			.saturating_add(Weight::from_parts(2_958_580, 52).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(6_945_513, 88).saturating_mul(b.into()))
+			.saturating_add(RocksDbWeight::get().reads(14_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(b.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((6_u64).saturating_mul(b.into())))
+	}
+	/// Storage: `NftHierarchy::AssetCount` (r:1 w:0)
+	/// Storage: `NftHierarchy::OwnerAssets` (r:n w:0)
+	/// The range of component `n` is `[0, 10]`.
+	fn get_owned_assets(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `156`
+		// Minimum execution time: 4_306_000 picoseconds.
+		Weight::from_parts(4_760_163, 156)
+			.saturating_add(Weight::from_parts(2_188_676, 54).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+	}
+	/// Storage: `NftHierarchy::NftInfos` (r:0 w:1)
+	/// The range of component `t` is `[0, 100]`.
+	fn force_register(t: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		// Minimum execution time: 2_624_000 picoseconds.
+		Weight::from_parts(3_021_380, 0)
+			.saturating_add(Weight::from_parts(42_994, 0).saturating_mul(t.into()))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `NftHierarchy::NftInfos` (r:2 w:0)
+	/// Storage: `NftHierarchy::Loans` (r:1 w:0)
+	/// Storage: `NftHierarchy::OwnerCount` (r:1 w:1)
+	/// Storage: `NftHierarchy::AssetCount` (r:1 w:1)
+	/// Storage: `NftHierarchy::OwnerAssets` (r:n w:1)
+	/// Storage: `NftHierarchy::LentCount` (r:1 w:0)
+	/// Storage: `NftHierarchy::ProvenanceBounds` (r:1 w:1)
+	/// Storage: `NftHierarchy::Provenance` (r:0 w:2)
+	/// The range of component `n` is `[0, 999]`.
+	fn force_set_ownership(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2314`
+		// Minimum execution time: 15_603_000 picoseconds.
+		Weight::from_parts(12_449_278, 2314)
+			.saturating_add(Weight::from_parts(2_290_610, 51).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: `NftHierarchy::Loans` (r:1 w:0)
+	/// Storage: `NftHierarchy::OwnerCount` (r:1 w:1)
+	/// Storage: `NftHierarchy::AssetCount` (r:1 w:1)
+	/// Storage: `NftHierarchy::OwnerAssets` (r:n w:2)
+	/// Storage: `NftHierarchy::ProvenanceBounds` (r:1 w:1)
+	/// Storage: `NftHierarchy::Provenance` (r:0 w:2)
+	/// The range of component `n` is `[1, 1000]`.
+	fn force_unset_ownership(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1546`
+		// Minimum execution time: 14_061_000 picoseconds.
+		Weight::from_parts(14_502_000, 1546)
+			.saturating_add(Weight::from_parts(2_303_270, 51).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: `NftHierarchy::NftInfos` (r:1 w:1)
+	/// Storage: `NftHierarchy::OwnerCount` (r:1 + n w:n)
+	/// Storage: `NftHierarchy::LentCount` (r:1 w:0)
+	/// Storage: `NftHierarchy::AssetCount` (r:1 w:1)
+	/// Storage: `NftHierarchy::OwnerAssets` (r:n w:n)
+	/// Storage: `NftHierarchy::Loans` (r:n w:0)
+	/// Storage: `NftHierarchy::ProvenanceBounds` (r:n w:n)
+	/// Storage: `NftHierarchy::Provenance` (r:0 w:n * 2)
+	/// The range of component `n` is `[0, 1000]`.
+	fn force_unregister(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `415`
+		// Minimum execution time: 9_515_000 picoseconds.
+		Weight::from_parts(9_745_000, 415)
+			.saturating_add(Weight::from_parts(12_320_838, 145).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((5_u64).saturating_mul(n.into())))
+	}
+	/// Storage: `Uniques::Asset` (r:1 w:0)
+	/// Storage: `NftHierarchy::Operators` (r:0 w:1)
+	fn approve_operator() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `214`
+		// Minimum execution time: 6_210_000 picoseconds.
+		Weight::from_parts(6_530_000, 214)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Uniques::Asset` (r:1 w:0)
+	/// Storage: `NftHierarchy::Operators` (r:1 w:1)
+	fn revoke_operator() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `340`
+		// Minimum execution time: 8_052_000 picoseconds.
+		Weight::from_parts(8_373_000, 340)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Uniques::Asset` (r:2 w:0)
+	/// Storage: `NftHierarchy::Agents` (r:1 w:1)
+	/// Storage: `NftHierarchy::AgentCount` (r:1 w:1)
+	fn appoint_agent() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `537`
+		// Minimum execution time: 14_742_000 picoseconds.
+		Weight::from_parts(15_754_000, 537)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Uniques::Asset` (r:2 w:0)
+	/// Storage: `NftHierarchy::Agents` (r:1 w:1)
+	/// Storage: `NftHierarchy::AgentCount` (r:1 w:1)
+	fn dismiss_agent() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `735`
+		// Minimum execution time: 18_237_000 picoseconds.
+		Weight::from_parts(18_908_000, 735)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `NftHierarchy::NftInfos` (r:1 w:0)
+	/// Storage: `NftHierarchy::Loans` (r:1 w:0)
+	/// Storage: `NftHierarchy::LoanOffers` (r:1 w:1)
+	/// Storage: `Uniques::Asset` (r:6 w:0)
+	/// Storage: `NftHierarchy::Operators` (r:1 w:0)
+	/// Storage: `NftHierarchy::Agents` (r:6 w:0)
+	fn lend() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1150`
+		// Minimum execution time: 37_076_000 picoseconds.
+		Weight::from_parts(43_996_000, 1150)
+			.saturating_add(RocksDbWeight::get().reads(16_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `NftHierarchy::OwnerCount` (r:1 w:1)
+	/// Storage: `NftHierarchy::LoanOffers` (r:1 w:1)
+	/// Storage: `NftHierarchy::Loans` (r:1 w:1)
+	/// Storage: `NftHierarchy::AssetCount` (r:2 w:2)
+	/// Storage: `NftHierarchy::LentCount` (r:2 w:1)
+	/// Storage: `NftHierarchy::OwnerAssets` (r:n * 2 w:3)
+	/// Storage: `NftHierarchy::LoanExpiries` (r:1 w:1)
+	/// Storage: `NftHierarchy::NextLoanSweep` (r:1 w:1)
+	/// Storage: `Uniques::Asset` (r:6 w:0)
+	/// Storage: `NftHierarchy::Operators` (r:1 w:0)
+	/// Storage: `NftHierarchy::Agents` (r:6 w:0)
+	/// Storage: `NftHierarchy::ProvenanceBounds` (r:1 w:1)
+	/// Storage: `NftHierarchy::Provenance` (r:0 w:4)
+	/// The range of component `n` is `[1, 999]`.
+	fn accept_loan(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4047`
+		// Minimum execution time: 61_773_000 picoseconds.
+		Weight::from_parts(63_836_000, 4047)
+			.saturating_add(Weight::from_parts(4_704_474, 100).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(23_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(16_u64))
+	}
+	/// Storage: `NftHierarchy::LoanOffers` (r:1 w:1)
+	/// Storage: `Uniques::Asset` (r:12 w:0)
+	/// Storage: `NftHierarchy::Operators` (r:2 w:0)
+	/// Storage: `NftHierarchy::Agents` (r:12 w:0)
+	fn cancel_loan_offer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1851`
+		// Minimum execution time: 61_963_000 picoseconds.
+		Weight::from_parts(64_697_000, 1851)
+			.saturating_add(RocksDbWeight::get().reads(27_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `NftHierarchy::OwnerCount` (r:1 w:1)
+	/// Storage: `NftHierarchy::Loans` (r:1 w:1)
+	/// Storage: `NftHierarchy::LoanExpiries` (r:1 w:1)
+	/// Storage: `NftHierarchy::AssetCount` (r:2 w:2)
+	/// Storage: `NftHierarchy::OwnerAssets` (r:n w:3)
+	/// Storage: `NftHierarchy::LentCount` (r:1 w:1)
+	/// Storage: `Uniques::Asset` (r:6 w:0)
+	/// Storage: `NftHierarchy::Operators` (r:1 w:0)
+	/// Storage: `NftHierarchy::Agents` (r:6 w:0)
+	/// Storage: `NftHierarchy::ProvenanceBounds` (r:1 w:1)
+	/// Storage: `NftHierarchy::Provenance` (r:0 w:4)
+	/// The range of component `n` is `[1, 1999]`.
+	fn return_loan(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4015`
+		// Minimum execution time: 55_784_000 picoseconds.
+		Weight::from_parts(56_735_000, 4015)
+			.saturating_add(Weight::from_parts(2_498_102, 50).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(20_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(14_u64))
+	}
+	/// Storage: `NftHierarchy::NftInfos` (r:2 w:0)
+	/// Storage: `NftHierarchy::Proposals` (r:1 w:1)
+	/// Storage: `Uniques::Asset` (r:12 w:0)
+	/// Storage: `NftHierarchy::Operators` (r:2 w:0)
+	/// Storage: `NftHierarchy::Agents` (r:12 w:0)
+	fn propose_relationship() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1942`
+		// Minimum execution time: 63_815_000 picoseconds.
+		Weight::from_parts(66_340_000, 1942)
+			.saturating_add(RocksDbWeight::get().reads(29_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `NftHierarchy::NftInfos` (r:1 w:0)
+	/// Storage: `NftHierarchy::OwnerCount` (r:1 w:1)
+	/// Storage: `NftHierarchy::Proposals` (r:1 w:1)
+	/// Storage: `NftHierarchy::AssetCount` (r:1 w:1)
+	/// Storage: `NftHierarchy::Loans` (r:1 w:0)
+	/// Storage: `NftHierarchy::LentCount` (r:1 w:0)
+	/// Storage: `NftHierarchy::OwnerAssets` (r:n w:1)
+	/// Storage: `Uniques::Asset` (r:6 w:0)
+	/// Storage: `NftHierarchy::Operators` (r:1 w:0)
+	/// Storage: `NftHierarchy::Agents` (r:6 w:0)
+	/// Storage: `NftHierarchy::ProvenanceBounds` (r:1 w:1)
+	/// Storage: `NftHierarchy::Provenance` (r:0 w:2)
+	/// The range of component `n` is `[0, 999]`.
+	fn accept_relationship(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3357`
+		// Minimum execution time: 46_389_000 picoseconds.
+		Weight::from_parts(48_518_061, 3357)
+			.saturating_add(Weight::from_parts(2_264_500, 51).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(20_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	/// Storage: `NftHierarchy::Proposals` (r:1 w:1)
+	/// Storage: `Uniques::Asset` (r:6 w:0)
+	/// Storage: `NftHierarchy::Operators` (r:1 w:0)
+	/// Storage: `NftHierarchy::Agents` (r:6 w:0)
+	fn reject_relationship() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1176`
+		// Minimum execution time: 36_004_000 picoseconds.
+		Weight::from_parts(43_795_000, 1176)
+			.saturating_add(RocksDbWeight::get().reads(14_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `NftHierarchy::Proposals` (r:1 w:1)
+	/// Storage: `Uniques::Asset` (r:6 w:0)
+	/// Storage: `NftHierarchy::Operators` (r:1 w:0)
+	/// Storage: `NftHierarchy::Agents` (r:6 w:0)
+	fn cancel_relationship() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1176`
+		// Minimum execution time: 36_815_000 picoseconds.
+		Weight::from_parts(38_528_000, 1176)
+			.saturating_add(RocksDbWeight::get().reads(14_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Uniques::Asset` (r:1 w:0)
+	fn dispatch_as_nft() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `214`
+		// Minimum execution time: 5_929_000 picoseconds.
+		Weight::from_parts(6_380_000, 214)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
+	/// Storage: `NftHierarchy::ProvenanceBounds` (r:1 w:1)
+	/// Storage: `NftHierarchy::Provenance` (r:n + 1 w:n)
+	/// The range of component `n` is `[1, 100]`.
+	fn prune_provenance(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `148`
+		// Minimum execution time: 9_144_000 picoseconds.
+		Weight::from_parts(9_544_000, 148)
+			.saturating_add(Weight::from_parts(3_521_560, 42).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+}
@@ -0,0 +1,152 @@
+//! Adapter that exposes `pallet_uniques` through the `nonfungibles_v2` traits the NFT pallets of
+//! Telurean Chain are written against, so that the pallets themselves do not depend on it.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+use core::marker::PhantomData;
+use frame_support::{
+    storage::KeyPrefixIterator,
+    traits::tokens::{nonfungibles, nonfungibles_v2},
+};
+use pallet_nft_hierarchy::traits::InspectItemsFrom;
+use sp_runtime::{DispatchError, DispatchResult, TokenError};
+use sp_std::vec::Vec;
+
+/// Exposes `pallet_uniques` through the `nonfungibles_v2` traits. The pallet has no configuration
+/// for collections and items, so the adapter takes `()` for both, and collections are always
+/// created with an id.
+pub struct UniquesAdapter<T, I = ()>(PhantomData<(T, I)>);
+
+type Uniques<T, I> = pallet_uniques::Pallet<T, I>;
+
+impl<T: pallet_uniques::Config<I>, I: 'static> nonfungibles_v2::Inspect<T::AccountId>
+    for UniquesAdapter<T, I>
+{
+    type ItemId = T::ItemId;
+    type CollectionId = T::CollectionId;
+
+    fn owner(collection: &Self::CollectionId, item: &Self::ItemId) -> Option<T::AccountId> {
+        <Uniques<T, I> as nonfungibles::Inspect<_>>::owner(collection, item)
+    }
+
+    fn collection_owner(collection: &Self::CollectionId) -> Option<T::AccountId> {
+        <Uniques<T, I> as nonfungibles::Inspect<_>>::collection_owner(collection)
+    }
+
+    fn attribute(
+        collection: &Self::CollectionId,
+        item: &Self::ItemId,
+        key: &[u8],
+    ) -> Option<Vec<u8>> {
+        <Uniques<T, I> as nonfungibles::Inspect<_>>::attribute(collection, item, key)
+    }
+
+    fn collection_attribute(collection: &Self::CollectionId, key: &[u8]) -> Option<Vec<u8>> {
+        <Uniques<T, I> as nonfungibles::Inspect<_>>::collection_attribute(collection, key)
+    }
+
+    fn can_transfer(collection: &Self::CollectionId, item: &Self::ItemId) -> bool {
+        <Uniques<T, I> as nonfungibles::Inspect<_>>::can_transfer(collection, item)
+    }
+}
+
+impl<T: pallet_uniques::Config<I>, I: 'static> nonfungibles_v2::InspectEnumerable<T::AccountId>
+    for UniquesAdapter<T, I>
+{
+    type CollectionsIterator = KeyPrefixIterator<T::CollectionId>;
+    type ItemsIterator = KeyPrefixIterator<T::ItemId>;
+    type OwnedIterator = KeyPrefixIterator<(T::CollectionId, T::ItemId)>;
+    type OwnedInCollectionIterator = KeyPrefixIterator<T::ItemId>;
+
+    fn collections() -> Self::CollectionsIterator {
+        <Uniques<T, I> as nonfungibles::InspectEnumerable<_>>::collections()
+    }
+
+    /// Returns the items of `collection` from the items themselves, as the enumeration of
+    /// `pallet_uniques` only lists the items that have metadata.
+    fn items(collection: &Self::CollectionId) -> Self::ItemsIterator {
+        pallet_uniques::Item::<T, I>::iter_key_prefix(collection)
+    }
+
+    fn owned(who: &T::AccountId) -> Self::OwnedIterator {
+        <Uniques<T, I> as nonfungibles::InspectEnumerable<_>>::owned(who)
+    }
+
+    fn owned_in_collection(
+        collection: &Self::CollectionId,
+        who: &T::AccountId,
+    ) -> Self::OwnedInCollectionIterator {
+        <Uniques<T, I> as nonfungibles::InspectEnumerable<_>>::owned_in_collection(collection, who)
+    }
+}
+
+impl<T: pallet_uniques::Config<I>, I: 'static> InspectItemsFrom<T::CollectionId, T::ItemId>
+    for UniquesAdapter<T, I>
+{
+    type ItemsIterator = KeyPrefixIterator<T::ItemId>;
+
+    fn items_from(
+        collection: &T::CollectionId,
+        start_after: Option<&T::ItemId>,
+    ) -> Self::ItemsIterator {
+        match start_after {
+            Some(start_after) => pallet_uniques::Item::<T, I>::iter_key_prefix_from(
+                collection,
+                pallet_uniques::Item::<T, I>::hashed_key_for(collection, start_after),
+            ),
+            None => pallet_uniques::Item::<T, I>::iter_key_prefix(collection),
+        }
+    }
+}
+
+impl<T: pallet_uniques::Config<I>, I: 'static> nonfungibles_v2::Create<T::AccountId, ()>
+    for UniquesAdapter<T, I>
+{
+    /// Not supported: `pallet_uniques` has no way to pick the id of a new collection.
+    fn create_collection(
+        _who: &T::AccountId,
+        _admin: &T::AccountId,
+        _config: &(),
+    ) -> Result<Self::CollectionId, DispatchError> {
+        Err(TokenError::Unsupported.into())
+    }
+
+    fn create_collection_with_id(
+        collection: Self::CollectionId,
+        who: &T::AccountId,
+        admin: &T::AccountId,
+        _config: &(),
+    ) -> DispatchResult {
+        <Uniques<T, I> as nonfungibles::Create<_>>::create_collection(&collection, who, admin)
+    }
+}
+
+impl<T: pallet_uniques::Config<I>, I: 'static> nonfungibles_v2::Mutate<T::AccountId, ()>
+    for UniquesAdapter<T, I>
+{
+    /// Mint `item` for `who`. The owner of the collection always pays the deposit in
+    /// `pallet_uniques`, so `deposit_collection_owner` is ignored.
+    fn mint_into(
+        collection: &Self::CollectionId,
+        item: &Self::ItemId,
+        who: &T::AccountId,
+        _config: &(),
+        _deposit_collection_owner: bool,
+    ) -> DispatchResult {
+        <Uniques<T, I> as nonfungibles::Mutate<_>>::mint_into(collection, item, who)
+    }
+
+    fn burn(
+        collection: &Self::CollectionId,
+        item: &Self::ItemId,
+        maybe_check_owner: Option<&T::AccountId>,
+    ) -> DispatchResult {
+        <Uniques<T, I> as nonfungibles::Mutate<_>>::burn(collection, item, maybe_check_owner)
+    }
+}
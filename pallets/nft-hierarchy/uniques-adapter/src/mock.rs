@@ -0,0 +1,123 @@
+use frame_support::{
+    derive_impl, parameter_types,
+    traits::{ConstU32, VariantCountOf},
+};
+use frame_system::{EnsureRoot, EnsureSigned};
+use sp_runtime::BuildStorage;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+#[frame_support::runtime]
+mod runtime {
+    #[runtime::runtime]
+    #[runtime::derive(
+        RuntimeCall,
+        RuntimeEvent,
+        RuntimeError,
+        RuntimeOrigin,
+        RuntimeFreezeReason,
+        RuntimeHoldReason,
+        RuntimeSlashReason,
+        RuntimeLockId,
+        RuntimeTask,
+        RuntimeViewFunction
+    )]
+    pub struct Test;
+
+    #[runtime::pallet_index(0)]
+    pub type System = frame_system::Pallet<Test>;
+
+    #[runtime::pallet_index(1)]
+    pub type Balances = pallet_balances::Pallet<Test>;
+
+    #[runtime::pallet_index(2)]
+    pub type Uniques = pallet_uniques::Pallet<Test>;
+}
+
+// frame_system
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+    type Block = Block;
+    type AccountId = u64;
+    type AccountData = pallet_balances::AccountData<u128>;
+}
+
+// pallet_balances
+parameter_types! {
+    pub const ExistentialDeposit: u128 = 1_000_000_000_000;
+}
+
+impl pallet_balances::Config for Test {
+    type MaxLocks = ConstU32<50>;
+    type MaxReserves = ();
+    type ReserveIdentifier = [u8; 8];
+    type Balance = u128;
+    type RuntimeEvent = RuntimeEvent;
+    type DustRemoval = ();
+    type ExistentialDeposit = ExistentialDeposit;
+    type AccountStore = System;
+    type WeightInfo = ();
+    type FreezeIdentifier = RuntimeFreezeReason;
+    type MaxFreezes = VariantCountOf<RuntimeFreezeReason>;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type RuntimeFreezeReason = RuntimeFreezeReason;
+    type DoneSlashHandler = ();
+}
+
+// pallet_uniques
+parameter_types! {
+    pub const CollectionDeposit: u128 = 1_000_000_000_000;
+    pub const ItemDeposit: u128 = 100_000_000_000;
+    pub const MetadataDepositBase: u128 = 100_000_000_000;
+    pub const AttributeDepositBase: u128 = 10_000_000_000;
+    pub const DepositPerByte: u128 = 1_000_000_000;
+}
+
+impl pallet_uniques::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type CollectionId = u32;
+    type ItemId = u128;
+    type Currency = Balances;
+    type ForceOrigin = EnsureRoot<Self::AccountId>;
+    type CreateOrigin = EnsureSigned<Self::AccountId>;
+    type Locker = ();
+    type CollectionDeposit = CollectionDeposit;
+    type ItemDeposit = ItemDeposit;
+    type MetadataDepositBase = MetadataDepositBase;
+    type AttributeDepositBase = AttributeDepositBase;
+    type DepositPerByte = DepositPerByte;
+    type StringLimit = ConstU32<128>;
+    type KeyLimit = ConstU32<64>;
+    type ValueLimit = ConstU32<256>;
+    type WeightInfo = pallet_uniques::weights::SubstrateWeight<Test>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type Helper = ();
+}
+
+// Build genesis storage according to the mock runtime.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let mut storage = frame_system::GenesisConfig::<Test>::default()
+        .build_storage()
+        .unwrap();
+
+    // Account 1 with enough balance to pay the deposits of pallet_uniques.
+    pallet_balances::GenesisConfig::<Test> {
+        balances: vec![(1, 1_000_000_000_000_000)],
+        dev_accounts: None,
+    }
+    .assimilate_storage(&mut storage)
+    .unwrap();
+
+    let mut ext = sp_io::TestExternalities::new(storage);
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
+
+/// Mint the item `item` of the collection `collection` for `owner`, creating the collection if it
+/// does not exist yet. Collections are created by account 1, the only one with funds.
+pub fn mint(collection: u32, item: u128, owner: u64) {
+    if Uniques::collection_owner(collection).is_none() {
+        Uniques::create(RuntimeOrigin::signed(1), collection, 1).unwrap();
+    }
+    Uniques::mint(RuntimeOrigin::signed(1), collection, item, owner).unwrap();
+}
@@ -0,0 +1,58 @@
+use crate::mock::{mint, new_test_ext, Test};
+use crate::UniquesAdapter;
+use frame_support::{
+    assert_noop, assert_ok,
+    traits::tokens::nonfungibles_v2::{Create, Inspect, InspectEnumerable, Mutate},
+};
+use pallet_nft_hierarchy::traits::InspectItemsFrom;
+use sp_runtime::TokenError;
+
+type Nfts = UniquesAdapter<Test>;
+
+#[test]
+fn lists_the_items_without_metadata() {
+    new_test_ext().execute_with(|| {
+        mint(1, 1, 2);
+        mint(1, 2, 3);
+        mint(2, 3, 2);
+
+        let mut items: Vec<u128> = Nfts::items(&1).collect();
+        items.sort();
+        assert_eq!(items, vec![1, 2]);
+    });
+}
+
+#[test]
+fn lists_the_items_from_a_burned_cursor() {
+    new_test_ext().execute_with(|| {
+        for item in 1..=4 {
+            mint(1, item, 2);
+        }
+        let all: Vec<u128> = Nfts::items_from(&1, None).collect();
+        assert_eq!(all.len(), 4);
+
+        assert_ok!(Nfts::burn(&1, &all[1], None));
+        let rest: Vec<u128> = Nfts::items_from(&1, Some(&all[1])).collect();
+        assert_eq!(rest, all[2..].to_vec());
+    });
+}
+
+#[test]
+fn creates_collections_and_mints_items() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Nfts::create_collection_with_id(1, &1, &1, &()));
+        assert_ok!(Nfts::mint_into(&1, &1, &2, &(), true));
+        assert_eq!(Nfts::collection_owner(&1), Some(1));
+        assert_eq!(Nfts::owner(&1, &1), Some(2));
+
+        assert_ok!(Nfts::burn(&1, &1, Some(&2)));
+        assert_eq!(Nfts::owner(&1, &1), None);
+    });
+}
+
+#[test]
+fn fails_to_create_a_collection_without_an_id() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(Nfts::create_collection(&1, &1, &()), TokenError::Unsupported);
+    });
+}
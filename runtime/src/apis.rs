@@ -42,8 +42,9 @@ use sp_version::RuntimeVersion;
 
 // Local module imports
 use super::{
-    AccountId, Aura, Balance, Block, Executive, Grandpa, InherentDataExt, Nonce, Runtime,
-    RuntimeCall, RuntimeGenesisConfig, SessionKeys, System, TransactionPayment, VERSION,
+    AccountId, Aura, Balance, Block, BlockNumber, Executive, Grandpa, InherentDataExt,
+    NftAttributes, NftHierarchy, Nonce, Runtime, RuntimeCall, RuntimeGenesisConfig, SessionKeys,
+    System, TransactionPayment, VERSION,
 };
 
 impl_runtime_apis! {
@@ -220,6 +221,41 @@ impl_runtime_apis! {
         }
     }
 
+    impl pallet_nft_attributes_runtime_api::NftAttributesApi<Block, u32, u128> for Runtime {
+        fn attributes(collection: u32, item: u128) -> Vec<(Vec<u8>, Vec<u8>)> {
+            NftAttributes::attributes(collection, item)
+        }
+
+        fn attribute(collection: u32, item: u128, key: Vec<u8>) -> Option<Vec<u8>> {
+            NftAttributes::attribute(collection, item, &key)
+        }
+
+        fn items_matching(
+            collection: u32,
+            key: Vec<u8>,
+            filter: pallet_nft_attributes::AttributeFilter,
+            start_after: Option<u128>,
+            limit: u32,
+        ) -> (Vec<u128>, Option<u128>) {
+            NftAttributes::items_matching(collection, &key, &filter, start_after, limit)
+        }
+
+        fn effective_attributes(collection: u32, item: u128) -> Vec<(Vec<u8>, Vec<u8>)> {
+            NftAttributes::effective_attributes(collection, item)
+        }
+    }
+
+    impl pallet_nft_hierarchy_runtime_api::NftHierarchyApi<Block, u32, u128, BlockNumber> for Runtime {
+        fn provenance(
+            collection: u32,
+            item: u128,
+            start: u32,
+            limit: u32,
+        ) -> Vec<(u32, pallet_nft_hierarchy::ProvenanceEntry<u32, u128, BlockNumber>)> {
+            NftHierarchy::provenance(collection, item, start, limit)
+        }
+    }
+
     #[cfg(feature = "runtime-benchmarks")]
     impl frame_benchmarking::Benchmark<Block> for Runtime {
         fn benchmark_metadata(extra: bool) -> (
@@ -31,5 +31,6 @@ frame_benchmarking::define_benchmarks!(
     [pallet_timestamp, Timestamp]
     [pallet_sudo, Sudo]
     [pallet_nft_attributes, NftAttributes]
+    [pallet_dice, Dice]
     [pallet_nft_hierarchy, NftHierarchy]
 );
@@ -31,21 +31,27 @@ use frame_support::{
         constants::{RocksDbWeight, WEIGHT_REF_TIME_PER_SECOND},
         IdentityFee, Weight,
     },
+    PalletId,
 };
 use frame_system::{
     limits::{BlockLength, BlockWeights},
     EnsureRoot, EnsureSigned,
 };
+use pallet_nft_hierarchy_uniques_adapter::UniquesAdapter;
 use pallet_transaction_payment::{ConstFeeMultiplier, FungibleAdapter, Multiplier};
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
-use sp_runtime::{traits::One, Perbill};
+use sp_runtime::{
+    traits::{One, Verify},
+    Perbill,
+};
 use sp_version::RuntimeVersion;
 
 // Local module imports
 use super::{
-    AccountId, Aura, Balance, Balances, Block, BlockNumber, Hash, Nonce, PalletInfo, Runtime,
-    RuntimeCall, RuntimeEvent, RuntimeFreezeReason, RuntimeHoldReason, RuntimeOrigin, RuntimeTask,
-    System, EXISTENTIAL_DEPOSIT, SLOT_DURATION, VERSION,
+    AccountId, Aura, Balance, Balances, Block, BlockNumber, Hash, NftAttributes, NftHierarchy,
+    Nonce, PalletInfo, Runtime, RuntimeCall, RuntimeEvent, RuntimeFreezeReason, RuntimeHoldReason,
+    RuntimeOrigin, RuntimeTask, Signature, System, DAYS, EXISTENTIAL_DEPOSIT, MINUTES,
+    SLOT_DURATION, UNIT, VERSION,
 };
 
 const NORMAL_DISPATCH_RATIO: Perbill = Perbill::from_percent(75);
@@ -167,7 +173,7 @@ impl pallet_uniques::Config for Runtime {
     type Currency = Balances; // Para pagos relacionados con creación/emisión
     type ForceOrigin = EnsureRoot<AccountId>; // Para acciones privilegiadas
     type CreateOrigin = EnsureSigned<AccountId>; // Quién puede crear colecciones
-    type Locker = (); // Bloqueo de NFTs, si es necesario
+    type Locker = NftHierarchy; // Bloquea los NFTs prestados
     type CollectionDeposit = ConstU128<1_000_000_000_000>; // Depósito para crear colecciones
     type ItemDeposit = ConstU128<100_000_000_000>; // Depósito por NFT
     type MetadataDepositBase = ConstU128<100_000_000_000>;
@@ -177,6 +183,37 @@ impl pallet_uniques::Config for Runtime {
     type KeyLimit = ConstU32<64>;
     type ValueLimit = ConstU32<256>;
     type WeightInfo = pallet_uniques::weights::SubstrateWeight<Runtime>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type Helper = ();
+}
+
+/// Mints the NFTs of the benchmarks in pallet_uniques, in collections owned by their first owner.
+#[cfg(feature = "runtime-benchmarks")]
+pub struct NftsBenchmarkHelper;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_nft_hierarchy::BenchmarkHelper<u32, u128, AccountId> for NftsBenchmarkHelper {
+    fn mint(collection: &u32, item: &u128, owner: &AccountId) {
+        use super::Uniques;
+
+        if Uniques::collection_owner(*collection).is_none() {
+            Uniques::do_create_collection(
+                *collection,
+                owner.clone(),
+                owner.clone(),
+                Default::default(),
+                true,
+                pallet_uniques::Event::ForceCreated { collection: *collection, owner: owner.clone() },
+            )
+            .expect("the collection does not exist yet");
+        }
+        Uniques::do_mint(*collection, *item, owner.clone(), |_| Ok(()))
+            .expect("the item does not exist yet");
+    }
+}
+
+parameter_types! {
+    pub const NftAccountsPalletId: PalletId = PalletId(*b"py/nftac");
 }
 
 impl pallet_nft_hierarchy::Config for Runtime {
@@ -187,12 +224,66 @@ impl pallet_nft_hierarchy::Config for Runtime {
     type StringLimit = ConstU32<128>;
     type TypeLimit = ConstU32<100>;
     type MaxAssetsPerTransaction = ConstU32<10>;
+    type MaxInventorySize = ConstU32<1_000>;
+    type MaxBatchSize = ConstU32<50>;
+    type MaxAgents = ConstU32<5>;
+    type MaxLoansPerBlock = ConstU32<50>;
+    type MaxProvenance = ConstU32<100>;
+    type ProvenanceRetention = ConstU32<{ 365 * DAYS }>; // Un año de historial
+    type RuntimeCall = RuntimeCall;
+    type PalletId = NftAccountsPalletId; // Deriva las cuentas de los NFTs
+    type ForceOrigin = EnsureRoot<AccountId>;
+    type Nfts = UniquesAdapter<Runtime>;
+    type NftCollectionConfig = ();
+    type NftItemConfig = ();
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = NftsBenchmarkHelper;
 }
 
 impl pallet_nft_attributes::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = pallet_nft_attributes::weights::SubstrateWeight<Runtime>;
+    type CollectionId = u32;
+    type ItemId = u128;
+    type Nfts = UniquesAdapter<Runtime>;
     type KeyLimit = ConstU32<64>;
     type ValueLimit = ConstU32<256>;
     type AttributeLimit = ConstU32<10>;
+    type Inventory = NftHierarchy;
+    type MaxInventoryDepth = ConstU32<3>;
+    type MaxInventoryItems = ConstU32<50>;
+    type MaxItemsPerQuery = ConstU32<100>;
+    type MaxItemsScanned = ConstU32<1_000>;
+    type ProposalLifetime = ConstU32<{ 7 * DAYS }>;
+    type OffchainSignature = Signature;
+    type OffchainPublic = <Signature as Verify>::Signer;
+    type CiphertextLimit = ConstU32<1024>;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type Currency = Balances;
+    type CommitmentDeposit = ConstU128<UNIT>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = ();
+    #[cfg(feature = "runtime-benchmarks")]
+    type NftsHelper = NftsBenchmarkHelper;
+}
+
+impl pallet_dice::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type WeightInfo = pallet_dice::weights::SubstrateWeight<Runtime>;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type Currency = Balances;
+    type SeedDeposit = ConstU128<UNIT>;
+    type RollDeposit = ConstU128<UNIT>;
+    type CommitPeriod = ConstU32<{ 10 * MINUTES }>;
+    // Sumado a CommitPeriod, debe quedar por debajo de BlockHashCount: las revelaciones leen el
+    // hash de un bloque de ese intervalo
+    type RevealPeriod = ConstU32<{ 10 * MINUTES }>;
+    type MaxParticipants = ConstU32<8>;
+    type MaxDice = ConstU32<100>;
+    type MaxSides = ConstU32<1_000>;
+    type NotationLimit = ConstU32<32>;
+    type CollectionId = u32;
+    type ItemId = u128;
+    type KeyLimit = ConstU32<64>;
+    type Attributes = NftAttributes;
 }
@@ -15,7 +15,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{AccountId, BalancesConfig, RuntimeGenesisConfig, SudoConfig};
+use crate::{
+    AccountId, BalancesConfig, NftAttributesConfig, NftHierarchyConfig, RuntimeGenesisConfig,
+    SudoConfig,
+};
 use alloc::{vec, vec::Vec};
 use frame_support::build_struct_json_patch;
 use serde_json::Value;
@@ -24,6 +27,9 @@ use sp_consensus_grandpa::AuthorityId as GrandpaId;
 use sp_genesis_builder::{self, PresetId};
 use sp_keyring::Sr25519Keyring;
 
+/// The preset of a development chain with a small RPG world already minted.
+pub const RPG_DEMO_PRESET: &str = "rpg-demo";
+
 // Returns the genesis config presets populated with given parameters.
 fn testnet_genesis(
     initial_authorities: Vec<(AuraId, GrandpaId)>,
@@ -41,7 +47,7 @@ fn testnet_genesis(
         aura: pallet_aura::GenesisConfig {
             authorities: initial_authorities
                 .iter()
-                .map(|x| (x.0.clone()))
+                .map(|x| x.0.clone())
                 .collect::<Vec<_>>(),
         },
         grandpa: pallet_grandpa::GenesisConfig {
@@ -92,11 +98,77 @@ pub fn local_config_genesis() -> Value {
     )
 }
 
+/// Return the development genesis config with an RPG world: two heroes, a knight held by Alice and
+/// a wizard held by Bob, who own the items of the armory that make up their gear. Alice owns both
+/// collections and is the game master of the heroes.
+pub fn rpg_demo_config_genesis() -> Value {
+    const HEROES: u32 = 1;
+    const ARMORY: u32 = 2;
+    const KNIGHT: u128 = 1;
+    const WIZARD: u128 = 2;
+    const SWORD: u128 = 1;
+    const STAFF: u128 = 2;
+    const SHIELD: u128 = 3;
+
+    let alice = Sr25519Keyring::Alice.to_account_id();
+    let bob = Sr25519Keyring::Bob.to_account_id();
+    let tags = |tags: &[&str]| tags.iter().map(|tag| tag.as_bytes().to_vec()).collect::<Vec<_>>();
+    let attributes = |pairs: &[(&str, &str)]| {
+        pairs
+            .iter()
+            .map(|(key, value)| (key.as_bytes().to_vec(), value.as_bytes().to_vec()))
+            .collect::<Vec<_>>()
+    };
+
+    let world = build_struct_json_patch!(RuntimeGenesisConfig {
+        nft_hierarchy: NftHierarchyConfig {
+            collections: vec![(HEROES, alice.clone()), (ARMORY, alice.clone())],
+            items: vec![
+                (HEROES, KNIGHT, alice.clone()),
+                (HEROES, WIZARD, bob.clone()),
+                (ARMORY, SWORD, alice.clone()),
+                (ARMORY, STAFF, bob),
+                (ARMORY, SHIELD, alice.clone()),
+            ],
+            nfts: vec![
+                (HEROES, KNIGHT, tags(&["character", "owner"])),
+                (HEROES, WIZARD, tags(&["character", "owner"])),
+                (ARMORY, SWORD, tags(&["weapon"])),
+                (ARMORY, STAFF, tags(&["weapon"])),
+                (ARMORY, SHIELD, tags(&["armor"])),
+            ],
+            ownerships: vec![
+                ((HEROES, KNIGHT), (ARMORY, SWORD)),
+                ((HEROES, KNIGHT), (ARMORY, SHIELD)),
+                ((HEROES, WIZARD), (ARMORY, STAFF)),
+            ],
+        },
+        nft_attributes: NftAttributesConfig {
+            game_masters: vec![(HEROES, alice)],
+            collection_defaults: vec![(HEROES, attributes(&[("hp", "10"), ("armor", "0")]))],
+            attributes: vec![
+                (HEROES, KNIGHT, attributes(&[("class", "knight"), ("hp", "14"), ("strength", "16")])),
+                (HEROES, WIZARD, attributes(&[("class", "wizard"), ("intelligence", "17")])),
+                (ARMORY, SWORD, attributes(&[("strength", "+2")])),
+                (ARMORY, STAFF, attributes(&[("intelligence", "+2")])),
+                (ARMORY, SHIELD, attributes(&[("armor", "+3")])),
+            ],
+        },
+    });
+
+    let mut patch = development_config_genesis();
+    if let (Value::Object(patch), Value::Object(world)) = (&mut patch, world) {
+        patch.extend(world);
+    }
+    patch
+}
+
 /// Provides the JSON representation of predefined genesis config for given `id`.
 pub fn get_preset(id: &PresetId) -> Option<Vec<u8>> {
     let patch = match id.as_ref() {
         sp_genesis_builder::DEV_RUNTIME_PRESET => development_config_genesis(),
         sp_genesis_builder::LOCAL_TESTNET_RUNTIME_PRESET => local_config_genesis(),
+        RPG_DEMO_PRESET => rpg_demo_config_genesis(),
         _ => return None,
     };
     Some(
@@ -111,5 +183,6 @@ pub fn preset_names() -> Vec<PresetId> {
     vec![
         PresetId::from(sp_genesis_builder::DEV_RUNTIME_PRESET),
         PresetId::from(sp_genesis_builder::LOCAL_TESTNET_RUNTIME_PRESET),
+        PresetId::from(RPG_DEMO_PRESET),
     ]
 }
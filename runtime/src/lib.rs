@@ -69,10 +69,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
     //   `spec_version`, and `authoring_version` are the same between Wasm and native.
     // This value is set to 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
     //   the compatible custom types.
-    spec_version: 100,
+    spec_version: 101,
     impl_version: 1,
     apis: apis::RUNTIME_API_VERSIONS,
-    transaction_version: 1,
+    transaction_version: 2,
     system_version: 1,
 };
 
@@ -174,7 +174,11 @@ pub type SignedPayload = generic::SignedPayload<RuntimeCall, TxExtension>;
 ///
 /// This can be a tuple of types, each implementing `OnRuntimeUpgrade`.
 #[allow(unused_parens)]
-type Migrations = ();
+type Migrations = (
+    pallet_nft_hierarchy::migrations::v1::MigrateV0ToV1<Runtime>,
+    pallet_nft_hierarchy::migrations::v2::MigrateV1ToV2<Runtime>,
+    pallet_nft_hierarchy::migrations::v3::MigrateV2ToV3<Runtime>,
+);
 
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
@@ -233,4 +237,7 @@ mod runtime {
 
     #[runtime::pallet_index(9)]
     pub type NftAttributes = pallet_nft_attributes;
+
+    #[runtime::pallet_index(10)]
+    pub type Dice = pallet_dice;
 }